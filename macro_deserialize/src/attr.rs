@@ -0,0 +1,62 @@
+use syn::{Attribute, Field, LitStr};
+
+/// `#[json(...)]` 属性で指定する変換の設定
+#[derive(Clone, Default)]
+pub(crate) struct Options {
+    /// 整数のフィールドで小数を切り捨て、範囲外の値を型の最小値・最大値に丸めるか
+    pub(crate) lossy: bool,
+    /// フィールド名の代わりに使う JSONオブジェクトのキー
+    pub(crate) rename: Option<String>,
+}
+
+impl Options {
+    /// 構造体の `#[json(...)]` を読み取る
+    /// rename はフィールドごとに異なるため、構造体には指定できない
+    pub(crate) fn from_container(attrs: &[Attribute]) -> syn::Result<Self> {
+        Self::default().parse(attrs, false)
+    }
+
+    /// フィールドの `#[json(...)]` を読み取り、指定のない項目は構造体の設定を引き継ぐ
+    pub(crate) fn with_field(&self, attrs: &[Attribute]) -> syn::Result<Self> {
+        self.clone().parse(attrs, true)
+    }
+
+    /// field に対応する JSONオブジェクトのキーを返却する
+    pub(crate) fn key(&self, field: &Field) -> String {
+        match &self.rename {
+            Some(key) => key.clone(),
+            None => field.ident.as_ref().unwrap().to_string(),
+        }
+    }
+
+    fn parse(mut self, attrs: &[Attribute], field: bool) -> syn::Result<Self> {
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("json")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    if !field {
+                        return Err(meta.error("`rename` はフィールドにのみ指定できます"));
+                    }
+                    let key: LitStr = meta.value()?.parse()?;
+                    self.rename = Some(key.value());
+                    Ok(())
+                } else if meta.path.is_ident("number") {
+                    let policy: LitStr = meta.value()?.parse()?;
+                    self.lossy = match policy.value().as_str() {
+                        "strict" => false,
+                        "lossy" => true,
+                        _ => {
+                            return Err(syn::Error::new(
+                                policy.span(),
+                                "`number` には \"strict\" か \"lossy\" を指定してください",
+                            ));
+                        }
+                    };
+                    Ok(())
+                } else {
+                    Err(meta.error("`json` 属性に指定できない項目です"))
+                }
+            })?;
+        }
+        Ok(self)
+    }
+}
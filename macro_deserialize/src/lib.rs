@@ -2,11 +2,16 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{Data, DeriveInput, Fields, parse_macro_input};
 
+use crate::attr::Options;
 use crate::ty::Ty;
 
+mod attr;
 mod ty;
 
-#[proc_macro_derive(Deserialize)]
+/// フィールドや構造体に `#[json(number = "lossy")]` を指定すると、整数のフィールドで小数を切り捨て範囲外の値を丸める
+/// 既定の `#[json(number = "strict")]` では小数や範囲外の値をエラーとする
+/// フィールドに `#[json(rename = "createdAt")]` を指定すると、フィールド名の代わりにそのキーから読み取る
+#[proc_macro_derive(Deserialize, attributes(json))]
 pub fn deserialize(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = input.ident;
@@ -19,23 +24,41 @@ pub fn deserialize(input: TokenStream) -> TokenStream {
         }
     };
 
+    let options = match Options::from_container(&input.attrs) {
+        Ok(options) => options,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
     let mut ast = vec![];
+    let mut keys = vec![];
 
     if let Fields::Named(named) = fields {
         for field in named.named {
-            ast.push(Ty::to_token_stream(&field))
+            let options = match options.with_field(&field.attrs) {
+                Ok(options) => options,
+                Err(e) => return e.to_compile_error().into(),
+            };
+            ast.push(Ty::to_token_stream(&field, &options));
+            keys.push(options.key(&field));
         }
     }
 
     let expanded = quote! {
         impl node::FromNode for #name {
-            fn from_node(value: &node::Node) -> Result<Self, node::Error> {
+            const FIELDS: Option<&'static [&'static str]> = Some(&[#(#keys),*]);
+
+            fn from_node<K: node::MapKind>(value: &node::Node<K>) -> Result<Self, node::Error> {
                 if let node::Node::Object(map) = value {
                     Ok(Self {
                         #(#ast),*
                     })
                 } else {
-                    Err(node::Error::ConversionError("構造体へのJSONのマッピングはJSONオブジェクトのみサポートしています".into()))
+                    // 構造体へのJSONのマッピングはJSONオブジェクトのみサポートしている
+                    Err(node::Error::TypeMismatch {
+                        field: None,
+                        expected: stringify!(#name).to_string(),
+                        found: value.kind(),
+                    })
                 }
             }
         }
@@ -43,3 +66,80 @@ pub fn deserialize(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// フィールドに `#[json(rename = "createdAt")]` を指定すると、フィールド名の代わりにそのキーで書き込む
+/// 型引数には ToNode・WriteJson の境界を加える
+#[proc_macro_derive(Serialize, attributes(json))]
+pub fn serialize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+
+    let mut generics = input.generics;
+    let params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = generics.make_where_clause();
+    for param in params {
+        where_clause.predicates.push(syn::parse_quote! {
+            #param: node::ToNode + node::serializer::WriteJson
+        });
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // タプル構造体・ユニット構造体はJSONオブジェクトのキーとなるフィールド名を持たない
+    let fields = match input.data {
+        Data::Struct(data) if matches!(data.fields, Fields::Named(_)) => data.fields,
+        _ => {
+            return quote! {
+                compile_error!("Serializeマクロは名前付きフィールドを持つ構造体にしか利用できません");
+            }
+            .into();
+        }
+    };
+
+    let options = match Options::from_container(&input.attrs) {
+        Ok(options) => options,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let mut idents = vec![];
+    let mut keys = vec![];
+
+    if let Fields::Named(named) = fields {
+        for field in named.named {
+            match options.with_field(&field.attrs) {
+                Ok(options) => keys.push(options.key(&field)),
+                Err(e) => return e.to_compile_error().into(),
+            }
+            idents.push(field.ident.unwrap());
+        }
+    }
+
+    // 各フィールドの型の ToNode・WriteJson に変換を任せるため、None は null として出力する
+    // WriteJson はフィールドを宣言した順に書き込む
+    let expanded = quote! {
+        impl #impl_generics node::ToNode for #name #ty_generics #where_clause {
+            fn to_node(&self) -> node::Node {
+                node::Node::Object(
+                    [#((#keys.to_string(), node::ToNode::to_node(&self.#idents))),*]
+                        .into_iter()
+                        .collect(),
+                )
+            }
+        }
+
+        impl #impl_generics node::serializer::WriteJson for #name #ty_generics #where_clause {
+            fn write_json<W: std::io::Write, F: node::serializer::Formatter>(
+                &self,
+                serializer: &mut node::serializer::Serializer<W, F>,
+            ) -> Result<(), node::serializer::Error> {
+                let mut object = serializer.begin_object()?;
+                #(object.field(#keys, &self.#idents)?;)*
+                object.end()
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
@@ -1,17 +1,21 @@
 use quote::quote;
 use syn::{Field, PathArguments, PathSegment, Type};
 
+use crate::attr::Options;
+
 pub(crate) enum Ty {
     String,
     Signed8,
     Signed16,
     Signed32,
     Signed64,
+    Signed128,
     SignedSize,
     Unsigned8,
     Unsigned16,
     Unsigned32,
     Unsigned64,
+    Unsigned128,
     UnsignedSize,
     Float64,
     Bool,
@@ -22,16 +26,20 @@ pub(crate) enum Ty {
 }
 
 impl Ty {
-    pub(crate) fn to_token_stream(field: &Field) -> proc_macro2::TokenStream {
+    pub(crate) fn to_token_stream(field: &Field, options: &Options) -> proc_macro2::TokenStream {
         let field_name = field.ident.as_ref().unwrap();
-        let field_str = field_name.to_string();
+        let field_str = options.key(field);
         let ty = &field.ty;
-        let exp = token_stream(&field_str, ty, true);
+        let exp = token_stream(&field_str, ty, true, options);
 
+        // フィールドの変換で起きたエラーには、位置としてキーを加える
         quote! {
-            #field_name: match map.get(#field_str) {
-                #exp
-            }
+            #field_name: (|| {
+                Ok::<_, node::Error>(match node::ObjectMap::get(map, #field_str) {
+                    #exp
+                })
+            })()
+            .map_err(|e| e.at_key(#field_str))?
         }
     }
 
@@ -42,14 +50,17 @@ impl Ty {
             "i16" => Self::Signed16,
             "i32" => Self::Signed32,
             "i64" => Self::Signed64,
+            "i128" => Self::Signed128,
             "isize" => Self::SignedSize,
             "u8" => Self::Unsigned8,
             "u16" => Self::Unsigned16,
             "u32" => Self::Unsigned32,
             "u64" => Self::Unsigned64,
+            "u128" => Self::Unsigned128,
             "usize" => Self::UnsignedSize,
             "f64" => Self::Float64,
             "bool" => Self::Bool,
+            // Decimal・Uuid などその他の型は FromNode の実装に変換を任せる
             _ => Self::Object,
         }
     }
@@ -64,7 +75,7 @@ impl From<&Type> for Ty {
                     "Vec" => Self::Vector(Box::new(inner_type(segment))),
                     _ => Self::from_ident(&segment.ident),
                 },
-                _ => Self::from_ident(&type_path.path.get_ident().unwrap()),
+                _ => Self::from_ident(type_path.path.get_ident().unwrap()),
             },
             Type::Tuple(tuple) => Self::Tuple(tuple.elems.iter().cloned().collect::<Vec<_>>()),
             _ => Self::Object,
@@ -82,106 +93,221 @@ fn inner_type(segment: &PathSegment) -> Type {
     }
 }
 
-fn token_stream(key: &str, ty: &Type, required: bool) -> proc_macro2::TokenStream {
+fn token_stream(
+    key: &str,
+    ty: &Type,
+    required: bool,
+    options: &Options,
+) -> proc_macro2::TokenStream {
     match &Ty::from(ty) {
-        Ty::String => string_expression(key, required),
+        Ty::String => string_expression(key, ty, required),
         Ty::Signed8
         | Ty::Signed16
         | Ty::Signed32
         | Ty::Signed64
-        | Ty::SignedSize
-        | Ty::Unsigned8
+        | Ty::Signed128
+        | Ty::SignedSize => int_expression(key, ty, true, required, options),
+        Ty::Unsigned8
         | Ty::Unsigned16
         | Ty::Unsigned32
         | Ty::Unsigned64
-        | Ty::UnsignedSize => int_expression(key, ty, required),
+        | Ty::Unsigned128
+        | Ty::UnsignedSize => int_expression(key, ty, false, required, options),
         Ty::Float64 => float_expression(key, ty, required),
-        Ty::Bool => bool_expression(key, required),
-        Ty::Optional(inner_ty) => token_stream(key, inner_ty, false),
+        Ty::Bool => bool_expression(key, ty, required),
+        Ty::Optional(inner_ty) => token_stream(key, inner_ty, false, options),
         Ty::Object => object_expression(key, ty, required),
-        Ty::Vector(inner_ty) => vector_expression(key, inner_ty, required),
-        Ty::Tuple(tuple) => tuple_expression(key, tuple, required),
+        Ty::Vector(inner_ty) => vector_expression(key, ty, inner_ty, required, options),
+        Ty::Tuple(tuple) => tuple_expression(key, ty, tuple, required),
+    }
+}
+
+/// 値がない場合は Missing、種類が異なる場合はフィールドの型と実際の種類を示す TypeMismatch とする
+fn mismatch(key: &str, ty: &Type) -> proc_macro2::TokenStream {
+    let expected = type_name(ty);
+
+    quote! {
+        Some(node) => return Err(node::Error::TypeMismatch {
+            field: Some(#key.to_string()),
+            expected: #expected.to_string(),
+            found: node.kind(),
+        }),
+        None => return Err(node::Error::Missing { field: #key.to_string() }),
+    }
+}
+
+/// Option のフィールドでは値がない場合と null を None とし、種類が異なる場合は TypeMismatch とする
+fn optional_mismatch(key: &str, ty: &Type) -> proc_macro2::TokenStream {
+    let expected = type_name(ty);
+
+    quote! {
+        Some(node::Node::Null) | None => None,
+        Some(node) => return Err(node::Error::TypeMismatch {
+            field: Some(#key.to_string()),
+            expected: #expected.to_string(),
+            found: node.kind(),
+        }),
+    }
+}
+
+/// 値をフィールドの型で表現できない場合の InvalidValue を返す式
+fn invalid_value(
+    key: &str,
+    ty: &Type,
+    reason: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let expected = type_name(ty);
+
+    quote! {
+        return Err(node::Error::InvalidValue {
+            field: Some(#key.to_string()),
+            expected: #expected.to_string(),
+            reason: #reason,
+        })
+    }
+}
+
+/// エラーに表示する型の表記から、識別子の間以外の空白を除く
+fn type_name(ty: &Type) -> String {
+    let tokens = quote!(#ty).to_string();
+    let is_ident = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    let mut name = String::with_capacity(tokens.len());
+    let mut chars = tokens.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != ' ' || (is_ident(name.chars().last()) && is_ident(chars.peek().copied())) {
+            name.push(c);
+        }
     }
+    name
 }
 
-fn string_expression(key: &str, required: bool) -> proc_macro2::TokenStream {
+fn string_expression(key: &str, ty: &Type, required: bool) -> proc_macro2::TokenStream {
+    let mismatch = mismatch(key, ty);
+    let optional = optional_mismatch(key, ty);
+
     if required {
         quote! {
             Some(node::Node::String(s)) => s.clone(),
-            _ => return Err(node::Error::RequiredError(format!("JSONオブジェクトから `{}` が読み取れません", #key).to_string())),
+            #mismatch
         }
     } else {
         quote! {
             Some(node::Node::String(s)) => Some(s.clone()),
-            _ => None,
+            #optional
         }
     }
 }
 
-fn int_expression(key: &str, ty: &Type, required: bool) -> proc_macro2::TokenStream {
+fn int_expression(
+    key: &str,
+    ty: &Type,
+    signed: bool,
+    required: bool,
+    options: &Options,
+) -> proc_macro2::TokenStream {
+    if options.lossy {
+        // 数値であれば必ず変換できるため、種類の確認のみ行う
+        let convert = quote! {
+            <#ty as node::IntegerFromNode>::from_node_with(node, node::NumberPolicy::Lossy)?
+        };
+        let mismatch = mismatch(key, ty);
+        let optional = optional_mismatch(key, ty);
+
+        return if required {
+            quote! {
+                Some(node @ node::Node::Number(_)) => #convert,
+                #mismatch
+            }
+        } else {
+            quote! {
+                Some(node @ node::Node::Number(_)) => Some(#convert),
+                #optional
+            }
+        };
+    }
+
+    // 小数部がある値や範囲外の整数は変換できないためエラーとする（`3.0` など値が整数の小数の表記は受け付ける）
+    // i128・u128 を経由し、arbitrary_precision フィーチャーで保持した u64 を超える整数も変換する
+    let invalid = invalid_value(
+        key,
+        ty,
+        quote! { format!("{} は範囲外か整数ではありません", s) },
+    );
+    let convert = if signed {
+        quote! {
+            match s.as_integral_i128().and_then(|i| <#ty as TryFrom<i128>>::try_from(i).ok()) {
+                Some(i) => i,
+                None => #invalid,
+            }
+        }
+    } else {
+        quote! {
+            match s.as_integral_u128().and_then(|i| <#ty as TryFrom<u128>>::try_from(i).ok()) {
+                Some(i) => i,
+                None => #invalid,
+            }
+        }
+    };
+
+    let mismatch = mismatch(key, ty);
+    let optional = optional_mismatch(key, ty);
+
     if required {
         quote! {
-            Some(node::Node::Number(s)) => {
-                let s: f64 = s.clone();
-                match <#ty as TryFrom<i64>>::try_from(s as i64) {
-                    Ok(i) => i,
-                    Err(e) => return Err(node::Error::ConversionError(e.to_string())),
-                }
-            },
-            _ => return Err(node::Error::RequiredError(format!("JSONオブジェクトから `{}` が読み取れません", #key).to_string())),
+            Some(node::Node::Number(s)) => #convert,
+            #mismatch
         }
     } else {
         quote! {
-            Some(node::Node::Number(s)) => {
-                let s: f64 = s.clone();
-                match <#ty as TryFrom<i64>>::try_from(s as i64) {
-                    Ok(i) => Some(i),
-                    Err(e) => return Err(node::Error::ConversionError(e.to_string())),
-                }
-            },
-            _ => None,
+            Some(node::Node::Number(s)) => Some(#convert),
+            #optional
         }
     }
 }
 
 fn float_expression(key: &str, ty: &Type, required: bool) -> proc_macro2::TokenStream {
+    let mismatch = mismatch(key, ty);
+    let optional = optional_mismatch(key, ty);
+    let invalid = invalid_value(key, ty, quote! { e.to_string() });
+
     if required {
         quote! {
             Some(node::Node::Number(s)) => {
-                let s: f64 = s.clone();
+                let s: f64 = s.as_f64();
                 match <#ty as TryFrom<f64>>::try_from(s) {
                     Ok(i) => i,
-                    Err(e) => return Err(node::Error::ConversionError(e.to_string())),
+                    Err(e) => #invalid,
                 }
             },
-            _ => return Err(node::Error::RequiredError(format!("JSONオブジェクトから `{}` が読み取れません", #key).to_string())),
+            #mismatch
         }
     } else {
         quote! {
             Some(node::Node::Number(s)) => {
-                let s: f64 = s.clone();
+                let s: f64 = s.as_f64();
                 match <#ty as TryFrom<f64>>::try_from(s) {
                     Ok(i) => Some(i),
-                    Err(e) => return Err(node::Error::ConversionError(e.to_string())),
+                    Err(e) => #invalid,
                 }
             },
-            _ => None,
+            #optional
         }
     }
 }
 
-fn bool_expression(key: &str, required: bool) -> proc_macro2::TokenStream {
+fn bool_expression(key: &str, ty: &Type, required: bool) -> proc_macro2::TokenStream {
+    let mismatch = mismatch(key, ty);
+    let optional = optional_mismatch(key, ty);
+
     if required {
         quote! {
-            Some(node::Node::True) => true,
-            Some(node::Node::False) => false,
-            _ => return Err(node::Error::RequiredError(format!("JSONオブジェクトから `{}` が読み取れません", #key).to_string())),
+            Some(node::Node::Bool(b)) => *b,
+            #mismatch
         }
     } else {
         quote! {
-            Some(node::Node::True) => Some(true),
-            Some(node::Node::False) => Some(false),
-            _ => None,
+            Some(node::Node::Bool(b)) => Some(*b),
+            #optional
         }
     }
 }
@@ -190,7 +316,7 @@ fn object_expression(key: &str, ty: &Type, required: bool) -> proc_macro2::Token
     if required {
         quote! {
             Some(node) => <#ty as node::FromNode>::from_node(&node)?,
-            _ => return Err(node::Error::RequiredError(format!("JSONオブジェクトから `{}` が読み取れません", #key).to_string())),
+            _ => return Err(node::Error::Missing { field: #key.to_string() }),
         }
     } else {
         quote! {
@@ -201,79 +327,74 @@ fn object_expression(key: &str, ty: &Type, required: bool) -> proc_macro2::Token
     }
 }
 
-fn vector_expression(key: &str, ty: &Type, required: bool) -> proc_macro2::TokenStream {
-    let exp = token_stream(key, ty, required);
+fn vector_expression(
+    key: &str,
+    ty: &Type,
+    inner_ty: &Type,
+    required: bool,
+    options: &Options,
+) -> proc_macro2::TokenStream {
+    // 要素は値があるため、フィールドが Option であっても要素の型そのものとして変換する
+    let exp = token_stream(key, inner_ty, true, options);
+
+    let mismatch = mismatch(key, ty);
+    let optional = optional_mismatch(key, ty);
 
     if required {
         quote! {
             Some(node::Node::Array(nodes)) => {
                 let mut values = vec![];
 
-                for node in nodes.into_iter() {
+                for (i, node) in nodes.iter().enumerate() {
                     values.push(
-                        match Some(node) {
-                            #exp
-                        }
+                        (|| Ok::<_, node::Error>(match Some(node) { #exp }))()
+                            .map_err(|e| e.at_index(i))?
                     )
                 }
 
                 values
             },
-            _ => return Err(node::Error::RequiredError(format!("JSONオブジェクトから `{}` が読み取れません", #key).to_string())),
+            #mismatch
         }
     } else {
         quote! {
             Some(node::Node::Array(nodes)) => {
                 let mut values = vec![];
 
-                for node in nodes.into_iter() {
+                for (i, node) in nodes.iter().enumerate() {
                     values.push(
-                        match Some(node) {
-                            #exp
-                        }
+                        (|| Ok::<_, node::Error>(match Some(node) { #exp }))()
+                            .map_err(|e| e.at_index(i))?
                     )
                 }
 
                 Some(values)
             },
-            _ => None,
+            #optional
         }
     }
 }
 
-fn tuple_expression(key: &str, tuple: &Vec<Type>, required: bool) -> proc_macro2::TokenStream {
-    let mut exps = vec![];
-
-    for ty in tuple.into_iter() {
-        let exp = token_stream(key, ty, true);
-
-        exps.push(quote! {
-            {
-                let node = iter.next();
-                match node {
-                    #exp
-                }
-            }
-        });
-    }
+fn tuple_expression(
+    key: &str,
+    ty: &Type,
+    tuple: &[Type],
+    required: bool,
+) -> proc_macro2::TokenStream {
+    // 要素数の確認と要素の変換は node の FromNode の実装に任せる
+    let mismatch = mismatch(key, ty);
+    let optional = optional_mismatch(key, ty);
+    let ty = quote! { (#(#tuple,)*) };
 
     if required {
         quote! {
-            Some(node::Node::Array(nodes)) => {
-                let mut iter = nodes.into_iter();
-
-                (#(#exps),*)
-            },
-            _ => return Err(node::Error::RequiredError(format!("JSONオブジェクトから `{}` が読み取れません", #key).to_string())),
+            Some(node @ node::Node::Array(_)) => <#ty as node::FromNode>::from_node(node)?,
+            #mismatch
         }
     } else {
         quote! {
-            Some(node::Node::Array(nodes)) => {
-                let mut iter = nodes.into_iter();
-
-                Some((#(#exps),*))
-            },
-            _ => None,
+            Some(node @ node::Node::Array(_)) => Some(<#ty as node::FromNode>::from_node(node)?),
+            #optional
         }
     }
 }
@@ -0,0 +1,229 @@
+use crate::{MapKind, Node, Number, ObjectMap};
+
+impl<K: MapKind> Node<K> {
+    /// String の場合、その内容を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let node: node::Node = node::Node::String("a".into());
+    /// assert_eq!(node.as_str(), Some("a"));
+    /// assert_eq!(node.as_bool(), None);
+    /// ```
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Node::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// String の場合、その可変参照を返却する
+    pub fn as_string_mut(&mut self) -> Option<&mut String> {
+        match self {
+            Node::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Number の場合、その値を返却する
+    pub fn as_number(&self) -> Option<&Number> {
+        match self {
+            Node::Number(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// i64 の範囲の整数の Number の場合、その値を返却する
+    /// 小数の表記（`1.0` など）は値が整数であれば返却し、小数部がある場合や範囲外の場合は切り捨てずに None を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let node: node::Node = node::Node::Number("-1".parse().unwrap());
+    /// assert_eq!(node.as_i64(), Some(-1));
+    /// assert_eq!(node.as_u64(), None);
+    ///
+    /// let node: node::Node = node::Node::Number("1e2".parse().unwrap());
+    /// assert_eq!(node.as_i64(), Some(100));
+    /// let node: node::Node = node::Node::Number("1.5".parse().unwrap());
+    /// assert_eq!(node.as_i64(), None);
+    /// ```
+    pub fn as_i64(&self) -> Option<i64> {
+        self.as_number()
+            .and_then(Number::as_integral_i128)
+            .and_then(|i| i64::try_from(i).ok())
+    }
+
+    /// u64 の範囲の整数の Number の場合、その値を返却する
+    /// 小数の表記（`1.0` など）は値が整数であれば返却し、小数部がある場合や範囲外の場合は切り捨てずに None を返却する
+    pub fn as_u64(&self) -> Option<u64> {
+        self.as_number()
+            .and_then(Number::as_integral_u128)
+            .and_then(|u| u64::try_from(u).ok())
+    }
+
+    /// Number の場合、f64 に変換して返却する
+    pub fn as_f64(&self) -> Option<f64> {
+        self.as_number().map(Number::as_f64)
+    }
+
+    /// Bool の場合、その値を返却する
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Node::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Null の場合、() を返却する
+    pub fn as_null(&self) -> Option<()> {
+        match self {
+            Node::Null => Some(()),
+            _ => None,
+        }
+    }
+
+    /// Array の場合、その要素を返却する
+    pub fn as_array(&self) -> Option<&Vec<Node<K>>> {
+        match self {
+            Node::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    /// Array の場合、その要素の可変参照を返却する
+    pub fn as_array_mut(&mut self) -> Option<&mut Vec<Node<K>>> {
+        match self {
+            Node::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    /// Object の場合、その Map を返却する
+    pub fn as_object(&self) -> Option<&K::Map<Node<K>>> {
+        match self {
+            Node::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// Object の場合、その Map の可変参照を返却する
+    pub fn as_object_mut(&mut self) -> Option<&mut K::Map<Node<K>>> {
+        match self {
+            Node::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// Object の場合、キーに対応する値を返却する
+    /// Object 以外やキーがない場合は None を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let node: node::Node = node::Node::Object(node::Map::from([(
+    ///     "a".to_string(),
+    ///     node::Node::Array(vec![node::Node::Bool(true)]),
+    /// )]));
+    /// assert_eq!(
+    ///     node.get("a").and_then(|a| a.get_index(0)),
+    ///     Some(&node::Node::Bool(true))
+    /// );
+    /// assert_eq!(node.get("a").and_then(|a| a.get("b")), None);
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&Node<K>> {
+        self.as_object().and_then(|object| object.get(key))
+    }
+
+    /// Object の場合、キーに対応する値の可変参照を返却する
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Node<K>> {
+        self.as_object_mut().and_then(|object| object.get_mut(key))
+    }
+
+    /// Array の場合、index 番目の要素を返却する
+    /// Array 以外や範囲外の場合は None を返却する
+    pub fn get_index(&self, index: usize) -> Option<&Node<K>> {
+        self.as_array().and_then(|array| array.get(index))
+    }
+
+    /// Array の場合、index 番目の要素の可変参照を返却する
+    pub fn get_index_mut(&mut self, index: usize) -> Option<&mut Node<K>> {
+        self.as_array_mut().and_then(|array| array.get_mut(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::map::Standard;
+    use crate::{Map, Node};
+
+    #[rstest::rstest]
+    #[case(Node::String("a".into()), Some("a"), None, None)]
+    #[case(Node::Number(1.5.into()), None, Some(1.5), None)]
+    #[case(Node::Number(2.into()), None, Some(2.0), None)]
+    #[case(Node::Bool(true), None, None, Some(true))]
+    #[case(Node::Null, None, None, None)]
+    fn test_scalar(
+        #[case] node: Node,
+        #[case] s: Option<&str>,
+        #[case] f: Option<f64>,
+        #[case] b: Option<bool>,
+    ) {
+        assert_eq!(node.as_str(), s);
+        assert_eq!(node.as_f64(), f);
+        assert_eq!(node.as_bool(), b);
+        assert_eq!(node.as_array(), None);
+        assert_eq!(node.as_object(), None);
+    }
+
+    #[rstest::rstest]
+    #[case("1", Some(1), Some(1))]
+    #[case("-1", Some(-1), None)]
+    #[case("18446744073709551615", None, Some(u64::MAX))]
+    #[case("-9223372036854775809", None, None)]
+    #[case("1.0", Some(1), Some(1))]
+    #[case("1e2", Some(100), Some(100))]
+    #[case("-2.0", Some(-2), None)]
+    #[case("1.5", None, None)]
+    #[case("1e15", Some(1_000_000_000_000_000), Some(1_000_000_000_000_000))]
+    #[case("1e20", None, None)]
+    fn test_integer(#[case] input: &str, #[case] i: Option<i64>, #[case] u: Option<u64>) {
+        let node: Node = Node::Number(input.parse().unwrap());
+        assert_eq!((node.as_i64(), node.as_u64()), (i, u));
+        assert_eq!(Node::<Standard>::String(input.into()).as_i64(), None);
+    }
+
+    #[test]
+    fn test_mut() {
+        let mut node: Node = Node::Array(vec![Node::Object(Map::new())]);
+        node.as_array_mut().unwrap()[0]
+            .as_object_mut()
+            .unwrap()
+            .insert("a".into(), Node::Null);
+        assert_eq!(
+            node.as_array().unwrap()[0].as_object(),
+            Some(&Map::from([("a".to_string(), Node::Null)]))
+        );
+        assert_eq!(
+            node.as_array().unwrap()[0].as_object().unwrap()["a"].as_null(),
+            Some(())
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let mut node: Node = Node::Array(vec![Node::Object(Map::from([(
+            "a".to_string(),
+            Node::Number(1.into()),
+        )]))]);
+        assert_eq!(node.get("a"), None);
+        assert_eq!(node.get_index(1), None);
+        assert_eq!(node.get_index(0).and_then(|n| n.get_index(0)), None);
+
+        *node.get_index_mut(0).and_then(|n| n.get_mut("a")).unwrap() = Node::Null;
+        assert_eq!(
+            node.get_index(0).and_then(|n| n.get("a")),
+            Some(&Node::Null)
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use crate::{Map, Node, Number};
+
+/// 文字列・Array・Object を Arc で共有する JSONデータ
+/// clone は参照カウントを増やすのみで木を複製しないため、解析済みのデータをキャッシュから配る場合に利用する
+/// 値の比較は Node と同様に内容で行う
+///
+/// # Examples
+///
+/// ```
+/// let node: node::Node = node::Node::Array(vec![node::Node::String("a".into())]);
+/// let shared = node::ArcNode::from(node.clone());
+/// let cloned = shared.clone();
+/// assert!(shared.ptr_eq(&cloned));
+/// assert_eq!(cloned.get_index(0), Some(&node::ArcNode::String("a".into())));
+/// assert_eq!(cloned.to_node(), node);
+/// ```
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub enum ArcNode {
+    String(Arc<str>),
+    Number(Number),
+    Bool(bool),
+    Null,
+    Array(Arc<[ArcNode]>),
+    Object(Arc<Map<ArcNode>>),
+}
+
+impl ArcNode {
+    /// Object の場合、キーに対応する値を返却する
+    pub fn get(&self, key: &str) -> Option<&ArcNode> {
+        match self {
+            ArcNode::Object(object) => object.get(key),
+            _ => None,
+        }
+    }
+
+    /// Array の場合、index 番目の要素を返却する
+    pub fn get_index(&self, index: usize) -> Option<&ArcNode> {
+        match self {
+            ArcNode::Array(array) => array.get(index),
+            _ => None,
+        }
+    }
+
+    /// 同じ文字列・Array・Object を共有しているか
+    /// それ以外の値は共有しないため false を返却する
+    pub fn ptr_eq(&self, other: &ArcNode) -> bool {
+        match (self, other) {
+            (ArcNode::String(a), ArcNode::String(b)) => Arc::ptr_eq(a, b),
+            (ArcNode::Array(a), ArcNode::Array(b)) => Arc::ptr_eq(a, b),
+            (ArcNode::Object(a), ArcNode::Object(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+
+    /// 共有をやめた Node へ複製して返却する
+    pub fn to_node(&self) -> Node {
+        match self {
+            ArcNode::String(s) => Node::String(s.to_string()),
+            ArcNode::Number(n) => Node::Number(n.clone()),
+            ArcNode::Bool(b) => Node::Bool(*b),
+            ArcNode::Null => Node::Null,
+            ArcNode::Array(array) => Node::Array(array.iter().map(ArcNode::to_node).collect()),
+            ArcNode::Object(object) => Node::Object(
+                object
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_node()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<Node> for ArcNode {
+    fn from(value: Node) -> Self {
+        match value {
+            Node::String(s) => ArcNode::String(s.into()),
+            Node::Number(n) => ArcNode::Number(n),
+            Node::Bool(b) => ArcNode::Bool(b),
+            Node::Null => ArcNode::Null,
+            Node::Array(array) => ArcNode::Array(array.into_iter().map(ArcNode::from).collect()),
+            Node::Object(object) => ArcNode::Object(Arc::new(
+                object
+                    .into_iter()
+                    .map(|(key, value)| (key, ArcNode::from(value)))
+                    .collect(),
+            )),
+        }
+    }
+}
+
+impl From<&ArcNode> for Node {
+    fn from(value: &ArcNode) -> Self {
+        value.to_node()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_subtree() {
+        let node: Node = Node::Object(Map::from([(
+            "items".to_string(),
+            Node::Array(vec![Node::Number(1.into()), Node::Null]),
+        )]));
+        let shared = ArcNode::from(node.clone());
+
+        let items = shared.get("items").unwrap().clone();
+        assert!(items.ptr_eq(shared.get("items").unwrap()));
+        assert_eq!(items.get_index(0), Some(&ArcNode::Number(1.into())));
+        assert_eq!(shared.get("missing"), None);
+        assert_eq!(Node::from(&shared), node);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{Node, Number};
+
+/// アリーナに確保した文字列・配列を参照する JSONデータ
+/// 木全体をアリーナごと一度に破棄するため、要素ごとの解放が発生しない
+/// アリーナは Drop を呼び出さないため、数値も表記の文字列として保持し、as_number で変換する
+/// Object のキーは昇順に並び、重複しない
+///
+/// # Examples
+///
+/// ```
+/// let object = [("a", node::ArenaNode::Number("1")), ("b", node::ArenaNode::Null)];
+/// let node = node::ArenaNode::Object(&object);
+/// assert_eq!(node.get("a").and_then(|a| a.as_number()), Some(1.into()));
+/// assert_eq!(node.get("c"), None);
+/// ```
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq)]
+pub enum ArenaNode<'b> {
+    String(&'b str),
+    /// 数値の表記（Number の Display と同じ形式）
+    Number(&'b str),
+    Bool(bool),
+    Null,
+    Array(&'b [ArenaNode<'b>]),
+    Object(&'b [(&'b str, ArenaNode<'b>)]),
+}
+
+impl<'b> ArenaNode<'b> {
+    /// Object の場合、キーに対応する値を二分探索して返却する
+    pub fn get(&self, key: &str) -> Option<&'b ArenaNode<'b>> {
+        match self {
+            ArenaNode::Object(object) => object
+                .binary_search_by(|(k, _)| (*k).cmp(key))
+                .ok()
+                .map(|i| &object[i].1),
+            _ => None,
+        }
+    }
+
+    /// Array の場合、index 番目の要素を返却する
+    pub fn get_index(&self, index: usize) -> Option<&'b ArenaNode<'b>> {
+        match self {
+            ArenaNode::Array(array) => array.get(index),
+            _ => None,
+        }
+    }
+
+    /// 文字列の場合、その内容を返却する
+    pub fn as_str(&self) -> Option<&'b str> {
+        match self {
+            ArenaNode::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// 数値の場合、表記を Number に変換して返却する
+    pub fn as_number(&self) -> Option<Number> {
+        match self {
+            ArenaNode::Number(n) => n.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// アリーナに依存しない Node に複製して返却する
+    pub fn to_node(&self) -> Node {
+        match self {
+            ArenaNode::String(s) => Node::String(s.to_string()),
+            ArenaNode::Number(_) => {
+                Node::Number(self.as_number().expect("数値の表記から生成している"))
+            }
+            ArenaNode::Bool(b) => Node::Bool(*b),
+            ArenaNode::Null => Node::Null,
+            ArenaNode::Array(array) => Node::Array(array.iter().map(ArenaNode::to_node).collect()),
+            ArenaNode::Object(object) => Node::Object(
+                object
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_node()))
+                    .collect(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use crate::{Node, Number};
+
+/// 解析した入力の文字列を借用する JSONデータ
+/// エスケープを含まない文字列とキーは入力を参照するため、読み出すのみの場合に文字列のメモリ確保を避けられる
+/// Object のキーは Node と同様に昇順に並ぶ
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// let input = String::from("ab");
+/// let node = node::BorrowedNode::Array(vec![node::BorrowedNode::String(Cow::Borrowed(&input))]);
+/// assert_eq!(node.get_index(0).and_then(node::BorrowedNode::as_str), Some("ab"));
+/// assert_eq!(node.into_owned(), node::Node::Array(vec![node::Node::String("ab".into())]));
+/// ```
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub enum BorrowedNode<'a> {
+    String(Cow<'a, str>),
+    Number(Number),
+    Bool(bool),
+    Null,
+    Array(Vec<BorrowedNode<'a>>),
+    Object(BTreeMap<Cow<'a, str>, BorrowedNode<'a>>),
+}
+
+impl<'a> BorrowedNode<'a> {
+    /// Object の場合、キーに対応する値を返却する
+    pub fn get(&self, key: &str) -> Option<&BorrowedNode<'a>> {
+        match self {
+            BorrowedNode::Object(object) => object.get(key),
+            _ => None,
+        }
+    }
+
+    /// Array の場合、index 番目の要素を返却する
+    pub fn get_index(&self, index: usize) -> Option<&BorrowedNode<'a>> {
+        match self {
+            BorrowedNode::Array(array) => array.get(index),
+            _ => None,
+        }
+    }
+
+    /// 文字列の場合、その内容を返却する
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            BorrowedNode::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// 借用している文字列を複製し、入力に依存しない Node に変換して返却する
+    pub fn into_owned(self) -> Node {
+        match self {
+            BorrowedNode::String(s) => Node::String(s.into_owned()),
+            BorrowedNode::Number(n) => Node::Number(n),
+            BorrowedNode::Bool(b) => Node::Bool(b),
+            BorrowedNode::Null => Node::Null,
+            BorrowedNode::Array(array) => {
+                Node::Array(array.into_iter().map(BorrowedNode::into_owned).collect())
+            }
+            BorrowedNode::Object(object) => Node::Object(
+                object
+                    .into_iter()
+                    .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<BorrowedNode<'_>> for Node {
+    fn from(value: BorrowedNode<'_>) -> Self {
+        value.into_owned()
+    }
+}
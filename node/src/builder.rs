@@ -0,0 +1,139 @@
+use crate::{MapKind, Node, ObjectMap};
+
+/// Object をキーと値を順に加えて組み立てる
+///
+/// # Examples
+///
+/// ```
+/// use node::Node;
+///
+/// let node: Node = Node::object()
+///     .field("name", "x")
+///     .field("tags", Node::array().item(1).item(2))
+///     .build();
+/// assert_eq!(node["name"], Node::String("x".into()));
+/// assert_eq!(node["tags"], Node::Array(vec![Node::Number(1.into()), Node::Number(2.into())]));
+/// ```
+pub struct ObjectBuilder<K: MapKind = crate::map::Standard>(K::Map<Node<K>>);
+
+/// Array を要素を順に加えて組み立てる
+pub struct ArrayBuilder<K: MapKind = crate::map::Standard>(Vec<Node<K>>);
+
+impl<K: MapKind> ObjectBuilder<K> {
+    /// 空の Object から組み立てを始める
+    pub fn new() -> Self {
+        Self(Default::default())
+    }
+
+    /// キーと値を加えて返却する
+    /// 同じキーを加えた場合は後の値で置き換える
+    pub fn field(mut self, key: impl Into<String>, value: impl Into<Node<K>>) -> Self {
+        self.0.insert(key.into(), value.into());
+        self
+    }
+
+    /// 値が Some の場合のみキーと値を加えて返却する
+    pub fn field_opt(self, key: impl Into<String>, value: Option<impl Into<Node<K>>>) -> Self {
+        match value {
+            Some(value) => self.field(key, value),
+            None => self,
+        }
+    }
+
+    /// 組み立てた Object を返却する
+    pub fn build(self) -> Node<K> {
+        Node::Object(self.0)
+    }
+}
+
+impl<K: MapKind> ArrayBuilder<K> {
+    /// 空の Array から組み立てを始める
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// 末尾に要素を加えて返却する
+    pub fn item(mut self, value: impl Into<Node<K>>) -> Self {
+        self.0.push(value.into());
+        self
+    }
+
+    /// 末尾に複数の要素を加えて返却する
+    pub fn items<T: Into<Node<K>>>(mut self, values: impl IntoIterator<Item = T>) -> Self {
+        self.0.extend(values.into_iter().map(Into::into));
+        self
+    }
+
+    /// 組み立てた Array を返却する
+    pub fn build(self) -> Node<K> {
+        Node::Array(self.0)
+    }
+}
+
+impl<K: MapKind> Default for ObjectBuilder<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: MapKind> Default for ArrayBuilder<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// build を呼ばずに入れ子の値として渡せるようにする
+impl<K: MapKind> From<ObjectBuilder<K>> for Node<K> {
+    fn from(value: ObjectBuilder<K>) -> Self {
+        value.build()
+    }
+}
+
+/// build を呼ばずに入れ子の値として渡せるようにする
+impl<K: MapKind> From<ArrayBuilder<K>> for Node<K> {
+    fn from(value: ArrayBuilder<K>) -> Self {
+        value.build()
+    }
+}
+
+impl<K: MapKind> Node<K> {
+    /// Object の組み立てを始める
+    pub fn object() -> ObjectBuilder<K> {
+        ObjectBuilder::new()
+    }
+
+    /// Array の組み立てを始める
+    pub fn array() -> ArrayBuilder<K> {
+        ArrayBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Map;
+
+    #[test]
+    fn test_builder() {
+        let node: Node = Node::object()
+            .field("a", Node::Null)
+            .field(
+                "b",
+                Node::array().items([Node::Bool(true), Node::Bool(false)]),
+            )
+            .field("a", Node::object().field_opt("c", None::<Node>))
+            .field_opt("d", Some(Node::array()))
+            .build();
+        assert_eq!(
+            node,
+            Node::Object(Map::from([
+                ("a".to_string(), Node::Object(Map::new())),
+                (
+                    "b".to_string(),
+                    Node::Array(vec![Node::Bool(true), Node::Bool(false)])
+                ),
+                ("d".to_string(), Node::Array(vec![])),
+            ]))
+        );
+    }
+}
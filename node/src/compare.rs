@@ -0,0 +1,216 @@
+use crate::{MapKind, Node, ObjectMap};
+
+/// Node::approx_eq で等しいとみなす範囲を設定する
+///
+/// # Examples
+///
+/// ```
+/// let computed: node::Node = node::json!({ "sum": 0.1 + 0.2, "tags": ["b", "a"] });
+/// let expected: node::Node = node::json!({ "sum": 0.3, "tags": ["a", "b"] });
+/// assert_ne!(computed, expected);
+/// assert!(computed.approx_eq(&expected, &node::Tolerance {
+///     unordered_arrays: true,
+///     ..Default::default()
+/// }));
+/// ```
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    /// 数値の差の許容値
+    /// 絶対値が 1 を超える数値同士は、大きい方の絶対値を掛けた値を許容値とする
+    pub epsilon: f64,
+    /// Array を要素の並び順によらない多重集合として比較するか
+    pub unordered_arrays: bool,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self {
+            epsilon: 1e-9,
+            unordered_arrays: false,
+        }
+    }
+}
+
+impl<K: MapKind> Node<K> {
+    /// 数値の誤差と Array の並び順を tolerance に従って許容し、等しいか
+    /// 数値は整数と小数の表記によらず f64 として比較する
+    pub fn approx_eq(&self, other: &Node<K>, tolerance: &Tolerance) -> bool {
+        match (self, other) {
+            (Node::Number(a), Node::Number(b)) => {
+                let (a, b) = (a.as_f64(), b.as_f64());
+                a == b || (a - b).abs() <= tolerance.epsilon * a.abs().max(b.abs()).max(1.0)
+            }
+            (Node::Array(a), Node::Array(b)) if tolerance.unordered_arrays => {
+                // 先に対応付けた要素は使用済みとして、残りから等しい要素を探す
+                let mut unmatched = b.iter().collect::<Vec<_>>();
+                a.len() == b.len()
+                    && a.iter().all(|a| {
+                        match unmatched.iter().position(|b| a.approx_eq(b, tolerance)) {
+                            Some(index) => {
+                                unmatched.swap_remove(index);
+                                true
+                            }
+                            None => false,
+                        }
+                    })
+            }
+            (Node::Array(a), Node::Array(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.approx_eq(b, tolerance))
+            }
+            (Node::Object(a), Node::Object(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .all(|(key, a)| b.get(key).is_some_and(|b| a.approx_eq(b, tolerance)))
+            }
+            (a, b) => a == b,
+        }
+    }
+}
+
+/// PartialEq と同じく数値は Number の比較に従い、NaN も自身と等しい
+impl<K: MapKind> Eq for Node<K> {}
+
+/// 種類の順（null・boolean・number・string・array・object）に並べ、同じ種類は値の順とする
+/// Object はキーの昇順に並べたキーと値の組の列として比較する
+///
+/// # Examples
+///
+/// ```
+/// let mut nodes: Vec<node::Node> = vec![
+///     node::json!("a"),
+///     node::json!({ "a": 1 }),
+///     node::json!(2),
+///     node::json!(null),
+///     node::json!(1.5),
+/// ];
+/// nodes.sort();
+/// assert_eq!(
+///     nodes,
+///     [node::json!(null), node::json!(1.5), node::json!(2), node::json!("a"), node::json!({ "a": 1 })]
+/// );
+/// ```
+impl<K: MapKind> Ord for Node<K> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Node::Bool(a), Node::Bool(b)) => a.cmp(b),
+            (Node::Number(a), Node::Number(b)) => a.cmp(b),
+            (Node::String(a), Node::String(b)) => a.cmp(b),
+            (Node::Array(a), Node::Array(b)) => a.cmp(b),
+            (Node::Object(a), Node::Object(b)) => sorted::<K>(a).cmp(&sorted::<K>(b)),
+            (a, b) => rank(a).cmp(&rank(b)),
+        }
+    }
+}
+
+impl<K: MapKind> PartialOrd for Node<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Object はキーの順序によらない値とする
+impl<K: MapKind> std::hash::Hash for Node<K> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        rank(self).hash(state);
+        match self {
+            Node::String(s) => s.hash(state),
+            Node::Number(n) => n.hash(state),
+            Node::Bool(b) => b.hash(state),
+            Node::Null => {}
+            Node::Array(array) => array.hash(state),
+            Node::Object(object) => sorted::<K>(object).hash(state),
+        }
+    }
+}
+
+fn rank<K: MapKind>(node: &Node<K>) -> u8 {
+    match node {
+        Node::Null => 0,
+        Node::Bool(_) => 1,
+        Node::Number(_) => 2,
+        Node::String(_) => 3,
+        Node::Array(_) => 4,
+        Node::Object(_) => 5,
+    }
+}
+
+fn sorted<K: MapKind>(object: &K::Map<Node<K>>) -> Vec<(&str, &Node<K>)> {
+    let mut entries = object.iter().collect::<Vec<_>>();
+    entries.sort_unstable_by_key(|(key, _)| *key);
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+
+    #[rstest::rstest]
+    #[case(json!(0.1 + 0.2), json!(0.3), true)]
+    #[case(json!(1), json!(1.0), true)]
+    #[case(json!(1e20), json!(1e20 + 1e10), true)]
+    #[case(json!(1e20), json!(1.001e20), false)]
+    #[case(json!(0.001), json!(0.002), false)]
+    #[case(json!([1, 2]), json!([2, 1]), false)]
+    #[case(json!([1, 2]), json!([1]), false)]
+    #[case(json!({ "a": [0.30000000000000004] }), json!({ "a": [0.3] }), true)]
+    #[case(json!({ "a": 1 }), json!({ "b": 1 }), false)]
+    #[case(json!("1"), json!(1), false)]
+    fn test_approx_eq(#[case] a: Node, #[case] b: Node, #[case] expected: bool) {
+        assert_eq!(a.approx_eq(&b, &Tolerance::default()), expected);
+        assert_eq!(b.approx_eq(&a, &Tolerance::default()), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(json!(null), json!(false))]
+    #[case(json!(true), json!(0))]
+    #[case(json!(1), json!(1.0))]
+    #[case(json!(1e10), json!(""))]
+    #[case(json!("a"), json!("b"))]
+    #[case(json!("z"), json!([]))]
+    #[case(json!([1]), json!([1, 0]))]
+    #[case(json!([2]), json!([10, 0]))]
+    #[case(json!([]), json!({}))]
+    #[case(json!({ "a": 2 }), json!({ "b": 1 }))]
+    #[case(json!({ "a": 1, "c": 1 }), json!({ "a": 1, "c": 2 }))]
+    fn test_ord(#[case] a: Node, #[case] b: Node) {
+        assert!(a < b);
+        assert!(b > a);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_hash() {
+        let mut a: Node<crate::map::Hashed> = Node::object().build();
+        let mut b: Node<crate::map::Hashed> = Node::object().build();
+        for i in 0..32 {
+            a.insert(i.to_string(), Node::from(i)).unwrap();
+            b.insert((31 - i).to_string(), Node::from(31 - i)).unwrap();
+        }
+        assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
+
+        let set = std::collections::HashSet::from([a, b]);
+        assert_eq!(set.len(), 1);
+
+        let set: std::collections::BTreeSet<Node> =
+            [json!(2), json!(null), json!(2), json!(-0.0), json!(0.0)]
+                .into_iter()
+                .collect();
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            [json!(null), json!(0.0), json!(2)]
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(json!([1, 2, 2]), json!([2, 1, 2]), true)]
+    #[case(json!([1, 1, 2]), json!([2, 1, 2]), false)]
+    #[case(json!([[1, 2], 3]), json!([3, [2, 1]]), true)]
+    fn test_unordered_arrays(#[case] a: Node, #[case] b: Node, #[case] expected: bool) {
+        let tolerance = Tolerance {
+            unordered_arrays: true,
+            ..Default::default()
+        };
+        assert_eq!(a.approx_eq(&b, &tolerance), expected);
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{Error, MapKind, Node, Number};
+
+impl<K: MapKind> From<&str> for Node<K> {
+    fn from(value: &str) -> Self {
+        Node::String(value.into())
+    }
+}
+
+impl<K: MapKind> From<String> for Node<K> {
+    fn from(value: String) -> Self {
+        Node::String(value)
+    }
+}
+
+impl<K: MapKind> From<bool> for Node<K> {
+    fn from(value: bool) -> Self {
+        Node::Bool(value)
+    }
+}
+
+impl<K: MapKind> From<Number> for Node<K> {
+    fn from(value: Number) -> Self {
+        Node::Number(value)
+    }
+}
+
+macro_rules! from_number {
+    ($($ty:ty),*) => {
+        $(
+            impl<K: MapKind> From<$ty> for Node<K> {
+                fn from(value: $ty) -> Self {
+                    Node::Number(value.into())
+                }
+            }
+        )*
+    };
+}
+
+from_number!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+/// None は Node::Null に変換する
+impl<K: MapKind, T: Into<Node<K>>> From<Option<T>> for Node<K> {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Node::Null, Into::into)
+    }
+}
+
+impl<K: MapKind, T: Into<Node<K>>> From<Vec<T>> for Node<K> {
+    fn from(value: Vec<T>) -> Self {
+        Node::Array(value.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Object の Map の種類は K に従って作り直す
+impl<K: MapKind, T: Into<Node<K>>> From<std::collections::BTreeMap<String, T>> for Node<K> {
+    fn from(value: std::collections::BTreeMap<String, T>) -> Self {
+        Node::Object(
+            value
+                .into_iter()
+                .map(|(key, value)| (key, value.into()))
+                .collect(),
+        )
+    }
+}
+
+/// 要素を集めた Array を構築する
+///
+/// # Examples
+///
+/// ```
+/// let node: node::Node = (1..=2).map(node::Node::from).collect();
+/// assert_eq!(node, node::json!([1, 2]));
+/// ```
+impl<K: MapKind> FromIterator<Node<K>> for Node<K> {
+    fn from_iter<T: IntoIterator<Item = Node<K>>>(iter: T) -> Self {
+        Node::Array(iter.into_iter().collect())
+    }
+}
+
+/// キーと値を集めた Object を構築する
+/// 同じキーが複数ある場合は後の値とする
+impl<K: MapKind> FromIterator<(String, Node<K>)> for Node<K> {
+    fn from_iter<T: IntoIterator<Item = (String, Node<K>)>>(iter: T) -> Self {
+        Node::Object(iter.into_iter().collect())
+    }
+}
+
+impl<'a, K: MapKind> TryFrom<&'a Node<K>> for &'a str {
+    type Error = Error;
+
+    fn try_from(node: &'a Node<K>) -> Result<Self, Error> {
+        node.as_str()
+            .ok_or_else(|| Error::type_mismatch::<str, K>(node))
+    }
+}
+
+impl<K: MapKind> TryFrom<&Node<K>> for String {
+    type Error = Error;
+
+    fn try_from(node: &Node<K>) -> Result<Self, Error> {
+        node.as_str()
+            .map(String::from)
+            .ok_or_else(|| Error::type_mismatch::<String, K>(node))
+    }
+}
+
+impl<K: MapKind> TryFrom<&Node<K>> for bool {
+    type Error = Error;
+
+    fn try_from(node: &Node<K>) -> Result<Self, Error> {
+        node.as_bool()
+            .ok_or_else(|| Error::type_mismatch::<bool, K>(node))
+    }
+}
+
+/// 整数の Number も f64 に変換する
+impl<K: MapKind> TryFrom<&Node<K>> for f64 {
+    type Error = Error;
+
+    fn try_from(node: &Node<K>) -> Result<Self, Error> {
+        node.as_f64()
+            .ok_or_else(|| Error::type_mismatch::<f64, K>(node))
+    }
+}
+
+impl<K: MapKind> TryFrom<&Node<K>> for Vec<Node<K>> {
+    type Error = Error;
+
+    fn try_from(node: &Node<K>) -> Result<Self, Error> {
+        node.as_array()
+            .cloned()
+            .ok_or_else(|| Error::type_mismatch::<Vec<Node<K>>, K>(node))
+    }
+}
+
+/// 小数の表記は値が整数であれば受け付け、小数部がある値や範囲外の値は切り捨てずに Error::InvalidValue とする
+macro_rules! try_from_integer {
+    ($as:ident: $($ty:ty),*) => {
+        $(
+            impl<K: MapKind> TryFrom<&Node<K>> for $ty {
+                type Error = Error;
+
+                fn try_from(node: &Node<K>) -> Result<Self, Error> {
+                    let n = node
+                        .as_number()
+                        .ok_or_else(|| Error::type_mismatch::<$ty, K>(node))?;
+                    n.$as()
+                        .and_then(|v| <$ty>::try_from(v).ok())
+                        .ok_or_else(|| Error::invalid_value::<$ty>(format!("{n} は範囲外か整数ではありません")))
+                }
+            }
+        )*
+    };
+}
+
+try_from_integer!(as_integral_i128: i8, i16, i32, i64, i128, isize);
+try_from_integer!(as_integral_u128: u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Map, Node, json};
+
+    #[rstest::rstest]
+    #[case(Node::from("a"), Node::String("a".into()))]
+    #[case(Node::from(true), Node::Bool(true))]
+    #[case(Node::from(-1_i8), Node::Number((-1).into()))]
+    #[case(Node::from(u64::MAX), Node::Number(u64::MAX.into()))]
+    #[case(Node::from(1.5), Node::Number(1.5.into()))]
+    #[case(Node::from(String::from("a")), Node::String("a".into()))]
+    #[case(Node::from(None::<bool>), Node::Null)]
+    #[case(Node::from(Some(false)), Node::Bool(false))]
+    #[case(
+        Node::from(vec![Some(1), None]),
+        Node::Array(vec![Node::Number(1.into()), Node::Null])
+    )]
+    #[case(
+        Node::from(std::collections::BTreeMap::from([("a".to_string(), vec!["b"])])),
+        Node::Object(Map::from([("a".to_string(), Node::Array(vec![Node::String("b".into())]))]))
+    )]
+    fn test_from(#[case] node: Node, #[case] expected: Node) {
+        assert_eq!(node, expected);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let node: Node = ["a", "b"].into_iter().map(Node::from).collect();
+        assert_eq!(node, json!(["a", "b"]));
+
+        let node: Node = [("a", 1), ("b", 2), ("a", 3)]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), Node::from(v)))
+            .collect();
+        assert_eq!(node, json!({ "a": 3, "b": 2 }));
+    }
+
+    #[test]
+    fn test_try_from() {
+        let node: Node =
+            json!({ "s": "a", "b": true, "i": -1, "f": 1.5, "g": 1.0, "h": 1e2, "a": [null] });
+        assert_eq!(String::try_from(&node["s"]).unwrap(), "a");
+        assert_eq!(<&str>::try_from(&node["s"]).unwrap(), "a");
+        assert!(bool::try_from(&node["b"]).unwrap());
+        assert_eq!(i8::try_from(&node["i"]).unwrap(), -1);
+        assert_eq!(f64::try_from(&node["i"]).unwrap(), -1.0);
+        assert_eq!(i64::try_from(&node["g"]).unwrap(), 1);
+        assert_eq!(u8::try_from(&node["h"]).unwrap(), 100);
+        assert_eq!(f64::try_from(&node["f"]).unwrap(), 1.5);
+        assert_eq!(Vec::try_from(&node["a"]).unwrap(), vec![Node::Null]);
+    }
+
+    #[rstest::rstest]
+    #[case(json!(1), |n: &Node| String::try_from(n).map(drop), "number の値は String に変換できません")]
+    #[case(json!(256), |n: &Node| u8::try_from(n).map(drop), "値を u8 に変換できません（256 は範囲外か整数ではありません）")]
+    #[case(json!(-1), |n: &Node| u64::try_from(n).map(drop), "値を u64 に変換できません（-1 は範囲外か整数ではありません）")]
+    #[case(json!(1.5), |n: &Node| i64::try_from(n).map(drop), "値を i64 に変換できません（1.5 は範囲外か整数ではありません）")]
+    #[case(json!(null), |n: &Node| bool::try_from(n).map(drop), "null の値は bool に変換できません")]
+    fn test_try_from_error(
+        #[case] node: Node,
+        #[case] convert: fn(&Node) -> Result<(), crate::Error>,
+        #[case] message: &str,
+    ) {
+        assert_eq!(convert(&node).unwrap_err().to_string(), message);
+    }
+}
@@ -0,0 +1,154 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat, Utc};
+
+use crate::from_node::as_str;
+use crate::serializer::{self, Formatter, Serializer, WriteJson};
+use crate::{Error, FromNode, MapKind, Node, ToNode};
+
+/// 日時の型ごとに文字列の解釈と表記を定める
+trait DateTimeText: Sized {
+    fn parse(s: &str) -> Result<Self, chrono::ParseError>;
+
+    fn format(&self) -> String;
+}
+
+/// RFC 3339 の文字列とし、UTC の場合は `Z` で表記する
+impl DateTimeText for DateTime<Utc> {
+    fn parse(s: &str) -> Result<Self, chrono::ParseError> {
+        DateTime::parse_from_rfc3339(s).map(|t| t.with_timezone(&Utc))
+    }
+
+    fn format(&self) -> String {
+        self.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+    }
+}
+
+/// RFC 3339 の文字列とし、時差を保持する
+impl DateTimeText for DateTime<FixedOffset> {
+    fn parse(s: &str) -> Result<Self, chrono::ParseError> {
+        DateTime::parse_from_rfc3339(s)
+    }
+
+    fn format(&self) -> String {
+        self.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+    }
+}
+
+/// `2024-01-31T12:00:00` のような時差のない日時
+impl DateTimeText for NaiveDateTime {
+    fn parse(s: &str) -> Result<Self, chrono::ParseError> {
+        s.parse()
+    }
+
+    fn format(&self) -> String {
+        self.format("%Y-%m-%dT%H:%M:%S%.f").to_string()
+    }
+}
+
+/// `2024-01-31` のような日付
+impl DateTimeText for NaiveDate {
+    fn parse(s: &str) -> Result<Self, chrono::ParseError> {
+        s.parse()
+    }
+
+    fn format(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// `12:00:00` のような時刻
+impl DateTimeText for NaiveTime {
+    fn parse(s: &str) -> Result<Self, chrono::ParseError> {
+        s.parse()
+    }
+
+    fn format(&self) -> String {
+        self.to_string()
+    }
+}
+
+macro_rules! datetime {
+    ($($ty:ty),*) => {
+        $(
+            impl FromNode for $ty {
+                fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+                    let s = as_str::<Self, K>(node)?;
+                    <$ty as DateTimeText>::parse(s).map_err(|e| {
+                        Error::invalid_value::<Self>(format!("`{s}` を解釈できません（{e}）"))
+                    })
+                }
+            }
+
+            impl ToNode for $ty {
+                fn to_node(&self) -> Node {
+                    Node::String(DateTimeText::format(self))
+                }
+            }
+
+            impl WriteJson for $ty {
+                fn write_json<W: std::io::Write, F: Formatter>(
+                    &self,
+                    serializer: &mut Serializer<W, F>,
+                ) -> Result<(), serializer::Error> {
+                    serializer.serialize_str(&DateTimeText::format(self))
+                }
+            }
+        )*
+    };
+}
+
+datetime!(
+    DateTime<Utc>,
+    DateTime<FixedOffset>,
+    NaiveDateTime,
+    NaiveDate,
+    NaiveTime
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+
+    #[rstest::rstest]
+    #[case("2024-01-31T12:34:56Z", "2024-01-31T12:34:56Z")]
+    #[case("2024-01-31T21:34:56.5+09:00", "2024-01-31T12:34:56.500Z")]
+    #[case("2024-01-31t12:34:56z", "2024-01-31T12:34:56Z")]
+    fn test_utc(#[case] input: &str, #[case] expected: &str) {
+        let node: Node = json!(input);
+        let time = DateTime::<Utc>::from_node(&node).unwrap();
+        assert_eq!(time.to_node(), json!(expected));
+        let mut json = Vec::new();
+        serializer::write_json(&time, &mut json).unwrap();
+        assert_eq!(json, format!("\"{expected}\"").into_bytes());
+    }
+
+    #[test]
+    fn test_naive() {
+        let node: Node = json!({
+            "offset": "2024-01-31T21:34:56+09:00",
+            "datetime": "2024-01-31T12:34:56.25",
+            "date": "2024-01-31",
+            "time": "12:34:56",
+        });
+        let offset = DateTime::<FixedOffset>::from_node(&node["offset"]).unwrap();
+        assert_eq!(offset.to_node(), node["offset"]);
+        let datetime = NaiveDateTime::from_node(&node["datetime"]).unwrap();
+        assert_eq!(datetime.to_node(), json!("2024-01-31T12:34:56.250"));
+        let date = NaiveDate::from_node(&node["date"]).unwrap();
+        assert_eq!(date, NaiveDate::from_ymd_opt(2024, 1, 31).unwrap());
+        assert_eq!(date.to_node(), node["date"]);
+        let time = NaiveTime::from_node(&node["time"]).unwrap();
+        assert_eq!(time.to_node(), node["time"]);
+    }
+
+    #[rstest::rstest]
+    #[case(json!("2024-01-31"), "値を DateTime<Utc> に変換できません（`2024-01-31` を解釈できません（premature end of input））")]
+    #[case(json!("2024-13-01T00:00:00Z"), "値を DateTime<Utc> に変換できません（`2024-13-01T00:00:00Z` を解釈できません（input is out of range））")]
+    #[case(json!(0), "number の値は DateTime<Utc> に変換できません")]
+    fn test_error(#[case] node: Node, #[case] expected: &str) {
+        match DateTime::<Utc>::from_node(&node) {
+            Err(e) => assert_eq!(e.to_string(), expected),
+            result => panic!("{result:?}"),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use rust_decimal::Decimal;
+
+use crate::serializer::{self, Formatter, Serializer, WriteJson};
+use crate::{Error, FromNode, MapKind, Node, Number, ToNode};
+
+/// 数値の入力の表記から f64 を経由せずに変換する
+/// Decimal で表現できない桁数や範囲の値は丸めずに Error::InvalidValue とする
+impl FromNode for Decimal {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        let Node::Number(n) = node else {
+            return Err(Error::type_mismatch::<Self, K>(node));
+        };
+        let text = n.as_str();
+        let result = if text.contains(['e', 'E']) {
+            Decimal::from_scientific(text)
+        } else {
+            Decimal::from_str_exact(text)
+        };
+        result.map_err(|e| Error::invalid_value::<Self>(format!("{text} を表現できません（{e}）")))
+    }
+}
+
+/// 小数点以下の桁数を保ったまま数値に変換する
+impl ToNode for Decimal {
+    fn to_node(&self) -> Node {
+        Node::Number(to_number(self))
+    }
+}
+
+impl WriteJson for Decimal {
+    fn write_json<W: std::io::Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), serializer::Error> {
+        serializer.serialize_number(&to_number(self))
+    }
+}
+
+fn to_number(value: &Decimal) -> Number {
+    value
+        .to_string()
+        .parse()
+        .expect("Decimal は JSONの数値の表記で表示する")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+
+    #[rstest::rstest]
+    #[case("0.1", "0.1")]
+    #[case("12.50", "12.50")]
+    #[case("-3", "-3")]
+    #[case("1.5e2", "150")]
+    #[case("25E-3", "0.025")]
+    #[case("79228162514264337593543950335", "79228162514264337593543950335")]
+    fn test_decimal(#[case] input: &str, #[case] output: &str) {
+        let node: Node = Node::Number(input.parse().unwrap());
+        let decimal = Decimal::from_node(&node).unwrap();
+        assert_eq!(decimal.to_string(), output);
+        assert_eq!(serializer::to_string(&decimal.to_node()), output);
+        let mut json = Vec::new();
+        serializer::write_json(&decimal, &mut json).unwrap();
+        assert_eq!(json, output.as_bytes());
+    }
+
+    #[rstest::rstest]
+    #[case(json!("0.1"), "string の値は Decimal に変換できません")]
+    #[case(
+        Node::Number("79228162514264337593543950336".parse().unwrap()),
+        "値を Decimal に変換できません（79228162514264337593543950336 を表現できません"
+    )]
+    #[case(
+        Node::Number("0.12345678901234567890123456789".parse().unwrap()),
+        "値を Decimal に変換できません（0.12345678901234567890123456789 を表現できません"
+    )]
+    fn test_error(#[case] node: Node, #[case] expected: &str) {
+        match Decimal::from_node(&node) {
+            Err(e) => assert!(e.to_string().starts_with(expected), "{e}"),
+            result => panic!("{result:?}"),
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{MapKind, Node, ObjectMap};
+
+/// Node::entry が返却する Object のキーに対応する位置を表現する
+///
+/// # Examples
+///
+/// ```
+/// let mut node: node::Node = node::Node::Null;
+/// for word in ["a", "b", "a"] {
+///     node.entry(word)
+///         .and_modify(|count| *count = (count.as_u64().unwrap() + 1).into())
+///         .or_insert(1.into());
+/// }
+/// assert_eq!(node["a"], node::Node::Number(2.into()));
+/// assert_eq!(node["b"], node::Node::Number(1.into()));
+/// ```
+pub enum Entry<'a, K: MapKind = crate::map::Standard> {
+    /// キーが存在する
+    Occupied(OccupiedEntry<'a, K>),
+    /// キーが存在しない
+    Vacant(VacantEntry<'a, K>),
+}
+
+/// キーが存在する Entry
+pub struct OccupiedEntry<'a, K: MapKind = crate::map::Standard> {
+    map: &'a mut K::Map<Node<K>>,
+    key: String,
+}
+
+/// キーが存在しない Entry
+pub struct VacantEntry<'a, K: MapKind = crate::map::Standard> {
+    map: &'a mut K::Map<Node<K>>,
+    key: String,
+}
+
+impl<'a, K: MapKind> Entry<'a, K> {
+    /// キーを返却する
+    pub fn key(&self) -> &str {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// キーがない場合は value を格納し、値の可変参照を返却する
+    pub fn or_insert(self, value: Node<K>) -> &'a mut Node<K> {
+        self.or_insert_with(|| value)
+    }
+
+    /// キーがない場合は f の戻り値を格納し、値の可変参照を返却する
+    pub fn or_insert_with(self, f: impl FnOnce() -> Node<K>) -> &'a mut Node<K> {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(f()),
+        }
+    }
+
+    /// キーがある場合は値を f で変更する
+    pub fn and_modify(mut self, f: impl FnOnce(&mut Node<K>)) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K: MapKind> OccupiedEntry<'a, K> {
+    /// キーを返却する
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 値を返却する
+    pub fn get(&self) -> &Node<K> {
+        self.map.get(&self.key).expect("キーが存在する")
+    }
+
+    /// 値の可変参照を返却する
+    pub fn get_mut(&mut self) -> &mut Node<K> {
+        self.map.get_mut(&self.key).expect("キーが存在する")
+    }
+
+    /// 値の可変参照を Entry より長い期間で返却する
+    pub fn into_mut(self) -> &'a mut Node<K> {
+        self.map.get_mut(&self.key).expect("キーが存在する")
+    }
+
+    /// 値を置き換え、元の値を返却する
+    pub fn insert(&mut self, value: Node<K>) -> Node<K> {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    /// キーを取り除き、その値を返却する
+    pub fn remove(self) -> Node<K> {
+        self.map.remove(&self.key).expect("キーが存在する")
+    }
+}
+
+impl<'a, K: MapKind> VacantEntry<'a, K> {
+    /// キーを返却する
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 値を格納し、その可変参照を返却する
+    pub fn insert(self, value: Node<K>) -> &'a mut Node<K> {
+        self.map.insert(self.key.clone(), value);
+        self.map.get_mut(&self.key).expect("直前に格納している")
+    }
+}
+
+impl<K: MapKind> Node<K> {
+    /// Object のキーに対応する Entry を返却する
+    /// Node::Null は空の Object に置き換えてから返却する
+    /// Object・Null 以外の場合はパニックする
+    pub fn entry(&mut self, key: impl Into<String>) -> Entry<'_, K> {
+        let key = key.into();
+        if self.is_null() {
+            *self = Node::Object(K::Map::default());
+        }
+
+        let kind = self.kind();
+        let Node::Object(map) = self else {
+            panic!("{kind} の値のキー `{key}` を参照できません");
+        };
+        if map.contains_key(&key) {
+            Entry::Occupied(OccupiedEntry { map, key })
+        } else {
+            Entry::Vacant(VacantEntry { map, key })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Map;
+
+    #[test]
+    fn test_entry() {
+        let mut node: Node = Node::Object(Map::from([("a".to_string(), Node::Bool(true))]));
+
+        let Entry::Occupied(mut entry) = node.entry("a") else {
+            panic!("キー `a` は存在する");
+        };
+        assert_eq!(entry.key(), "a");
+        assert_eq!(entry.insert(Node::Bool(false)), Node::Bool(true));
+        assert_eq!(entry.get(), &Node::Bool(false));
+        assert_eq!(entry.remove(), Node::Bool(false));
+
+        let Entry::Vacant(entry) = node.entry("a") else {
+            panic!("キー `a` は取り除いている");
+        };
+        *entry.insert(Node::Null) = Node::Number(1.into());
+        assert_eq!(
+            node,
+            Node::Object(Map::from([("a".to_string(), Node::Number(1.into()))]))
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(Node::Null, Node::Object(Map::from([("k".to_string(), Node::Bool(true))])))]
+    #[case(
+        Node::Object(Map::from([("k".to_string(), Node::Null)])),
+        Node::Object(Map::from([("k".to_string(), Node::Bool(false))]))
+    )]
+    fn test_or_insert(#[case] mut node: Node, #[case] expected: Node) {
+        node.entry("k")
+            .and_modify(|value| *value = Node::Bool(false))
+            .or_insert(Node::Bool(true));
+        assert_eq!(node, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "array の値のキー `k` を参照できません")]
+    fn test_entry_panic() {
+        let mut node: Node = Node::Array(vec![]);
+        node.entry("k");
+    }
+}
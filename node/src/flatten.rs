@@ -0,0 +1,260 @@
+use crate::{MapKind, Node, ObjectMap, PathError, PathSegment};
+
+/// Node::flatten・Node::unflatten のキーの表記を設定する
+/// 既定値は `a.b[2].c` の形式となり、prefix に `json` を指定すると gron と同じ `json.a.b[2].c` の形式となる
+#[derive(std::fmt::Debug, Clone, PartialEq, Eq)]
+pub struct FlattenOptions {
+    /// Object のキーの区切り
+    pub separator: String,
+    /// Array の添字を `[2]` と表記するか
+    /// false の場合はキーと同じく区切りの後に `2` と表記し、unflatten では数字のみのキーを添字として扱う
+    pub bracket_indices: bool,
+    /// 全てのキーの先頭に付ける名前
+    pub prefix: String,
+}
+
+impl Default for FlattenOptions {
+    fn default() -> Self {
+        Self {
+            separator: ".".into(),
+            bracket_indices: true,
+            prefix: String::new(),
+        }
+    }
+}
+
+impl FlattenOptions {
+    fn push(&self, key: &mut String, segment: &PathSegment) {
+        let separator = if key.is_empty() { "" } else { &self.separator };
+        match segment {
+            PathSegment::Index(index) if self.bracket_indices => {
+                key.push_str(&format!("[{index}]"))
+            }
+            PathSegment::Index(index) => key.push_str(&format!("{separator}{index}")),
+            PathSegment::Key(k) => key.push_str(&format!("{separator}{k}")),
+        }
+    }
+
+    /// キーの表記を PathSegment の列に戻す
+    fn parse(&self, key: &str) -> Vec<PathSegment> {
+        let key = key.strip_prefix(self.prefix.as_str()).unwrap_or(key);
+        let key = if self.prefix.is_empty() {
+            key
+        } else {
+            key.strip_prefix(self.separator.as_str()).unwrap_or(key)
+        };
+        if key.is_empty() {
+            return Vec::new();
+        }
+
+        let mut segments = Vec::new();
+        for part in key.split(self.separator.as_str()) {
+            if !self.bracket_indices {
+                segments.push(match part.parse() {
+                    Ok(index) if part.bytes().all(|b| b.is_ascii_digit()) => {
+                        PathSegment::Index(index)
+                    }
+                    _ => PathSegment::Key(part.into()),
+                });
+                continue;
+            }
+
+            // 末尾から `[2]` を読み、残りをキーとする
+            let mut rest = part;
+            let mut indices = Vec::new();
+            while let Some(inner) = rest.strip_suffix(']')
+                && let Some((head, index)) = inner.rsplit_once('[')
+                && let Ok(index) = index.parse::<usize>()
+            {
+                indices.push(PathSegment::Index(index));
+                rest = head;
+            }
+            if !rest.is_empty() || indices.is_empty() {
+                segments.push(PathSegment::Key(rest.into()));
+            }
+            segments.extend(indices.into_iter().rev());
+        }
+
+        segments
+    }
+}
+
+impl<K: MapKind> Node<K> {
+    /// 入れ子の値を、末端の値までのキーと末端の値の組の列に平坦化する
+    /// 空の Array・Object は末端の値として扱う
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let node: node::Node = node::json!({ "a": { "b": [1, { "c": true }] }, "d": [] });
+    /// let flat = node.flatten(&node::FlattenOptions::default());
+    /// assert_eq!(
+    ///     flat,
+    ///     [
+    ///         ("a.b[0]".to_string(), node::json!(1)),
+    ///         ("a.b[1].c".to_string(), node::json!(true)),
+    ///         ("d".to_string(), node::json!([])),
+    ///     ]
+    /// );
+    /// assert_eq!(node::Node::unflatten(flat, &node::FlattenOptions::default()), Ok(node));
+    /// ```
+    pub fn flatten(&self, options: &FlattenOptions) -> Vec<(String, Node<K>)> {
+        let mut entries = Vec::new();
+        self.flatten_into(options.prefix.clone(), options, &mut entries);
+        entries
+    }
+
+    fn flatten_into(
+        &self,
+        key: String,
+        options: &FlattenOptions,
+        entries: &mut Vec<(String, Node<K>)>,
+    ) {
+        let children: Vec<(PathSegment, &Node<K>)> = match self {
+            Node::Object(object) => object
+                .iter()
+                .map(|(k, value)| (PathSegment::from(k), value))
+                .collect(),
+            Node::Array(array) => array
+                .iter()
+                .enumerate()
+                .map(|(index, value)| (PathSegment::from(index), value))
+                .collect(),
+            _ => Vec::new(),
+        };
+        if children.is_empty() {
+            entries.push((key, self.clone()));
+            return;
+        }
+
+        for (segment, value) in children {
+            let mut key = key.clone();
+            options.push(&mut key, &segment);
+            value.flatten_into(key, options, entries);
+        }
+    }
+
+    /// Node::flatten の結果からキーの表記に従って入れ子の値を組み立てる
+    /// 添字が飛んでいる Array は Node::Null で埋める
+    /// 末端の値の下にさらに値がある場合など、組み立てられない場合は PathError::TypeMismatch を返却する
+    pub fn unflatten<S: AsRef<str>>(
+        entries: impl IntoIterator<Item = (S, Node<K>)>,
+        options: &FlattenOptions,
+    ) -> Result<Node<K>, PathError> {
+        let mut root = Node::Null;
+        for (key, value) in entries {
+            let segments = options.parse(key.as_ref());
+            let mut node = &mut root;
+            for (position, segment) in segments.iter().enumerate() {
+                node = node.child_or_insert(position, segment)?;
+            }
+            *node = value;
+        }
+
+        Ok(root)
+    }
+
+    /// Node::Null は segment に応じて空の Object・Array に置き換える
+    fn child_or_insert(
+        &mut self,
+        position: usize,
+        segment: &PathSegment,
+    ) -> Result<&mut Node<K>, PathError> {
+        match (&mut *self, segment) {
+            (Node::Null, PathSegment::Key(_)) => *self = Node::Object(K::Map::default()),
+            (Node::Null, PathSegment::Index(_)) => *self = Node::Array(Vec::new()),
+            _ => {}
+        }
+
+        let found = self.kind();
+        match (self, segment) {
+            (Node::Object(object), PathSegment::Key(key)) => {
+                if !object.contains_key(key) {
+                    object.insert(key.clone(), Node::Null);
+                }
+                Ok(object.get_mut(key).expect("直前に格納している"))
+            }
+            (Node::Array(array), PathSegment::Index(index)) => {
+                if array.len() <= *index {
+                    array.resize(index + 1, Node::Null);
+                }
+                Ok(&mut array[*index])
+            }
+            _ => Err(PathError::TypeMismatch {
+                position,
+                segment: segment.clone(),
+                found,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Kind, json};
+
+    fn node() -> Node {
+        json!({ "a": { "b c": [1, [2, { "d": null }]] }, "e": {}, "f": "g" })
+    }
+
+    #[rstest::rstest]
+    #[case(
+        FlattenOptions::default(),
+        ["a.b c[0]", "a.b c[1][0]", "a.b c[1][1].d", "e", "f"]
+    )]
+    #[case(
+        FlattenOptions { prefix: "json".into(), ..Default::default() },
+        ["json.a.b c[0]", "json.a.b c[1][0]", "json.a.b c[1][1].d", "json.e", "json.f"]
+    )]
+    #[case(
+        FlattenOptions { separator: "__".into(), bracket_indices: false, ..Default::default() },
+        ["a__b c__0", "a__b c__1__0", "a__b c__1__1__d", "e", "f"]
+    )]
+    fn test_flatten(#[case] options: FlattenOptions, #[case] keys: [&str; 5]) {
+        let flat = node().flatten(&options);
+        assert_eq!(
+            flat.iter().map(|(key, _)| key.as_str()).collect::<Vec<_>>(),
+            keys
+        );
+        assert_eq!(Node::unflatten(flat, &options), Ok(node()));
+    }
+
+    #[rstest::rstest]
+    #[case(json!(1), "")]
+    #[case(json!([]), "")]
+    #[case(json!([true]), "[0]")]
+    fn test_flatten_root(#[case] node: Node, #[case] key: &str) {
+        let flat = node.flatten(&FlattenOptions::default());
+        assert_eq!(flat[0].0, key);
+        assert_eq!(Node::unflatten(flat, &FlattenOptions::default()), Ok(node));
+    }
+
+    #[test]
+    fn test_unflatten() {
+        let options = FlattenOptions::default();
+        let node: Node =
+            Node::unflatten([("a[2]", json!(1)), ("b.c", json!(true))], &options).unwrap();
+        assert_eq!(node, json!({ "a": [null, null, 1], "b": { "c": true } }));
+
+        assert_eq!(
+            Node::<crate::map::Standard>::unflatten([("a", json!(1)), ("a.b", json!(2))], &options),
+            Err(PathError::TypeMismatch {
+                position: 1,
+                segment: "b".into(),
+                found: Kind::Number
+            })
+        );
+        assert_eq!(
+            Node::<crate::map::Standard>::unflatten(
+                [("a[0]", json!(1)), ("a.b", json!(2))],
+                &options
+            ),
+            Err(PathError::TypeMismatch {
+                position: 1,
+                segment: "b".into(),
+                found: Kind::Array
+            })
+        );
+    }
+}
@@ -0,0 +1,735 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::{Error, FromNode, MapKind, Node, Number, ObjectMap};
+
+/// Object の Map は既定の種類に作り直す
+impl FromNode for Node {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        Ok(node.clone().into_map_kind())
+    }
+}
+
+impl FromNode for Number {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        match node {
+            Node::Number(n) => Ok(n.clone()),
+            _ => Err(Error::type_mismatch::<Self, K>(node)),
+        }
+    }
+}
+
+/// Node::Null のみ変換できる
+impl FromNode for () {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        match node {
+            Node::Null => Ok(()),
+            _ => Err(Error::type_mismatch::<Self, K>(node)),
+        }
+    }
+}
+
+/// TryFrom<&Node> の変換に従う
+macro_rules! from_node_try_from {
+    ($($ty:ty),*) => {
+        $(
+            impl FromNode for $ty {
+                fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+                    <$ty>::try_from(node)
+                }
+            }
+        )*
+    };
+}
+
+from_node_try_from!(
+    String, bool, f64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// 整数に変換した後、0 は Error::InvalidValue とする
+macro_rules! from_node_non_zero {
+    ($($ty:ty),*) => {
+        $(
+            impl FromNode for std::num::NonZero<$ty> {
+                fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+                    Self::new(<$ty>::from_node(node)?)
+                        .ok_or_else(|| Error::invalid_value::<Self>("0 は指定できません"))
+                }
+            }
+        )*
+    };
+}
+
+from_node_non_zero!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// 整数の型に変換する場合の小数や範囲外の値の扱い
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberPolicy {
+    /// 値が整数であれば小数の表記（`3.0`・`1e2` など）も受け付け、小数部がある値や範囲外の値は Error::InvalidValue とする
+    #[default]
+    Strict,
+    /// 小数は 0 の方向に切り捨て、範囲外の値は型の最小値・最大値に丸める
+    Lossy,
+}
+
+/// Number を小数や範囲外の値の扱いを指定して整数の型に変換する
+/// FromNode::from_node は NumberPolicy::Strict で変換する
+///
+/// # Examples
+///
+/// ```
+/// use node::{IntegerFromNode, NumberPolicy};
+///
+/// let node: node::Node = node::json!([3.9, -1, 300, 3.0]);
+/// assert!(u8::from_node_with(&node[0], NumberPolicy::Strict).is_err());
+/// assert_eq!(u8::from_node_with(&node[3], NumberPolicy::Strict).unwrap(), 3);
+/// assert_eq!(u8::from_node_with(&node[0], NumberPolicy::Lossy).unwrap(), 3);
+/// assert_eq!(u8::from_node_with(&node[1], NumberPolicy::Lossy).unwrap(), 0);
+/// assert_eq!(u8::from_node_with(&node[2], NumberPolicy::Lossy).unwrap(), 255);
+/// ```
+pub trait IntegerFromNode: FromNode {
+    fn from_node_with<K: MapKind>(node: &Node<K>, policy: NumberPolicy) -> Result<Self, Error>;
+}
+
+macro_rules! integer_from_node {
+    ($($ty:ty),*) => {
+        $(
+            impl IntegerFromNode for $ty {
+                fn from_node_with<K: MapKind>(
+                    node: &Node<K>,
+                    policy: NumberPolicy,
+                ) -> Result<Self, Error> {
+                    match (policy, node) {
+                        (NumberPolicy::Strict, _) => Self::from_node(node),
+                        (NumberPolicy::Lossy, Node::Number(n)) => Ok(match (n.as_i128(), n.as_u128()) {
+                            (Some(i), _) => <$ty>::try_from(i)
+                                .unwrap_or(if i < 0 { <$ty>::MIN } else { <$ty>::MAX }),
+                            (None, Some(u)) => <$ty>::try_from(u).unwrap_or(<$ty>::MAX),
+                            // f64 からの as による変換は 0 の方向に切り捨て、範囲外の値は最小値・最大値に丸める
+                            (None, None) => n.as_f64() as $ty,
+                        }),
+                        (NumberPolicy::Lossy, _) => Err(Error::type_mismatch::<$ty, K>(node)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+integer_from_node!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// １文字の文字列から変換する
+impl FromNode for char {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        let s = as_str::<Self, K>(node)?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(Error::invalid_value::<Self>(format!(
+                "`{s}` は１文字ではありません"
+            ))),
+        }
+    }
+}
+
+impl FromNode for std::path::PathBuf {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        as_str::<Self, K>(node).map(Self::from)
+    }
+}
+
+/// 文字列を FromStr で解釈する
+macro_rules! from_node_from_str {
+    ($($ty:ty),*) => {
+        $(
+            impl FromNode for $ty {
+                fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+                    let s = as_str::<Self, K>(node)?;
+                    s.parse()
+                        .map_err(|e| Error::invalid_value::<Self>(format!("`{s}` を解釈できません（{e}）")))
+                }
+            }
+        )*
+    };
+}
+
+from_node_from_str!(
+    std::net::IpAddr,
+    std::net::Ipv4Addr,
+    std::net::Ipv6Addr,
+    std::net::SocketAddr,
+    std::net::SocketAddrV4,
+    std::net::SocketAddrV6
+);
+
+/// f64 として読み取り、最も近い f32 に丸める
+impl FromNode for f32 {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        node.as_f64()
+            .map(|v| v as f32)
+            .ok_or_else(|| Error::type_mismatch::<Self, K>(node))
+    }
+}
+
+/// 文字列の値を返却し、文字列でなければ T に変換できないことを表すエラーを返却する
+pub(crate) fn as_str<T: ?Sized, K: MapKind>(node: &Node<K>) -> Result<&str, Error> {
+    node.as_str()
+        .ok_or_else(|| Error::type_mismatch::<T, K>(node))
+}
+
+/// 変換した値を包む
+macro_rules! from_node_pointer {
+    ($($pointer:ident),*) => {
+        $(
+            impl<T: FromNode> FromNode for $pointer<T> {
+                fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+                    T::from_node(node).map($pointer::new)
+                }
+            }
+        )*
+    };
+}
+
+from_node_pointer!(Box, Rc, Arc);
+
+/// 文字列を String を経由せずに保持する
+macro_rules! from_node_str_pointer {
+    ($($pointer:ident),*) => {
+        $(
+            impl FromNode for $pointer<str> {
+                fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+                    as_str::<Self, K>(node).map(Self::from)
+                }
+            }
+        )*
+    };
+}
+
+from_node_str_pointer!(Box, Rc, Arc);
+
+/// Node から借用できないため、常に Cow::Owned に変換する
+impl FromNode for std::borrow::Cow<'_, str> {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        String::from_node(node).map(Self::Owned)
+    }
+}
+
+/// Node::Null は None に変換する
+impl<T: FromNode> FromNode for Option<T> {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        match node {
+            Node::Null => Ok(None),
+            _ => T::from_node(node).map(Some),
+        }
+    }
+}
+
+impl<T: FromNode> FromNode for Vec<T> {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        match node {
+            Node::Array(array) => array
+                .iter()
+                .enumerate()
+                .map(|(i, value)| T::from_node(value).map_err(|e| e.at_index(i)))
+                .collect(),
+            _ => Err(Error::type_mismatch::<Self, K>(node)),
+        }
+    }
+}
+
+impl<T: FromNode> FromNode for std::collections::BTreeMap<String, T> {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        from_object(node)
+    }
+}
+
+impl<T: FromNode, S: std::hash::BuildHasher + Default> FromNode
+    for std::collections::HashMap<String, T, S>
+{
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        from_object(node)
+    }
+}
+
+/// Array を集合に変換する場合の重複した要素の扱い
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateElements {
+    /// 重複した要素を無視する
+    #[default]
+    Ignore,
+    /// 重複した要素があれば Error::InvalidValue とする
+    Error,
+}
+
+/// Array を重複した要素の扱いを指定して集合に変換する
+/// FromNode::from_node は DuplicateElements::Ignore で変換する
+///
+/// # Examples
+///
+/// ```
+/// use node::{DuplicateElements, FromNode, SetFromNode};
+/// use std::collections::BTreeSet;
+///
+/// let node: node::Node = node::json!(["b", "a", "b"]);
+/// assert_eq!(BTreeSet::<String>::from_node(&node).unwrap().len(), 2);
+/// assert!(BTreeSet::<String>::from_node_with(&node, DuplicateElements::Error).is_err());
+/// ```
+pub trait SetFromNode: FromNode {
+    fn from_node_with<K: MapKind>(
+        node: &Node<K>,
+        duplicates: DuplicateElements,
+    ) -> Result<Self, Error>;
+}
+
+impl<T: FromNode + Ord> SetFromNode for std::collections::BTreeSet<T> {
+    fn from_node_with<K: MapKind>(
+        node: &Node<K>,
+        duplicates: DuplicateElements,
+    ) -> Result<Self, Error> {
+        let mut set = Self::new();
+        from_array::<Self, _, _>(node, duplicates, |value| set.insert(value))?;
+        Ok(set)
+    }
+}
+
+impl<T, S> SetFromNode for std::collections::HashSet<T, S>
+where
+    T: FromNode + Eq + std::hash::Hash,
+    S: std::hash::BuildHasher + Default,
+{
+    fn from_node_with<K: MapKind>(
+        node: &Node<K>,
+        duplicates: DuplicateElements,
+    ) -> Result<Self, Error> {
+        let mut set = Self::default();
+        from_array::<Self, _, _>(node, duplicates, |value| set.insert(value))?;
+        Ok(set)
+    }
+}
+
+impl<T: FromNode + Ord> FromNode for std::collections::BTreeSet<T> {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        Self::from_node_with(node, DuplicateElements::Ignore)
+    }
+}
+
+impl<T, S> FromNode for std::collections::HashSet<T, S>
+where
+    T: FromNode + Eq + std::hash::Hash,
+    S: std::hash::BuildHasher + Default,
+{
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        Self::from_node_with(node, DuplicateElements::Ignore)
+    }
+}
+
+/// Array の要素を順に T に変換して集合 S の insert に渡す
+/// insert は集合に加えた場合に true を返却する
+fn from_array<S, K, T>(
+    node: &Node<K>,
+    duplicates: DuplicateElements,
+    mut insert: impl FnMut(T) -> bool,
+) -> Result<(), Error>
+where
+    S: ?Sized,
+    K: MapKind,
+    T: FromNode,
+{
+    let Node::Array(array) = node else {
+        return Err(Error::type_mismatch::<S, K>(node));
+    };
+    for (index, value) in array.iter().enumerate() {
+        let value = T::from_node(value).map_err(|e| e.at_index(index))?;
+        if !insert(value) && duplicates == DuplicateElements::Error {
+            return Err(Error::invalid_value::<S>(format!(
+                "{index} 番目の要素が重複しています"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// 要素数が一致する Array から変換する
+macro_rules! from_node_tuple {
+    ($(($len:literal: $($name:ident),+)),*) => {
+        $(
+            impl<$($name: FromNode),+> FromNode for ($($name,)+) {
+                fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+                    match node {
+                        Node::Array(array) if array.len() == $len => {
+                            let mut iter = array.iter().enumerate();
+                            Ok(($({
+                                let (i, value) = iter.next().unwrap();
+                                $name::from_node(value).map_err(|e| e.at_index(i))?
+                            },)+))
+                        }
+                        Node::Array(array) => Err(length_mismatch::<Self>($len, array.len())),
+                        _ => Err(Error::type_mismatch::<Self, K>(node)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_node_tuple!(
+    (1: A),
+    (2: A, B),
+    (3: A, B, C),
+    (4: A, B, C, D),
+    (5: A, B, C, D, E),
+    (6: A, B, C, D, E, F),
+    (7: A, B, C, D, E, F, G),
+    (8: A, B, C, D, E, F, G, H),
+    (9: A, B, C, D, E, F, G, H, I),
+    (10: A, B, C, D, E, F, G, H, I, J),
+    (11: A, B, C, D, E, F, G, H, I, J, L),
+    (12: A, B, C, D, E, F, G, H, I, J, L, M)
+);
+
+/// 要素数が N の Array から変換する
+impl<T: FromNode, const N: usize> FromNode for [T; N] {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        match node {
+            Node::Array(array) if array.len() == N => Ok(Vec::<T>::from_node(node)?
+                .try_into()
+                .unwrap_or_else(|_| unreachable!("要素数は確認済み"))),
+            Node::Array(array) => Err(length_mismatch::<Self>(N, array.len())),
+            _ => Err(Error::type_mismatch::<Self, K>(node)),
+        }
+    }
+}
+
+fn length_mismatch<T>(expected: usize, actual: usize) -> Error {
+    Error::invalid_value::<T>(format!("要素数が {expected} ではなく {actual} でした"))
+}
+
+/// Object の値をそれぞれ T に変換し、キーとともに集める
+fn from_object<K, T, C>(node: &Node<K>) -> Result<C, Error>
+where
+    K: MapKind,
+    T: FromNode,
+    C: FromIterator<(String, T)>,
+{
+    match node {
+        Node::Object(object) => object
+            .iter()
+            .map(|(key, value)| {
+                let value = T::from_node(value).map_err(|e| e.at_key(key.to_string()))?;
+                Ok((key.to_string(), value))
+            })
+            .collect(),
+        _ => Err(Error::type_mismatch::<C, K>(node)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+
+    #[rstest::rstest]
+    #[case(json!("a"), Ok("a".to_string()))]
+    #[case(json!(1), Err(()))]
+    fn test_string(#[case] node: Node, #[case] expected: Result<String, ()>) {
+        assert_eq!(String::from_node(&node).map_err(|_| ()), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(json!(-1), Ok(-1))]
+    #[case(json!(128), Err(()))]
+    #[case(json!(1.5), Err(()))]
+    #[case(json!("1"), Err(()))]
+    fn test_i8(#[case] node: Node, #[case] expected: Result<i8, ()>) {
+        assert_eq!(i8::from_node(&node).map_err(|_| ()), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(json!(3), Ok(3.0))]
+    #[case(json!(0.5), Ok(0.5))]
+    #[case(json!(true), Err(()))]
+    fn test_f64(#[case] node: Node, #[case] expected: Result<f64, ()>) {
+        assert_eq!(f64::from_node(&node).map_err(|_| ()), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(json!(null), Ok(None))]
+    #[case(json!([1, null]), Ok(Some(vec![Some(1), None])))]
+    #[case(json!([]), Ok(Some(vec![])))]
+    #[case(json!({}), Err(()))]
+    #[case(json!([1, "a"]), Err(()))]
+    fn test_option_vec(#[case] node: Node, #[case] expected: Result<Option<Vec<Option<u8>>>, ()>) {
+        assert_eq!(
+            Option::<Vec<Option<u8>>>::from_node(&node).map_err(|_| ()),
+            expected
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(json!([1, "a", [true]]), Ok((1, "a".to_string(), (true,))))]
+    #[case(json!([1, "a"]), Err(("", "値を (u8, String, (bool,)) に変換できません（要素数が 3 ではなく 2 でした）")))]
+    #[case(json!([1, "a", [true, false]]), Err(("/2", "値を (bool,) に変換できません（要素数が 1 ではなく 2 でした）")))]
+    #[case(json!([1, 2, [true]]), Err(("/1", "number の値は String に変換できません")))]
+    #[case(json!([1, "a", [0]]), Err(("/2/0", "number の値は bool に変換できません")))]
+    #[case(json!({}), Err(("", "object の値は (u8, String, (bool,)) に変換できません")))]
+    fn test_tuple(
+        #[case] node: Node,
+        #[case] expected: Result<(u8, String, (bool,)), (&str, &str)>,
+    ) {
+        let result = <(u8, String, (bool,))>::from_node(&node).map_err(describe);
+        assert_eq!(
+            result,
+            expected.map_err(|(path, message)| (path.into(), message.into()))
+        );
+    }
+
+    /// エラーの位置と変換に失敗した理由を返却する
+    fn describe(e: Error) -> (String, String) {
+        (e.path().to_string(), e.inner().to_string())
+    }
+
+    #[rstest::rstest]
+    #[case(json!([1, 2.5, -3]), Ok([1.0, 2.5, -3.0]))]
+    #[case(json!([1, 2]), Err(("", "値を [f64; 3] に変換できません（要素数が 3 ではなく 2 でした）")))]
+    #[case(json!([1, 2, 3, 4]), Err(("", "値を [f64; 3] に変換できません（要素数が 3 ではなく 4 でした）")))]
+    #[case(json!([1, 2, null]), Err(("/2", "null の値は f64 に変換できません")))]
+    fn test_array(#[case] node: Node, #[case] expected: Result<[f64; 3], (&str, &str)>) {
+        let result = <[f64; 3]>::from_node(&node).map_err(describe);
+        assert_eq!(
+            result,
+            expected.map_err(|(path, message)| (path.into(), message.into()))
+        );
+        assert_eq!(
+            <[u8; 0]>::from_node(&Node::<crate::map::Standard>::Array(vec![])).unwrap(),
+            []
+        );
+    }
+
+    #[test]
+    fn test_tuple_12() {
+        let node: Node = (0..12).map(Node::from).collect();
+        type Tuple = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, f64);
+        assert_eq!(
+            Tuple::from_node(&node).unwrap(),
+            (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11.0)
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(json!(3), NumberPolicy::Strict, Ok(3))]
+    #[case(json!(3.0), NumberPolicy::Strict, Ok(3))]
+    #[case(json!(1e2), NumberPolicy::Strict, Ok(100))]
+    #[case(json!(3.9), NumberPolicy::Strict, Err("値を i8 に変換できません（3.9 は範囲外か整数ではありません）".into()))]
+    #[case(json!(200), NumberPolicy::Strict, Err("値を i8 に変換できません（200 は範囲外か整数ではありません）".into()))]
+    #[case(json!(3.9), NumberPolicy::Lossy, Ok(3))]
+    #[case(json!(-3.9), NumberPolicy::Lossy, Ok(-3))]
+    #[case(json!(200), NumberPolicy::Lossy, Ok(127))]
+    #[case(json!(-200), NumberPolicy::Lossy, Ok(-128))]
+    #[case(json!(18446744073709551615u64), NumberPolicy::Lossy, Ok(127))]
+    #[case(json!(-1e300), NumberPolicy::Lossy, Ok(-128))]
+    #[case(json!("3"), NumberPolicy::Lossy, Err("string の値は i8 に変換できません".into()))]
+    fn test_integer(
+        #[case] node: Node,
+        #[case] policy: NumberPolicy,
+        #[case] expected: Result<i8, String>,
+    ) {
+        assert_eq!(
+            i8::from_node_with(&node, policy).map_err(|e| e.to_string()),
+            expected
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(json!([2, 1, 2]), DuplicateElements::Ignore, Ok(vec![1, 2]))]
+    #[case(json!([2, 1, 2]), DuplicateElements::Error, Err("値を BTreeSet<u8> に変換できません（2 番目の要素が重複しています）".into()))]
+    #[case(json!([2, 1]), DuplicateElements::Error, Ok(vec![1, 2]))]
+    #[case(json!([]), DuplicateElements::Error, Ok(vec![]))]
+    #[case(json!({}), DuplicateElements::Ignore, Err("object の値は BTreeSet<u8> に変換できません".into()))]
+    fn test_set(
+        #[case] node: Node,
+        #[case] duplicates: DuplicateElements,
+        #[case] expected: Result<Vec<u8>, String>,
+    ) {
+        let message = |e: Error| e.to_string();
+        let set = std::collections::BTreeSet::<u8>::from_node_with(&node, duplicates);
+        assert_eq!(set.map(Vec::from_iter).map_err(message), expected);
+        let set = std::collections::HashSet::<u8>::from_node_with(&node, duplicates);
+        let set = set.map(|set| {
+            let mut values = Vec::from_iter(set);
+            values.sort();
+            values
+        });
+        let expected = expected.map_err(|message| message.replace("BTreeSet", "HashSet"));
+        assert_eq!(set.map_err(message), expected);
+    }
+
+    #[test]
+    fn test_pointer() {
+        let node: Node = json!([1, 2]);
+        assert_eq!(*Box::<Vec<u8>>::from_node(&node).unwrap(), vec![1, 2]);
+        assert_eq!(*Rc::<(u8, u8)>::from_node(&node).unwrap(), (1, 2));
+        assert_eq!(*Arc::<Node>::from_node(&node).unwrap(), node);
+        assert!(Box::<String>::from_node(&node).is_err());
+    }
+
+    #[test]
+    fn test_path() {
+        let node: Node = json!({ "a": [{ "b": [null, "x"] }], "c~/": [1, 2] });
+        type Nested = std::collections::BTreeMap<
+            String,
+            Vec<std::collections::HashMap<String, Vec<Option<bool>>>>,
+        >;
+        let error = Nested::from_node(&node).unwrap_err();
+        assert_eq!(
+            describe(error),
+            (
+                "/a/0/b/1".into(),
+                "string の値は bool に変換できません".into()
+            )
+        );
+
+        let error =
+            std::collections::BTreeMap::<String, std::collections::BTreeSet<String>>::from_node(
+                &node,
+            )
+            .unwrap_err();
+        assert_eq!(error.path().tokens(), ["a", "0"]);
+        let error =
+            std::collections::BTreeMap::<String, Vec<String>>::from_node(&node).unwrap_err();
+        assert_eq!(error.path().to_string(), "/a/0");
+        assert_eq!(
+            <Vec<String>>::from_node(&node["c~/"])
+                .unwrap_err()
+                .path()
+                .to_string(),
+            "/0"
+        );
+        let node: Node = json!({ "c~/": [1, null] });
+        let error = std::collections::BTreeMap::<String, Vec<f64>>::from_node(&node).unwrap_err();
+        assert_eq!(error.path().to_string(), "/c~0~1/1");
+        assert_eq!(
+            error.to_string(),
+            "`/c~0~1/1` でnull の値は f64 に変換できません"
+        );
+    }
+
+    #[test]
+    fn test_map() {
+        let node: Node = json!({ "a": [true], "b": [] });
+        let expected = [("a".to_string(), vec![true]), ("b".to_string(), vec![])];
+        assert_eq!(
+            std::collections::BTreeMap::<String, Vec<bool>>::from_node(&node).unwrap(),
+            std::collections::BTreeMap::from(expected.clone())
+        );
+        assert_eq!(
+            std::collections::HashMap::<String, Vec<bool>>::from_node(
+                &node.clone().into_map_kind::<crate::map::Hashed>()
+            )
+            .unwrap(),
+            std::collections::HashMap::from(expected)
+        );
+        assert!(std::collections::BTreeMap::<String, bool>::from_node(&node).is_err());
+        assert!(
+            std::collections::HashMap::<String, bool>::from_node(
+                &Node::<crate::map::Standard>::Array(vec![])
+            )
+            .is_err()
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(json!(1), Ok(1))]
+    #[case(json!(0), Err("値を NonZero<u32> に変換できません（0 は指定できません）".into()))]
+    #[case(json!(-1), Err("値を u32 に変換できません（-1 は範囲外か整数ではありません）".into()))]
+    #[case(json!("1"), Err("string の値は u32 に変換できません".into()))]
+    fn test_non_zero(#[case] node: Node, #[case] expected: Result<u32, String>) {
+        let result = std::num::NonZeroU32::from_node(&node).map(std::num::NonZeroU32::get);
+        assert_eq!(result.map_err(|e| e.to_string()), expected);
+    }
+
+    #[test]
+    fn test_str_pointer() {
+        let node: Node = json!(["a", 1]);
+        assert_eq!(&*Box::<str>::from_node(&node[0]).unwrap(), "a");
+        assert_eq!(&*Rc::<str>::from_node(&node[0]).unwrap(), "a");
+        assert_eq!(&*Arc::<str>::from_node(&node[0]).unwrap(), "a");
+        assert!(matches!(
+            std::borrow::Cow::<str>::from_node(&node[0]).unwrap(),
+            std::borrow::Cow::Owned(s) if s == "a"
+        ));
+        assert_eq!(
+            Box::<str>::from_node(&node[1]).unwrap_err().to_string(),
+            "number の値は Box<str> に変換できません"
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(json!("a"), Ok('a'))]
+    #[case(json!("あ"), Ok('あ'))]
+    #[case(json!("ab"), Err("値を char に変換できません（`ab` は１文字ではありません）".into()))]
+    #[case(json!(""), Err("値を char に変換できません（`` は１文字ではありません）".into()))]
+    #[case(json!(1), Err("number の値は char に変換できません".into()))]
+    fn test_char(#[case] node: Node, #[case] expected: Result<char, String>) {
+        let result = char::from_node(&node).map_err(|e| e.to_string());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_str() {
+        use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+        let node: Node = json!({
+            "path": "/etc/app.json",
+            "ip": "::1",
+            "v4": "127.0.0.1",
+            "addr": "127.0.0.1:80",
+            "bad": "localhost:80",
+        });
+        assert_eq!(
+            std::path::PathBuf::from_node(&node["path"]).unwrap(),
+            std::path::Path::new("/etc/app.json")
+        );
+        assert_eq!(
+            IpAddr::from_node(&node["ip"]).unwrap(),
+            IpAddr::from([0, 0, 0, 0, 0, 0, 0, 1])
+        );
+        assert_eq!(
+            Ipv4Addr::from_node(&node["v4"]).unwrap(),
+            Ipv4Addr::LOCALHOST
+        );
+        assert_eq!(
+            SocketAddr::from_node(&node["addr"]).unwrap(),
+            SocketAddr::from(([127, 0, 0, 1], 80))
+        );
+        match SocketAddr::from_node(&node["bad"]) {
+            Err(e @ Error::InvalidValue { .. }) => {
+                assert!(e.to_string().starts_with(
+                    "値を SocketAddr に変換できません（`localhost:80` を解釈できません"
+                ))
+            }
+            result => panic!("{result:?}"),
+        }
+        assert!(std::path::PathBuf::from_node(&node["missing"]).is_err());
+    }
+
+    #[test]
+    fn test_generic() {
+        fn get<T: FromNode>(node: &Node, key: &str) -> Result<T, Error> {
+            T::from_node(&node[key])
+        }
+
+        let node = json!({ "s": "x", "u": 7, "b": false, "n": null, "f": 0.25, "o": { "k": [] } });
+        assert_eq!(get::<String>(&node, "s").unwrap(), "x");
+        assert_eq!(get::<u64>(&node, "u").unwrap(), 7);
+        assert!(!get::<bool>(&node, "b").unwrap());
+        get::<()>(&node, "n").unwrap();
+        assert_eq!(get::<f32>(&node, "f").unwrap(), 0.25);
+        assert_eq!(get::<Number>(&node, "u").unwrap(), Number::from(7));
+        assert_eq!(get::<Node>(&node, "o").unwrap(), json!({ "k": [] }));
+        assert!(get::<u64>(&node, "s").is_err());
+    }
+}
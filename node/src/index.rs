@@ -0,0 +1,112 @@
+use crate::{MapKind, Node, ObjectMap};
+
+/// Object 以外やキーがない場合は Node::Null を返却する
+///
+/// # Examples
+///
+/// ```
+/// let mut doc: node::Node = node::Node::Null;
+/// doc["config"]["retries"] = node::Node::Number(3.into());
+/// assert_eq!(doc["config"]["retries"], node::Node::Number(3.into()));
+/// assert_eq!(doc["config"]["missing"][0], node::Node::Null);
+/// ```
+impl<K: MapKind> std::ops::Index<&str> for Node<K> {
+    type Output = Node<K>;
+
+    fn index(&self, key: &str) -> &Node<K> {
+        self.get(key).unwrap_or(&Node::Null)
+    }
+}
+
+/// Array 以外や範囲外の場合は Node::Null を返却する
+impl<K: MapKind> std::ops::Index<usize> for Node<K> {
+    type Output = Node<K>;
+
+    fn index(&self, index: usize) -> &Node<K> {
+        self.get_index(index).unwrap_or(&Node::Null)
+    }
+}
+
+/// キーがない場合は Node::Null を格納してその可変参照を返却する
+/// Node::Null は空の Object に置き換えてから格納する
+/// Object・Null 以外の場合はパニックする
+impl<K: MapKind> std::ops::IndexMut<&str> for Node<K> {
+    fn index_mut(&mut self, key: &str) -> &mut Node<K> {
+        if self.is_null() {
+            *self = Node::Object(K::Map::default());
+        }
+
+        let kind = self.kind();
+        let Node::Object(object) = self else {
+            panic!("{kind} の値をキー `{key}` で変更できません");
+        };
+        if !object.contains_key(key) {
+            object.insert(key.to_string(), Node::Null);
+        }
+        object.get_mut(key).expect("直前に格納している")
+    }
+}
+
+/// Array 以外や範囲外の場合はパニックする
+impl<K: MapKind> std::ops::IndexMut<usize> for Node<K> {
+    fn index_mut(&mut self, index: usize) -> &mut Node<K> {
+        let kind = self.kind();
+        let Node::Array(array) = self else {
+            panic!("{kind} の値を添字 {index} で変更できません");
+        };
+        let len = array.len();
+        array
+            .get_mut(index)
+            .unwrap_or_else(|| panic!("添字 {index} が要素数 {len} の範囲外です"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Map, Node};
+
+    #[test]
+    fn test_index() {
+        let node: Node = Node::Object(Map::from([(
+            "a".to_string(),
+            Node::Array(vec![Node::Bool(true)]),
+        )]));
+        assert_eq!(node["a"][0], Node::Bool(true));
+        assert_eq!(node["a"][1], Node::Null);
+        assert_eq!(node["a"]["b"], Node::Null);
+        assert_eq!(node["b"], Node::Null);
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut node: Node = Node::Object(Map::new());
+        node["a"]["b"] = Node::Array(vec![Node::Null]);
+        node["a"]["b"][0] = Node::Bool(false);
+        assert_eq!(
+            node,
+            Node::Object(Map::from([(
+                "a".to_string(),
+                Node::Object(Map::from([(
+                    "b".to_string(),
+                    Node::Array(vec![Node::Bool(false)])
+                )]))
+            )]))
+        );
+    }
+
+    #[rstest::rstest]
+    #[should_panic(expected = "number の値をキー `a` で変更できません")]
+    #[case(Node::Number(1.into()))]
+    #[should_panic(expected = "array の値をキー `a` で変更できません")]
+    #[case(Node::Array(vec![]))]
+    fn test_index_mut_panic(#[case] mut node: Node) {
+        node["a"] = Node::Null;
+    }
+
+    #[test]
+    #[should_panic(expected = "添字 1 が要素数 1 の範囲外です")]
+    fn test_index_mut_out_of_range() {
+        let mut node: Node = Node::Array(vec![Node::Null]);
+        node[1] = Node::Null;
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{MapKind, Node};
+
+/// Node の種類を表現する
+/// 変換に失敗した場合のメッセージで期待した種類と実際の種類を示すために利用する
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    String,
+    Number,
+    Bool,
+    Null,
+    Array,
+    Object,
+}
+
+/// JSONの型名（`string`・`number`・`boolean`・`null`・`array`・`object`）を出力する
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kind::String => write!(f, "string"),
+            Kind::Number => write!(f, "number"),
+            Kind::Bool => write!(f, "boolean"),
+            Kind::Null => write!(f, "null"),
+            Kind::Array => write!(f, "array"),
+            Kind::Object => write!(f, "object"),
+        }
+    }
+}
+
+/// 期待した種類と実際の種類が異なることを表現する
+#[derive(thiserror::Error, std::fmt::Debug, Clone, Copy, PartialEq, Eq)]
+#[error("{expected} を期待しましたが {found} の値でした")]
+pub struct KindError {
+    pub expected: Kind,
+    pub found: Kind,
+}
+
+impl<K: MapKind> Node<K> {
+    /// 種類を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let node: node::Node = node::Node::Array(vec![]);
+    /// assert_eq!(node.kind(), node::Kind::Array);
+    /// assert_eq!(node.kind().to_string(), "array");
+    /// assert!(node.is_array());
+    /// ```
+    pub fn kind(&self) -> Kind {
+        match self {
+            Node::String(_) => Kind::String,
+            Node::Number(_) => Kind::Number,
+            Node::Bool(_) => Kind::Bool,
+            Node::Null => Kind::Null,
+            Node::Array(_) => Kind::Array,
+            Node::Object(_) => Kind::Object,
+        }
+    }
+
+    /// String か
+    pub fn is_string(&self) -> bool {
+        self.kind() == Kind::String
+    }
+
+    /// Number か
+    pub fn is_number(&self) -> bool {
+        self.kind() == Kind::Number
+    }
+
+    /// i64 で表現できる整数の Number か
+    pub fn is_i64(&self) -> bool {
+        matches!(self, Node::Number(n) if n.is_i64())
+    }
+
+    /// u64 で表現できる整数の Number か
+    pub fn is_u64(&self) -> bool {
+        matches!(self, Node::Number(n) if n.is_u64())
+    }
+
+    /// 小数（指数表記を含む）の Number か
+    pub fn is_f64(&self) -> bool {
+        matches!(self, Node::Number(n) if n.is_f64())
+    }
+
+    /// Bool か
+    pub fn is_bool(&self) -> bool {
+        self.kind() == Kind::Bool
+    }
+
+    /// Null か
+    pub fn is_null(&self) -> bool {
+        self.kind() == Kind::Null
+    }
+
+    /// Array か
+    pub fn is_array(&self) -> bool {
+        self.kind() == Kind::Array
+    }
+
+    /// Object か
+    pub fn is_object(&self) -> bool {
+        self.kind() == Kind::Object
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Kind, Map, Node};
+
+    #[rstest::rstest]
+    #[case(Node::String("a".into()), Kind::String)]
+    #[case(Node::Number(1.into()), Kind::Number)]
+    #[case(Node::Bool(false), Kind::Bool)]
+    #[case(Node::Null, Kind::Null)]
+    #[case(Node::Array(vec![]), Kind::Array)]
+    #[case(Node::Object(Map::new()), Kind::Object)]
+    fn test_kind(#[case] node: Node, #[case] kind: Kind) {
+        assert_eq!(node.kind(), kind);
+        let predicates = [
+            (Kind::String, node.is_string()),
+            (Kind::Number, node.is_number()),
+            (Kind::Bool, node.is_bool()),
+            (Kind::Null, node.is_null()),
+            (Kind::Array, node.is_array()),
+            (Kind::Object, node.is_object()),
+        ];
+        for (k, is) in predicates {
+            assert_eq!(is, k == kind);
+        }
+    }
+
+    #[rstest::rstest]
+    #[case("-1", true, false, false)]
+    #[case("18446744073709551615", false, true, false)]
+    #[case("1.0", false, false, true)]
+    fn test_number(#[case] input: &str, #[case] i: bool, #[case] u: bool, #[case] f: bool) {
+        let node: Node = Node::Number(input.parse().unwrap());
+        assert_eq!((node.is_i64(), node.is_u64(), node.is_f64()), (i, u, f));
+    }
+}
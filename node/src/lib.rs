@@ -1,24 +1,364 @@
+/// Node の値を取り出すメソッド
+mod access;
+/// 文字列・Array・Object を共有するJSONデータ
+mod arc;
+/// アリーナに確保したJSONデータ
+mod arena;
+/// 入力の文字列を借用するJSONデータ
+mod borrowed;
+/// Object・Array を組み立てるビルダー
+mod builder;
+/// Node の比較
+mod compare;
+/// Rust の値から Node への変換
+mod convert;
+/// chrono の日時の型と Node の相互変換
+#[cfg(feature = "chrono")]
+mod datetime;
+/// rust_decimal::Decimal と Node の相互変換
+#[cfg(feature = "decimal")]
+mod decimal;
+/// Node::Object のキーに対応する位置
+mod entry;
+/// 入れ子の値とキーの表記で平坦化した値の相互変換
+mod flatten;
+/// Node を Rust の値に変換する FromNode の実装
+mod from_node;
+/// Node の添字による参照と変更
+mod index;
+/// Node の種類
+mod kind;
+/// JSONの表記に近い形で Node を構築するマクロ
+mod macros;
+/// Node::Object のキーと値
+pub mod map;
+/// RFC 7386 の JSON Merge Patch
+mod merge;
+/// Node の値を変更するメソッド
+mod modify;
+/// 同じ内容の値を同じ木に揃える正規化
+mod normalize;
+/// JSONの数値
+mod number;
+/// RFC 6902 の JSON Patch
+pub mod patch;
+/// キーと添字を順に辿るパス
+mod path;
+/// RFC 6901 の JSON Pointer
+mod pointer;
+/// 値を隠す処理
+mod redact;
+/// Node から JSONの文字列への変換
+pub mod serializer;
+/// 時間を表す型と Node の相互変換
+mod time;
+/// Rust の値を Node に変換する ToNode の実装
+mod to_node;
+/// 概要を表示するための切り詰め
+mod truncate;
+/// uuid::Uuid と Node の相互変換
+#[cfg(feature = "uuid")]
+mod uuid;
+/// Node の全ての値を辿る処理
+mod walk;
+
+pub use arc::ArcNode;
+pub use arena::ArenaNode;
+pub use borrowed::BorrowedNode;
+pub use builder::{ArrayBuilder, ObjectBuilder};
+pub use compare::Tolerance;
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+pub use flatten::FlattenOptions;
+pub use from_node::{DuplicateElements, IntegerFromNode, NumberPolicy, SetFromNode};
+pub use kind::{Kind, KindError};
+pub use map::{Map, MapKind, ObjectMap};
+pub use normalize::NormalizeOptions;
+pub use number::{Number, ParseNumberError};
+pub use path::{Path, PathError, PathSegment};
+pub use pointer::{Pointer, PointerError};
+pub use redact::{RedactAction, Redactor};
+pub use time::TimeUnit;
+pub use truncate::TruncateOptions;
+
+/// Node を Rust の値に変換する
+/// 文字列・数値・真偽値の型にも実装しており、型を引数にした汎用的な読み取りに利用できる
+///
+/// # Examples
+///
+/// ```
+/// use node::FromNode;
+///
+/// fn get<T: FromNode>(node: &node::Node, key: &str) -> Result<T, node::Error> {
+///     T::from_node(&node[key])
+/// }
+///
+/// let node = node::json!({ "name": "a", "port": 80 });
+/// assert_eq!(get::<String>(&node, "name").unwrap(), "a");
+/// assert_eq!(get::<u16>(&node, "port").unwrap(), 80);
+/// assert!(get::<u8>(&node, "name").is_err());
+/// ```
 pub trait FromNode: Sized {
-    fn from_node(node: &Node) -> Result<Self, Error>;
+    /// from_node がJSONオブジェクトから読み出すキーの一覧
+    /// Some の場合、一覧にないキーの値は構築せずに読み飛ばしても良い
+    const FIELDS: Option<&'static [&'static str]> = None;
+
+    /// Object の Map の種類によらず変換できるよう、MapKind について汎用とする
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error>;
+}
+
+/// FromNode とは逆に Rust の値から Node を構築する
+/// 構築した Node は serializer で JSONの文字列に変換できる
+///
+/// # Examples
+///
+/// ```
+/// use node::ToNode;
+///
+/// let value = (1, vec![Some("a"), None]);
+/// assert_eq!(value.to_node(), node::json!([1, ["a", null]]));
+/// assert_eq!(node::serializer::to_string(&value.to_node()), r#"[1,["a",null]]"#);
+/// ```
+pub trait ToNode {
+    fn to_node(&self) -> Node;
 }
 
 /// JSONデータを表現する
-#[derive(std::fmt::Debug, Clone, PartialEq)]
-pub enum Node {
+/// Object の Map の種類は K で指定し、既定では node::Map を使う
+pub enum Node<K: MapKind = map::Standard> {
     String(String),
-    Number(f64),
-    True,
-    False,
+    Number(Number),
+    Bool(bool),
     Null,
-    Array(Vec<Node>),
-    Object(std::collections::BTreeMap<String, Node>),
-    EOF,
+    Array(Vec<Node<K>>),
+    Object(K::Map<Node<K>>),
+}
+
+impl<K: MapKind> Node<K> {
+    /// Object の Map を L が表す種類に作り直して返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let node: node::Node = node::Node::Object(node::Map::from([("a".to_string(), node::Node::Null)]));
+    /// let node = node.into_map_kind::<node::map::Sorted>();
+    /// assert_eq!(
+    ///     node,
+    ///     node::Node::Object(std::collections::BTreeMap::from([("a".to_string(), node::Node::Null)]))
+    /// );
+    /// ```
+    pub fn into_map_kind<L: MapKind>(self) -> Node<L> {
+        match self {
+            Node::String(s) => Node::String(s),
+            Node::Number(n) => Node::Number(n),
+            Node::Bool(b) => Node::Bool(b),
+            Node::Null => Node::Null,
+            Node::Array(array) => Node::Array(array.into_iter().map(Node::into_map_kind).collect()),
+            Node::Object(object) => Node::Object(
+                object
+                    .into_iter()
+                    .map(|(key, value)| (key, value.into_map_kind()))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// 値を取り出し、元の位置には Node::Null を残す
+    /// 部分木を複製せずに組み替える場合に利用する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut node: node::Node = node::Node::Array(vec![node::Node::Bool(true)]);
+    /// let taken = node[0].take();
+    /// assert_eq!(taken, node::Node::Bool(true));
+    /// assert_eq!(node, node::Node::Array(vec![node::Node::Null]));
+    /// ```
+    pub fn take(&mut self) -> Node<K> {
+        std::mem::take(self)
+    }
+}
+
+/// Node::Null を返却する
+impl<K: MapKind> Default for Node<K> {
+    fn default() -> Self {
+        Node::Null
+    }
+}
+
+// K 自体は Clone などを実装しなくても良いため、derive を使わずに実装する
+impl<K: MapKind> Clone for Node<K> {
+    fn clone(&self) -> Self {
+        match self {
+            Node::String(s) => Node::String(s.clone()),
+            Node::Number(n) => Node::Number(n.clone()),
+            Node::Bool(b) => Node::Bool(*b),
+            Node::Null => Node::Null,
+            Node::Array(array) => Node::Array(array.clone()),
+            Node::Object(object) => Node::Object(object.clone()),
+        }
+    }
+}
+
+impl<K: MapKind> std::fmt::Debug for Node<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Node::String(s) => f.debug_tuple("String").field(s).finish(),
+            Node::Number(n) => f.debug_tuple("Number").field(n).finish(),
+            Node::Bool(b) => f.debug_tuple("Bool").field(b).finish(),
+            Node::Null => f.write_str("Null"),
+            Node::Array(array) => f.debug_tuple("Array").field(array).finish(),
+            Node::Object(object) => f.debug_tuple("Object").field(object).finish(),
+        }
+    }
 }
 
+impl<K: MapKind> PartialEq for Node<K> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Node::String(a), Node::String(b)) => a == b,
+            (Node::Number(a), Node::Number(b)) => a == b,
+            (Node::Bool(a), Node::Bool(b)) => a == b,
+            (Node::Null, Node::Null) => true,
+            (Node::Array(a), Node::Array(b)) => a == b,
+            (Node::Object(a), Node::Object(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// Node から Rust の値への変換に失敗したことを表現する
+/// field は構造体のフィールドの変換で起きた場合のフィールド名、expected は変換先の Rust の型名
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
-    #[error("{0}")]
-    RequiredError(String),
-    #[error("JSONの値の変換に失敗しました（{0}）")]
-    ConversionError(String),
+    /// Object に必要なキーがない
+    #[error("JSONオブジェクトから `{field}` が読み取れません")]
+    Missing { field: String },
+    /// 値の種類を変換先の型が受け付けない
+    #[error("{}{found} の値は {expected} に変換できません", field_label(.field, " "))]
+    TypeMismatch {
+        field: Option<String>,
+        expected: String,
+        found: Kind,
+    },
+    /// 値の種類は受け付けるが、値を変換先の型で表現できない
+    #[error("{}値を {expected} に変換できません（{reason}）", field_label(.field, ""))]
+    InvalidValue {
+        field: Option<String>,
+        expected: String,
+        reason: String,
+    },
+    /// 入れ子の値の変換に失敗したことを、その値を指す JSON Pointer とともに表現する
+    #[error("`{path}` で{source}")]
+    AtPath { path: Pointer, source: Box<Error> },
+}
+
+/// field があれば「`field` の」に separator を続けた文字列を返却する
+fn field_label(field: &Option<String>, separator: &str) -> String {
+    field
+        .as_ref()
+        .map(|field| format!("`{field}` の{separator}"))
+        .unwrap_or_default()
+}
+
+impl Error {
+    /// node の種類の値を T に変換できないことを表すエラーを返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let node: node::Node = node::json!([]);
+    /// let error = node::Error::type_mismatch::<String, _>(&node);
+    /// assert_eq!(error.to_string(), "array の値は String に変換できません");
+    /// ```
+    pub fn type_mismatch<T: ?Sized, K: MapKind>(node: &Node<K>) -> Self {
+        Error::TypeMismatch {
+            field: None,
+            expected: type_name::<T>(),
+            found: node.kind(),
+        }
+    }
+
+    /// 値を T で表現できないことを表すエラーを返却する
+    pub fn invalid_value<T: ?Sized>(reason: impl Into<String>) -> Self {
+        Error::InvalidValue {
+            field: None,
+            expected: type_name::<T>(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Object の key の値を変換した際のエラーとして、位置の先頭に key を加える
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use node::FromNode;
+    ///
+    /// let node: node::Node = node::json!({ "a": [1, "x"] });
+    /// let error = std::collections::BTreeMap::<String, Vec<u8>>::from_node(&node).unwrap_err();
+    /// assert_eq!(error.path().to_string(), "/a/1");
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "`/a/1` でstring の値は u8 に変換できません"
+    /// );
+    /// ```
+    pub fn at_key(self, key: impl Into<String>) -> Self {
+        match self {
+            Error::AtPath { mut path, source } => {
+                path.push_front(key);
+                Error::AtPath { path, source }
+            }
+            error => {
+                let mut path = Pointer::root();
+                path.push(key);
+                Error::AtPath {
+                    path,
+                    source: Box::new(error),
+                }
+            }
+        }
+    }
+
+    /// Array の index 番目の値を変換した際のエラーとして、位置の先頭に index を加える
+    pub fn at_index(self, index: usize) -> Self {
+        self.at_key(index.to_string())
+    }
+
+    /// 変換に失敗した値を指す JSON Pointer を返却する
+    /// 変換しようとした値そのものが原因の場合は空の JSON Pointer とする
+    pub fn path(&self) -> Pointer {
+        match self {
+            Error::AtPath { path, .. } => path.clone(),
+            _ => Pointer::root(),
+        }
+    }
+
+    /// 位置の情報を除いた、変換に失敗した原因のエラーを返却する
+    pub fn inner(&self) -> &Error {
+        match self {
+            Error::AtPath { source, .. } => source,
+            error => error,
+        }
+    }
+}
+
+/// モジュールのパスを除いた T の型名を返却する
+fn type_name<T: ?Sized>() -> String {
+    let name = std::any::type_name::<T>();
+    let mut short = String::with_capacity(name.len());
+    let mut segment_start = 0;
+    for (i, c) in name.char_indices() {
+        if c.is_alphanumeric() || c == '_' {
+            continue;
+        }
+        if c == ':' {
+            segment_start = i + 1;
+            continue;
+        }
+        short.push_str(&name[segment_start..i]);
+        short.push(c);
+        segment_start = i + c.len_utf8();
+    }
+    short.push_str(&name[segment_start..]);
+    short
 }
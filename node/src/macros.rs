@@ -0,0 +1,138 @@
+/// JSONの表記に近い形で Node を構築する
+/// `null`・Array・Object 以外の値は式として評価し、Node::from で変換する
+/// Object のキーは `&str`・String に変換できる式とする
+///
+/// # Examples
+///
+/// ```
+/// let name = "x";
+/// let node: node::Node = node::json!({
+///     "a": [1, true, null, -1.5],
+///     "b": { "c": name },
+///     "d": node::Node::Array(vec![]),
+/// });
+/// assert_eq!(node["a"][0], node::Node::Number(1.into()));
+/// assert_eq!(node["b"]["c"], node::Node::String("x".into()));
+/// assert_eq!(node.pointer("/a/2"), Some(&node::Node::Null));
+/// ```
+#[macro_export]
+macro_rules! json {
+    (null) => {
+        $crate::Node::Null
+    };
+    ([ $($tt:tt)* ]) => {
+        $crate::Node::Array($crate::json_internal!(@array [] $($tt)*))
+    };
+    ({}) => {
+        $crate::Node::Object(::core::default::Default::default())
+    };
+    ({ $($tt:tt)+ }) => {
+        $crate::Node::Object({
+            let mut object = ::core::default::Default::default();
+            $crate::json_internal!(@object object () $($tt)*);
+            object
+        })
+    };
+    ($other:expr) => {
+        $crate::Node::from($other)
+    };
+}
+
+/// json! の Array・Object の中身を `,` と `:` で区切りながら１トークンずつ読み進める
+#[doc(hidden)]
+#[macro_export]
+macro_rules! json_internal {
+    // Array: 読み終えた要素を vec! にする
+    (@array [$($elems:expr),*]) => {
+        ::std::vec![$($elems),*]
+    };
+    (@array [$($elems:expr),*] $($rest:tt)+) => {
+        $crate::json_internal!(@element [$($elems),*] () $($rest)+)
+    };
+    // Array: `,` までを１つの要素として読む
+    (@element [$($elems:expr),*] ($($value:tt)+) , $($rest:tt)*) => {
+        $crate::json_internal!(@array [$($elems,)* $crate::json!($($value)+)] $($rest)*)
+    };
+    (@element [$($elems:expr),*] ($($value:tt)*) $next:tt $($rest:tt)*) => {
+        $crate::json_internal!(@element [$($elems),*] ($($value)* $next) $($rest)*)
+    };
+    (@element [$($elems:expr),*] ($($value:tt)+)) => {
+        $crate::json_internal!(@array [$($elems,)* $crate::json!($($value)+)])
+    };
+
+    // Object: `:` までをキーとして読む
+    (@object $object:ident ()) => {};
+    (@object $object:ident ($($key:tt)+) : $($rest:tt)*) => {
+        $crate::json_internal!(@value $object ($($key)+) () $($rest)*)
+    };
+    (@object $object:ident ($($key:tt)*) $next:tt $($rest:tt)*) => {
+        $crate::json_internal!(@object $object ($($key)* $next) $($rest)*)
+    };
+    // Object: `,` までを値として読み、格納する
+    (@value $object:ident ($($key:tt)+) ($($value:tt)+) , $($rest:tt)*) => {
+        $crate::json_internal!(@insert $object ($($key)+) ($($value)+));
+        $crate::json_internal!(@object $object () $($rest)*)
+    };
+    (@value $object:ident ($($key:tt)+) ($($value:tt)*) $next:tt $($rest:tt)*) => {
+        $crate::json_internal!(@value $object ($($key)+) ($($value)* $next) $($rest)*)
+    };
+    (@value $object:ident ($($key:tt)+) ($($value:tt)+)) => {
+        $crate::json_internal!(@insert $object ($($key)+) ($($value)+))
+    };
+    (@insert $object:ident ($($key:tt)+) ($($value:tt)+)) => {
+        $crate::ObjectMap::insert(
+            &mut $object,
+            ::std::string::String::from($($key)+),
+            $crate::json!($($value)+),
+        );
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Map, Node};
+
+    #[test]
+    fn test_json() {
+        let key = String::from("k");
+        let inner: Node = Node::Bool(false);
+        let node: Node = json!({
+            "a": [],
+            "b": [1, "s", [null], {}],
+            key.as_str(): inner.clone(),
+            "c": { "d": -2, "e": 1 + 1 }
+        });
+        assert_eq!(
+            node,
+            Node::Object(Map::from([
+                ("a".to_string(), Node::Array(vec![])),
+                (
+                    "b".to_string(),
+                    Node::Array(vec![
+                        Node::Number(1.into()),
+                        Node::String("s".into()),
+                        Node::Array(vec![Node::Null]),
+                        Node::Object(Map::new()),
+                    ])
+                ),
+                ("k".to_string(), inner),
+                (
+                    "c".to_string(),
+                    Node::Object(Map::from([
+                        ("d".to_string(), Node::Number((-2).into())),
+                        ("e".to_string(), Node::Number(2.into())),
+                    ]))
+                ),
+            ]))
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(json!(null), Node::Null)]
+    #[case(json!(true), Node::Bool(true))]
+    #[case(json!([1,]), Node::Array(vec![Node::Number(1.into())]))]
+    #[case(json!({"a": null,}), Node::Object(Map::from([("a".to_string(), Node::Null)])))]
+    fn test_json_scalar(#[case] node: Node, #[case] expected: Node) {
+        assert_eq!(node, expected);
+    }
+}
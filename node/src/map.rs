@@ -0,0 +1,584 @@
+use crate::Node;
+
+#[cfg(not(feature = "preserve_order"))]
+mod inner {
+    pub(super) type Map<V> = std::collections::BTreeMap<Box<str>, V>;
+    pub(super) type Iter<'a, V> = std::collections::btree_map::Iter<'a, Box<str>, V>;
+    pub(super) type IterMut<'a, V> = std::collections::btree_map::IterMut<'a, Box<str>, V>;
+    pub(super) type IntoIter<V> = std::collections::btree_map::IntoIter<Box<str>, V>;
+    pub(super) type Keys<'a, V> = std::collections::btree_map::Keys<'a, Box<str>, V>;
+    pub(super) type Values<'a, V> = std::collections::btree_map::Values<'a, Box<str>, V>;
+    pub(super) type ValuesMut<'a, V> = std::collections::btree_map::ValuesMut<'a, Box<str>, V>;
+}
+
+#[cfg(feature = "preserve_order")]
+mod inner {
+    pub(super) type Map<V> = indexmap::IndexMap<Box<str>, V>;
+    pub(super) type Iter<'a, V> = indexmap::map::Iter<'a, Box<str>, V>;
+    pub(super) type IterMut<'a, V> = indexmap::map::IterMut<'a, Box<str>, V>;
+    pub(super) type IntoIter<V> = indexmap::map::IntoIter<Box<str>, V>;
+    pub(super) type Keys<'a, V> = indexmap::map::Keys<'a, Box<str>, V>;
+    pub(super) type Values<'a, V> = indexmap::map::Values<'a, Box<str>, V>;
+    pub(super) type ValuesMut<'a, V> = indexmap::map::ValuesMut<'a, Box<str>, V>;
+}
+
+/// Node::Object のキーと値を保持する
+/// 既定ではキーの昇順に並び、`preserve_order` フィーチャーが有効な場合は挿入した順に並ぶ
+/// キーは容量を持たない Box<str> で保持する
+/// 比較はキーの順序によらない
+///
+/// # Examples
+///
+/// ```
+/// let mut map: node::Map = node::Map::new();
+/// map.insert("b".into(), node::Node::Null);
+/// map.insert("a".into(), node::Node::Bool(true));
+/// assert_eq!(map.get("a"), Some(&node::Node::Bool(true)));
+/// assert_eq!(map, node::Map::from([
+///     ("a".to_string(), node::Node::Bool(true)),
+///     ("b".to_string(), node::Node::Null),
+/// ]));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Map<V = Node> {
+    map: inner::Map<V>,
+}
+
+impl<V> Default for Map<V> {
+    fn default() -> Self {
+        Self {
+            map: inner::Map::default(),
+        }
+    }
+}
+
+impl<V> Map<V> {
+    /// 空の Map を生成して返却する
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 要素の数を返却する
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// 要素がないか
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// 全ての要素を取り除く
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// キーに対応する値を返却する
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.map.get(key)
+    }
+
+    /// キーに対応する値の可変参照を返却する
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        self.map.get_mut(key)
+    }
+
+    /// キーを持つか
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// 値を格納し、同じキーの値があった場合はその値を返却する
+    /// 同じキーの値があった場合、並び順は元の位置のままとなる
+    pub fn insert(&mut self, key: String, value: V) -> Option<V> {
+        self.map.insert(key.into_boxed_str(), value)
+    }
+
+    /// キーに対応する値を取り除いて返却する
+    /// 残りの要素の並び順は変わらない
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        #[cfg(not(feature = "preserve_order"))]
+        return self.map.remove(key);
+        #[cfg(feature = "preserve_order")]
+        return self.map.shift_remove(key);
+    }
+
+    /// f が false を返却した要素を取り除く
+    /// 残りの要素の並び順は変わらない
+    pub fn retain(&mut self, mut f: impl FnMut(&str, &mut V) -> bool) {
+        self.map.retain(|key, value| f(key, value));
+    }
+
+    /// 要素をキーの昇順に並べ替える
+    /// `preserve_order` フィーチャーが無効な場合は常に昇順のため何もしない
+    pub fn sort_keys(&mut self) {
+        #[cfg(feature = "preserve_order")]
+        self.map.sort_keys();
+    }
+
+    /// キーと値の組を並び順に返却する
+    pub fn iter(&self) -> Iter<'_, V> {
+        Iter(self.map.iter())
+    }
+
+    /// キーと値の可変参照の組を並び順に返却する
+    pub fn iter_mut(&mut self) -> IterMut<'_, V> {
+        IterMut(self.map.iter_mut())
+    }
+
+    /// キーを並び順に返却する
+    pub fn keys(&self) -> Keys<'_, V> {
+        Keys(self.map.keys())
+    }
+
+    /// 値を並び順に返却する
+    pub fn values(&self) -> Values<'_, V> {
+        Values(self.map.values())
+    }
+
+    /// 値の可変参照を並び順に返却する
+    pub fn values_mut(&mut self) -> ValuesMut<'_, V> {
+        ValuesMut(self.map.values_mut())
+    }
+}
+
+impl<V: std::fmt::Debug> std::fmt::Debug for Map<V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<V> std::ops::Index<&str> for Map<V> {
+    type Output = V;
+
+    /// キーがない場合はパニックする
+    fn index(&self, key: &str) -> &V {
+        self.get(key).expect("キーが存在しません")
+    }
+}
+
+impl<V> std::ops::IndexMut<&str> for Map<V> {
+    fn index_mut(&mut self, key: &str) -> &mut V {
+        self.get_mut(key).expect("キーが存在しません")
+    }
+}
+
+impl<V> FromIterator<(String, V)> for Map<V> {
+    fn from_iter<T: IntoIterator<Item = (String, V)>>(iter: T) -> Self {
+        Self {
+            map: iter
+                .into_iter()
+                .map(|(key, value)| (key.into_boxed_str(), value))
+                .collect(),
+        }
+    }
+}
+
+impl<V> Extend<(String, V)> for Map<V> {
+    fn extend<T: IntoIterator<Item = (String, V)>>(&mut self, iter: T) {
+        self.map.extend(
+            iter.into_iter()
+                .map(|(key, value)| (key.into_boxed_str(), value)),
+        );
+    }
+}
+
+impl<V, const N: usize> From<[(String, V); N]> for Map<V> {
+    fn from(value: [(String, V); N]) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<V> From<std::collections::BTreeMap<String, V>> for Map<V> {
+    fn from(value: std::collections::BTreeMap<String, V>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<V> IntoIterator for Map<V> {
+    type Item = (String, V);
+    type IntoIter = IntoIter<V>;
+
+    fn into_iter(self) -> IntoIter<V> {
+        IntoIter(self.map.into_iter())
+    }
+}
+
+impl<'a, V> IntoIterator for &'a Map<V> {
+    type Item = (&'a str, &'a V);
+    type IntoIter = Iter<'a, V>;
+
+    fn into_iter(self) -> Iter<'a, V> {
+        self.iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a mut Map<V> {
+    type Item = (&'a str, &'a mut V);
+    type IntoIter = IterMut<'a, V>;
+
+    fn into_iter(self) -> IterMut<'a, V> {
+        self.iter_mut()
+    }
+}
+
+/// 内部の Map の反復子を包み、フィーチャーによらず同じ型として公開する
+/// キーは Box<str> ではなく &str・String として返却する
+macro_rules! iterator {
+    ($(#[$doc:meta])* $name:ident <$($a:lifetime,)? V>, $item:ty, $convert:expr) => {
+        $(#[$doc])*
+        pub struct $name<$($a,)? V = Node>(inner::$name<$($a,)? V>);
+
+        impl<$($a,)? V> Iterator for $name<$($a,)? V> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.next().map($convert)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.size_hint()
+            }
+        }
+
+        impl<$($a,)? V> DoubleEndedIterator for $name<$($a,)? V> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.0.next_back().map($convert)
+            }
+        }
+
+        impl<$($a,)? V> ExactSizeIterator for $name<$($a,)? V> {}
+    };
+}
+
+iterator!(
+    /// Map::iter が返却する反復子
+    Iter<'a, V>, (&'a str, &'a V), |(key, value)| (&**key, value)
+);
+iterator!(
+    /// Map::iter_mut が返却する反復子
+    IterMut<'a, V>, (&'a str, &'a mut V), |(key, value)| (&**key, value)
+);
+iterator!(
+    /// Map を所有権ごと反復する反復子
+    IntoIter<V>, (String, V), |(key, value): (Box<str>, V)| (key.into_string(), value)
+);
+iterator!(
+    /// Map::keys が返却する反復子
+    Keys<'a, V>, &'a str, |key| &**key
+);
+iterator!(
+    /// Map::values が返却する反復子
+    Values<'a, V>, &'a V, |value| value
+);
+iterator!(
+    /// Map::values_mut が返却する反復子
+    ValuesMut<'a, V>, &'a mut V, |value| value
+);
+
+/// Node::Object の Map が共通して提供する操作
+/// Node や FromNode はこのトレイトを通して Object を読み書きする
+pub trait ObjectMap<V>:
+    Default + FromIterator<(String, V)> + IntoIterator<Item = (String, V)>
+{
+    /// 要素の数を返却する
+    fn len(&self) -> usize;
+
+    /// 要素がないか
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// キーに対応する値を返却する
+    fn get(&self, key: &str) -> Option<&V>;
+
+    /// キーに対応する値の可変参照を返却する
+    fn get_mut(&mut self, key: &str) -> Option<&mut V>;
+
+    /// キーを持つか
+    fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// 値を格納し、同じキーの値があった場合はその値を返却する
+    fn insert(&mut self, key: String, value: V) -> Option<V>;
+
+    /// キーに対応する値を取り除いて返却する
+    fn remove(&mut self, key: &str) -> Option<V>;
+
+    /// f が false を返却した要素を取り除く
+    fn retain(&mut self, f: impl FnMut(&str, &mut V) -> bool);
+
+    /// 要素をキーの昇順に並べ替える
+    /// 並び順を持たない、または常に昇順の Map では何もしない
+    fn sort_keys(&mut self) {}
+
+    /// キーと値の組を返却する
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a str, &'a V)>
+    where
+        V: 'a;
+
+    /// キーと値の可変参照の組を返却する
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a str, &'a mut V)>
+    where
+        V: 'a;
+}
+
+impl<V> ObjectMap<V> for Map<V> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn get(&self, key: &str) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+
+    fn insert(&mut self, key: String, value: V) -> Option<V> {
+        self.insert(key, value)
+    }
+
+    fn remove(&mut self, key: &str) -> Option<V> {
+        self.remove(key)
+    }
+
+    fn retain(&mut self, f: impl FnMut(&str, &mut V) -> bool) {
+        self.retain(f);
+    }
+
+    fn sort_keys(&mut self) {
+        self.sort_keys();
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a str, &'a V)>
+    where
+        V: 'a,
+    {
+        self.iter()
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a str, &'a mut V)>
+    where
+        V: 'a,
+    {
+        self.iter_mut()
+    }
+}
+
+impl<V> ObjectMap<V> for std::collections::BTreeMap<String, V> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn get(&self, key: &str) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+
+    fn insert(&mut self, key: String, value: V) -> Option<V> {
+        self.insert(key, value)
+    }
+
+    fn remove(&mut self, key: &str) -> Option<V> {
+        self.remove(key)
+    }
+
+    fn retain(&mut self, mut f: impl FnMut(&str, &mut V) -> bool) {
+        self.retain(|key, value| f(key, value));
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a str, &'a V)>
+    where
+        V: 'a,
+    {
+        self.iter().map(|(key, value)| (key.as_str(), value))
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a str, &'a mut V)>
+    where
+        V: 'a,
+    {
+        self.iter_mut().map(|(key, value)| (key.as_str(), value))
+    }
+}
+
+impl<V> ObjectMap<V> for std::collections::HashMap<String, V> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn get(&self, key: &str) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+
+    fn insert(&mut self, key: String, value: V) -> Option<V> {
+        self.insert(key, value)
+    }
+
+    fn remove(&mut self, key: &str) -> Option<V> {
+        self.remove(key)
+    }
+
+    fn retain(&mut self, mut f: impl FnMut(&str, &mut V) -> bool) {
+        self.retain(|key, value| f(key, value));
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a str, &'a V)>
+    where
+        V: 'a,
+    {
+        self.iter().map(|(key, value)| (key.as_str(), value))
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a str, &'a mut V)>
+    where
+        V: 'a,
+    {
+        self.iter_mut().map(|(key, value)| (key.as_str(), value))
+    }
+}
+
+#[cfg(feature = "preserve_order")]
+impl<V> ObjectMap<V> for indexmap::IndexMap<String, V> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn get(&self, key: &str) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        self.get_mut(key)
+    }
+
+    fn insert(&mut self, key: String, value: V) -> Option<V> {
+        self.insert(key, value)
+    }
+
+    /// 残りの要素の並び順は変わらない
+    fn remove(&mut self, key: &str) -> Option<V> {
+        self.shift_remove(key)
+    }
+
+    /// 残りの要素の並び順は変わらない
+    fn retain(&mut self, mut f: impl FnMut(&str, &mut V) -> bool) {
+        self.retain(|key, value| f(key, value));
+    }
+
+    fn sort_keys(&mut self) {
+        self.sort_keys();
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a str, &'a V)>
+    where
+        V: 'a,
+    {
+        self.iter().map(|(key, value)| (key.as_str(), value))
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a str, &'a mut V)>
+    where
+        V: 'a,
+    {
+        self.iter_mut().map(|(key, value)| (key.as_str(), value))
+    }
+}
+
+/// Node::Object に使う Map の種類を表現する
+/// Node は値として自身を持つため、Map の型そのものではなく値の型から Map の型を決める型で指定する
+///
+/// # Examples
+///
+/// ```
+/// use node::map::{Hashed, ObjectMap};
+///
+/// let mut map = std::collections::HashMap::new();
+/// map.insert("a".to_string(), node::Node::<Hashed>::Null);
+/// let node = node::Node::<Hashed>::Object(map);
+/// if let node::Node::Object(map) = &node {
+///     assert_eq!(ObjectMap::get(map, "a"), Some(&node::Node::Null));
+/// }
+/// ```
+pub trait MapKind {
+    type Map<V: Clone + std::fmt::Debug + PartialEq>: ObjectMap<V>
+        + Clone
+        + std::fmt::Debug
+        + PartialEq;
+}
+
+/// node::Map を使う（既定）
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Standard;
+
+impl MapKind for Standard {
+    type Map<V: Clone + std::fmt::Debug + PartialEq> = Map<V>;
+}
+
+/// キーの昇順に並ぶ std::collections::BTreeMap を使う
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sorted;
+
+impl MapKind for Sorted {
+    type Map<V: Clone + std::fmt::Debug + PartialEq> = std::collections::BTreeMap<String, V>;
+}
+
+/// 並び順を持たない std::collections::HashMap を使う
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hashed;
+
+impl MapKind for Hashed {
+    type Map<V: Clone + std::fmt::Debug + PartialEq> = std::collections::HashMap<String, V>;
+}
+
+/// 挿入した順に並ぶ indexmap::IndexMap を使う
+#[cfg(feature = "preserve_order")]
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Indexed;
+
+#[cfg(feature = "preserve_order")]
+impl MapKind for Indexed {
+    type Map<V: Clone + std::fmt::Debug + PartialEq> = indexmap::IndexMap<String, V>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order() {
+        let mut map: Map = Map::new();
+        for key in ["c", "a", "b"] {
+            map.insert(key.into(), Node::Null);
+        }
+        map.insert("c".into(), Node::Bool(true));
+        map.remove("a");
+
+        let keys = map.keys().collect::<Vec<_>>();
+        #[cfg(not(feature = "preserve_order"))]
+        assert_eq!(keys, vec!["b", "c"]);
+        #[cfg(feature = "preserve_order")]
+        assert_eq!(keys, vec!["c", "b"]);
+
+        map.sort_keys();
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec!["b", "c"]);
+        assert_eq!(map["c"], Node::Bool(true));
+    }
+
+    #[test]
+    fn test_eq_ignores_order() {
+        let a: Map = Map::from([("a".into(), Node::Null), ("b".into(), Node::Null)]);
+        let b: Map = Map::from([("b".into(), Node::Null), ("a".into(), Node::Null)]);
+        assert_eq!(a, b);
+    }
+}
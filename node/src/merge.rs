@@ -0,0 +1,113 @@
+use crate::{MapKind, Node, ObjectMap};
+
+impl<K: MapKind> Node<K> {
+    /// RFC 7386 の JSON Merge Patch を適用する
+    /// patch が Object の場合はキーごとに再帰的に適用し、値が null のキーは取り除く
+    /// patch が Object 以外の場合は patch で置き換える
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut doc: node::Node = node::json!({ "title": "a", "author": { "name": "x", "email": "x@example.com" } });
+    /// doc.merge_patch(&node::json!({ "title": "b", "author": { "email": null } }));
+    /// assert_eq!(doc, node::json!({ "title": "b", "author": { "name": "x" } }));
+    /// ```
+    pub fn merge_patch(&mut self, patch: &Node<K>) {
+        let Node::Object(patch) = patch else {
+            *self = patch.clone();
+            return;
+        };
+        if !self.is_object() {
+            *self = Node::Object(K::Map::default());
+        }
+
+        for (key, value) in patch.iter() {
+            if value.is_null() {
+                self.remove(key).expect("Object に置き換えている");
+            } else {
+                self.entry(key).or_insert(Node::Null).merge_patch(value);
+            }
+        }
+    }
+
+    /// self に適用すると target となる JSON Merge Patch を返却する
+    /// Merge Patch は null の値を表現できないため、target の Object が持つ null の値は取り除かれる
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let source: node::Node = node::json!({ "a": 1, "b": { "c": 2, "d": 3 } });
+    /// let target: node::Node = node::json!({ "a": 1, "b": { "c": 4 }, "e": [] });
+    /// let patch = source.merge_patch_to(&target);
+    /// assert_eq!(patch, node::json!({ "b": { "c": 4, "d": null }, "e": [] }));
+    ///
+    /// let mut patched = source.clone();
+    /// patched.merge_patch(&patch);
+    /// assert_eq!(patched, target);
+    /// ```
+    pub fn merge_patch_to(&self, target: &Node<K>) -> Node<K> {
+        let (Node::Object(source), Node::Object(target)) = (self, target) else {
+            return target.clone();
+        };
+
+        let removed = source
+            .iter()
+            .filter(|(key, _)| !target.contains_key(key))
+            .map(|(key, _)| (key.to_string(), Node::Null));
+        let changed = target
+            .iter()
+            .filter_map(|(key, value)| match source.get(key) {
+                Some(source) if source == value => None,
+                Some(source) => Some((key.to_string(), source.merge_patch_to(value))),
+                None => Some((key.to_string(), value.clone())),
+            });
+
+        removed.chain(changed).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Node, json};
+
+    // RFC 7386 の Appendix A の例
+    #[rstest::rstest]
+    #[case(json!({"a": "b"}), json!({"a": "c"}), json!({"a": "c"}))]
+    #[case(json!({"a": "b"}), json!({"b": "c"}), json!({"a": "b", "b": "c"}))]
+    #[case(json!({"a": "b"}), json!({"a": null}), json!({}))]
+    #[case(json!({"a": "b", "b": "c"}), json!({"a": null}), json!({"b": "c"}))]
+    #[case(json!({"a": ["b"]}), json!({"a": "c"}), json!({"a": "c"}))]
+    #[case(json!({"a": "c"}), json!({"a": ["b"]}), json!({"a": ["b"]}))]
+    #[case(
+        json!({"a": {"b": "c"}}),
+        json!({"a": {"b": "d", "c": null}}),
+        json!({"a": {"b": "d"}})
+    )]
+    #[case(json!({"a": [{"b": "c"}]}), json!({"a": [1]}), json!({"a": [1]}))]
+    #[case(json!(["a", "b"]), json!(["c", "d"]), json!(["c", "d"]))]
+    #[case(json!({"a": "b"}), json!(["c"]), json!(["c"]))]
+    #[case(json!({"a": "foo"}), json!(null), json!(null))]
+    #[case(json!({"a": "foo"}), json!("bar"), json!("bar"))]
+    #[case(json!({"e": null}), json!({"a": 1}), json!({"e": null, "a": 1}))]
+    #[case(json!([1, 2]), json!({"a": "b", "c": null}), json!({"a": "b"}))]
+    #[case(json!({}), json!({"a": {"bb": {"ccc": null}}}), json!({"a": {"bb": {}}}))]
+    fn test_merge_patch(#[case] mut target: Node, #[case] patch: Node, #[case] expected: Node) {
+        target.merge_patch(&patch);
+        assert_eq!(target, expected);
+    }
+
+    #[rstest::rstest]
+    #[case(json!({"a": 1}), json!({"a": 1}), json!({}))]
+    #[case(json!({"a": {"b": 1}}), json!({"a": {"b": 1, "c": [2]}}), json!({"a": {"c": [2]}}))]
+    #[case(json!({"a": 1, "b": 2}), json!({"b": 3}), json!({"a": null, "b": 3}))]
+    #[case(json!({"a": 1}), json!([1]), json!([1]))]
+    #[case(json!([1]), json!({"a": 1}), json!({"a": 1}))]
+    fn test_merge_patch_to(#[case] source: Node, #[case] target: Node, #[case] expected: Node) {
+        let patch = source.merge_patch_to(&target);
+        assert_eq!(patch, expected);
+
+        let mut patched = source;
+        patched.merge_patch(&patch);
+        assert_eq!(patched, target);
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{Kind, KindError, MapKind, Node, ObjectMap};
+
+impl<K: MapKind> Node<K> {
+    /// Object の場合、キーと値を格納して元の値を返却する
+    /// Object 以外の場合は KindError を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut node: node::Node = node::Node::Object(node::Map::new());
+    /// assert_eq!(node.insert("a", node::Node::Null), Ok(None));
+    /// assert_eq!(node.remove("a"), Ok(Some(node::Node::Null)));
+    ///
+    /// let mut node: node::Node = node::Node::Array(vec![]);
+    /// node.push(node::Node::Bool(true)).unwrap();
+    /// assert_eq!(node.pop(), Ok(Some(node::Node::Bool(true))));
+    /// assert!(node.insert("a", node::Node::Null).is_err());
+    /// ```
+    pub fn insert(
+        &mut self,
+        key: impl Into<String>,
+        value: Node<K>,
+    ) -> Result<Option<Node<K>>, KindError> {
+        Ok(self.object_mut()?.insert(key.into(), value))
+    }
+
+    /// Object の場合、キーを取り除いてその値を返却する
+    /// Object 以外の場合は KindError を返却する
+    pub fn remove(&mut self, key: &str) -> Result<Option<Node<K>>, KindError> {
+        Ok(self.object_mut()?.remove(key))
+    }
+
+    /// Array の場合、末尾に値を加える
+    /// Array 以外の場合は KindError を返却する
+    pub fn push(&mut self, value: Node<K>) -> Result<(), KindError> {
+        self.array_mut()?.push(value);
+        Ok(())
+    }
+
+    /// Array の場合、末尾の値を取り除いて返却する
+    /// Array 以外の場合は KindError を返却する
+    pub fn pop(&mut self) -> Result<Option<Node<K>>, KindError> {
+        Ok(self.array_mut()?.pop())
+    }
+
+    /// Object の場合、f が false を返却したキーと値を取り除く
+    /// Object 以外の場合は何もしない
+    pub fn retain_keys(&mut self, f: impl FnMut(&str, &mut Node<K>) -> bool) {
+        if let Node::Object(object) = self {
+            object.retain(f);
+        }
+    }
+
+    /// Array の場合、f が false を返却した要素を取り除く
+    /// Array 以外の場合は何もしない
+    pub fn retain_items(&mut self, f: impl FnMut(&Node<K>) -> bool) {
+        if let Node::Array(array) = self {
+            array.retain(f);
+        }
+    }
+
+    /// 入れ子の Object を含め、f が false を返却したキーと値を取り除く
+    /// Array の要素は取り除かずに中の Object を辿る
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut node: node::Node = node::json!({
+    ///     "_meta": 1,
+    ///     "items": [{ "id": 1, "_meta": 2 }],
+    /// });
+    /// node.filter_deep(|key, _| !key.starts_with('_'));
+    /// assert_eq!(node, node::json!({ "items": [{ "id": 1 }] }));
+    /// ```
+    pub fn filter_deep(&mut self, mut f: impl FnMut(&str, &Node<K>) -> bool) {
+        self.filter_deep_with(&mut f);
+    }
+
+    fn filter_deep_with(&mut self, f: &mut impl FnMut(&str, &Node<K>) -> bool) {
+        match self {
+            Node::Object(object) => object.retain(|key, value| {
+                let keep = f(key, value);
+                if keep {
+                    value.filter_deep_with(f);
+                }
+                keep
+            }),
+            Node::Array(array) => array.iter_mut().for_each(|value| value.filter_deep_with(f)),
+            _ => {}
+        }
+    }
+
+    fn object_mut(&mut self) -> Result<&mut K::Map<Node<K>>, KindError> {
+        let found = self.kind();
+        self.as_object_mut().ok_or(KindError {
+            expected: Kind::Object,
+            found,
+        })
+    }
+
+    fn array_mut(&mut self) -> Result<&mut Vec<Node<K>>, KindError> {
+        let found = self.kind();
+        self.as_array_mut().ok_or(KindError {
+            expected: Kind::Array,
+            found,
+        })
+    }
+}
+
+/// Array の末尾に要素を加える
+/// Node::Null は空の Array に置き換えてから加える
+/// Array・Null 以外の場合はパニックする
+impl<K: MapKind> Extend<Node<K>> for Node<K> {
+    fn extend<T: IntoIterator<Item = Node<K>>>(&mut self, iter: T) {
+        if self.is_null() {
+            *self = Node::Array(Vec::new());
+        }
+        match self.array_mut() {
+            Ok(array) => array.extend(iter),
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+/// Object にキーと値を加える
+/// Node::Null は空の Object に置き換えてから加える
+/// Object・Null 以外の場合はパニックする
+impl<K: MapKind> Extend<(String, Node<K>)> for Node<K> {
+    fn extend<T: IntoIterator<Item = (String, Node<K>)>>(&mut self, iter: T) {
+        if self.is_null() {
+            *self = Node::Object(K::Map::default());
+        }
+        match self.object_mut() {
+            Ok(object) => iter.into_iter().for_each(|(key, value)| {
+                object.insert(key, value);
+            }),
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Map;
+
+    #[test]
+    fn test_object() {
+        let mut node: Node = Node::Object(Map::new());
+        assert_eq!(node.insert("a", Node::Bool(true)), Ok(None));
+        assert_eq!(node.insert("a", Node::Null), Ok(Some(Node::Bool(true))));
+        assert_eq!(node.remove("a"), Ok(Some(Node::Null)));
+        assert_eq!(node.remove("a"), Ok(None));
+        assert_eq!(
+            node.push(Node::Null),
+            Err(KindError {
+                expected: Kind::Array,
+                found: Kind::Object
+            })
+        );
+    }
+
+    #[test]
+    fn test_array() {
+        let mut node: Node = Node::Array(vec![]);
+        assert_eq!(node.push(Node::Bool(true)), Ok(()));
+        assert_eq!(node.pop(), Ok(Some(Node::Bool(true))));
+        assert_eq!(node.pop(), Ok(None));
+        assert_eq!(
+            node.remove("a"),
+            Err(KindError {
+                expected: Kind::Object,
+                found: Kind::Array
+            })
+        );
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut node: Node = crate::json!({ "a": 1, "b": 2, "c": 3 });
+        node.retain_keys(|key, value| {
+            *value = Node::from(key);
+            key != "b"
+        });
+        assert_eq!(node, crate::json!({ "a": "a", "c": "c" }));
+        node.retain_items(|_| false);
+        assert_eq!(node, crate::json!({ "a": "a", "c": "c" }));
+
+        let mut node: Node = crate::json!([1, null, 2]);
+        node.retain_items(|value| !value.is_null());
+        assert_eq!(node, crate::json!([1, 2]));
+    }
+
+    #[test]
+    fn test_filter_deep() {
+        let mut node: Node = crate::json!([{ "a": { "a": 1, "b": [{ "a": 2 }] }, "b": 3 }]);
+        node.filter_deep(|key, value| key != "a" || value.is_object());
+        assert_eq!(node, crate::json!([{ "a": { "b": [{}] }, "b": 3 }]));
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut node: Node = Node::Null;
+        node.extend([Node::Bool(true)]);
+        node.extend(vec![Node::Null]);
+        assert_eq!(node, Node::Array(vec![Node::Bool(true), Node::Null]));
+
+        let mut node: Node = Node::Null;
+        node.extend([("a".to_string(), Node::Null)]);
+        node.extend([("a".to_string(), Node::Bool(false))]);
+        assert_eq!(
+            node,
+            Node::Object(Map::from([("a".to_string(), Node::Bool(false))]))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "array を期待しましたが object の値でした")]
+    fn test_extend_panic() {
+        let mut node: Node = Node::Object(Map::new());
+        node.extend([Node::Null]);
+    }
+
+    #[test]
+    fn test_error_message() {
+        let mut node: Node = Node::Null;
+        let error = node.insert("a", Node::Null).unwrap_err();
+        assert_eq!(error.to_string(), "object を期待しましたが null の値でした");
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{MapKind, Node, Number, ObjectMap};
+
+/// Node::normalize の挙動を設定する
+/// 既定値はキーの並べ替え・`-0.0` の `0` への統一・数値の表記の統一を行い、Array の並べ替えは行わない
+///
+/// # Examples
+///
+/// ```
+/// let mut node: node::Node = node::json!({
+///     "b": [{ "id": 2.0 }, { "id": 1 }],
+///     "a": -0.0,
+/// });
+/// node.normalize(&node::NormalizeOptions {
+///     sort_arrays_by: Some("id".into()),
+///     ..Default::default()
+/// });
+/// assert_eq!(node, node::json!({ "a": 0, "b": [{ "id": 1 }, { "id": 2 }] }));
+/// ```
+#[derive(std::fmt::Debug, Clone, PartialEq, Eq)]
+pub struct NormalizeOptions {
+    /// Object のキーを昇順に並べ替えるか
+    /// 並び順を持たない Map では何もしない
+    pub sort_keys: bool,
+    /// Some の場合、全ての要素が Object の Array をそのキーの値の昇順に並べ替える
+    /// キーがない要素は先頭に並べ、値が等しい要素は元の順を保つ
+    pub sort_arrays_by: Option<String>,
+    /// `-0.0` を `0` に置き換えるか
+    pub collapse_negative_zero: bool,
+    /// 整数値の小数（`1.0`・`1e2` など）を整数の表記に揃えるか
+    /// `arbitrary_precision` フィーチャーが有効な場合、その他の小数も f64 として最短の表記に揃える
+    pub normalize_numbers: bool,
+}
+
+impl Default for NormalizeOptions {
+    fn default() -> Self {
+        Self {
+            sort_keys: true,
+            sort_arrays_by: None,
+            collapse_negative_zero: true,
+            normalize_numbers: true,
+        }
+    }
+}
+
+impl<K: MapKind> Node<K> {
+    /// 設定に従って全ての値を正規化する
+    /// ハッシュ値の計算や比較のために、同じ内容の値を同じ木に揃える
+    pub fn normalize(&mut self, options: &NormalizeOptions) {
+        match self {
+            Node::Number(n) => {
+                if let Some(normalized) = normalize_number(n, options) {
+                    *n = normalized;
+                }
+            }
+            Node::Array(array) => {
+                array.iter_mut().for_each(|value| value.normalize(options));
+                if let Some(key) = &options.sort_arrays_by
+                    && array.iter().all(Node::is_object)
+                {
+                    array.sort_by(|a, b| a.get(key).cmp(&b.get(key)));
+                }
+            }
+            Node::Object(object) => {
+                object
+                    .iter_mut()
+                    .for_each(|(_, value)| value.normalize(options));
+                if options.sort_keys {
+                    object.sort_keys();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 置き換える必要がない場合は None を返却する
+fn normalize_number(n: &Number, options: &NormalizeOptions) -> Option<Number> {
+    // 整数の表記は i64・u64 の範囲外でも f64 を経由すると値が変わるため、そのまま残す
+    if !n.is_f64() {
+        return None;
+    }
+
+    let f = n.as_f64();
+    // `-0.0` は整数値の小数でもあるため、整数の表記に揃える前に扱いを決める
+    if f == 0.0 && f.is_sign_negative() {
+        if options.collapse_negative_zero {
+            return Some(0.into());
+        }
+        return options.normalize_numbers.then(|| f.into());
+    }
+    if options.normalize_numbers && f.fract() == 0.0 {
+        // 2^64 未満であれば u64・i64 で正確に表現できる
+        if (0.0..18446744073709551616.0).contains(&f) {
+            return Some((f as u64).into());
+        }
+        if (-9223372036854775808.0..0.0).contains(&f) {
+            return Some((f as i64).into());
+        }
+    }
+    options.normalize_numbers.then(|| f.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+
+    #[rstest::rstest]
+    #[case(json!(1.0), json!(1))]
+    #[case(json!(-2e0), json!(-2))]
+    #[case(json!(-0.0), json!(0))]
+    #[case(json!(1.5), json!(1.5))]
+    #[case(json!(1e300), json!(1e300))]
+    #[case(json!(u64::MAX), json!(u64::MAX))]
+    fn test_normalize_number(#[case] mut node: Node, #[case] expected: Node) {
+        node.normalize(&NormalizeOptions::default());
+        assert_eq!(node, expected);
+    }
+
+    #[test]
+    fn test_collapse_negative_zero() {
+        let options = NormalizeOptions {
+            normalize_numbers: false,
+            ..Default::default()
+        };
+        let mut node: Node = json!([-0.0, 1.0]);
+        node.normalize(&options);
+        assert_eq!(node, json!([0, 1.0]));
+    }
+
+    #[test]
+    fn test_keep_negative_zero() {
+        let options = NormalizeOptions {
+            collapse_negative_zero: false,
+            ..Default::default()
+        };
+        let mut node: Node = json!([-0.0, 2.0]);
+        node.normalize(&options);
+        let number = node[0].as_number().unwrap();
+        assert!(number.is_f64());
+        assert!(number.as_f64().is_sign_negative());
+        assert_eq!(node[1], json!(2));
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_keep_large_integer() {
+        let large = "123456789012345678901234567890";
+        let mut node: Node = Node::Array(vec![
+            Node::Number(large.parse().unwrap()),
+            Node::Number("123456789012345678901234567891".parse().unwrap()),
+        ]);
+        node.normalize(&NormalizeOptions::default());
+        assert_eq!(node[0].as_number().unwrap().as_str(), large);
+        assert_ne!(node[0], node[1]);
+    }
+
+    #[test]
+    fn test_sort_arrays_by() {
+        let options = NormalizeOptions {
+            sort_arrays_by: Some("k".into()),
+            ..Default::default()
+        };
+        let mut node: Node = json!([
+            { "k": "b" },
+            { "k": 2 },
+            {},
+            { "k": "a", "n": 1 },
+            { "k": "a", "n": 0 },
+        ]);
+        node.normalize(&options);
+        assert_eq!(
+            node,
+            json!([{}, { "k": 2 }, { "k": "a", "n": 1 }, { "k": "a", "n": 0 }, { "k": "b" }])
+        );
+
+        let mut node: Node = json!([{ "k": 2 }, 1, { "k": 1 }]);
+        node.normalize(&options);
+        assert_eq!(node, json!([{ "k": 2 }, 1, { "k": 1 }]));
+    }
+}
@@ -0,0 +1,717 @@
+/// JSONの数値を表現する
+/// 整数は i64・u64 の範囲で正確に保持し、それ以外は f64 で保持する
+/// 整数と小数は表記が異なれば別の値として扱う（`1` と `1.0` は等しくない）
+/// 順序は値の大小とし、値が等しい整数と小数は整数を先とする（`-0.0` と `0.0` は等しく、NaN は全順序で扱う）
+/// `arbitrary_precision` フィーチャーが有効な場合は入力の表記をそのまま保持し、値を取り出す際に変換する
+/// フィーチャーの有無で扱いが変わらないよう、どちらの表現も Copy は実装しない
+///
+/// # Examples
+///
+/// ```
+/// let number = "9007199254740993".parse::<node::Number>().unwrap();
+/// assert_eq!(number.as_u64(), Some(9007199254740993));
+/// assert_eq!(number.to_string(), "9007199254740993");
+///
+/// let number = "-1.5e2".parse::<node::Number>().unwrap();
+/// assert_eq!(number.as_i64(), None);
+/// assert_eq!(number.as_f64(), -150.0);
+/// ```
+#[cfg(not(feature = "arbitrary_precision"))]
+#[derive(Clone)]
+pub struct Number(N);
+
+/// JSONの数値を表現する
+/// 入力の表記をそのまま保持し、値を取り出す際に変換する
+/// 整数と小数は表記が異なれば別の値として扱い、同じ種類の表記は桁を省略せずに値を比較する（`1.50` と `15e-1` は等しい）
+/// 順序は値の大小とし、値が等しい整数と小数は整数を先とする
+///
+/// # Examples
+///
+/// ```
+/// let number = "0.10000000000000000001".parse::<node::Number>().unwrap();
+/// assert_eq!(number.as_str(), "0.10000000000000000001");
+/// assert_eq!(number.as_f64(), 0.1);
+/// assert_ne!(number, "0.1".parse().unwrap());
+/// ```
+#[cfg(feature = "arbitrary_precision")]
+#[derive(Clone)]
+pub struct Number(String);
+
+/// 正の整数は PosInt、負の整数は NegInt のみで表し、同じ値が２通りの表現を持たないようにする
+#[cfg(not(feature = "arbitrary_precision"))]
+#[derive(std::fmt::Debug, Clone, Copy)]
+enum N {
+    PosInt(u64),
+    NegInt(i64),
+    Float(f64),
+}
+
+impl Number {
+    /// i64 で表現できる整数か
+    pub fn is_i64(&self) -> bool {
+        self.as_i64().is_some()
+    }
+
+    /// u64 で表現できる整数か
+    pub fn is_u64(&self) -> bool {
+        self.as_u64().is_some()
+    }
+
+    /// 値が i128 の範囲の整数であれば返却する
+    /// as_i128 と異なり、`1.0`・`1e2` など小数の表記でも値が整数であれば返却する
+    pub fn as_integral_i128(&self) -> Option<i128> {
+        self.as_i128()
+            .or_else(|| exact_integer(self.as_f64()).map(|f| f as i128))
+    }
+
+    /// 値が u128 の範囲の整数であれば返却する
+    /// as_u128 と異なり、`1.0`・`1e2` など小数の表記でも値が整数であれば返却する
+    pub fn as_integral_u128(&self) -> Option<u128> {
+        self.as_u128().or_else(|| {
+            exact_integer(self.as_f64())
+                .filter(|f| *f >= 0.0)
+                .map(|f| f as u128)
+        })
+    }
+}
+
+/// f が小数部を持たず、絶対値が 2^53 以下であれば返却する
+/// それより大きい f64 は元の表記の整数から丸められている場合があるため、整数として扱わない
+fn exact_integer(f: f64) -> Option<f64> {
+    (f.fract() == 0.0 && f.abs() <= 2f64.powi(53)).then_some(f)
+}
+
+#[cfg(not(feature = "arbitrary_precision"))]
+impl Number {
+    /// 小数（指数表記を含む）か
+    pub fn is_f64(&self) -> bool {
+        matches!(self.0, N::Float(_))
+    }
+
+    /// i64 で表現できる整数の場合は値を返却する
+    pub fn as_i64(&self) -> Option<i64> {
+        match self.0 {
+            N::PosInt(n) => i64::try_from(n).ok(),
+            N::NegInt(n) => Some(n),
+            N::Float(_) => None,
+        }
+    }
+
+    /// u64 で表現できる整数の場合は値を返却する
+    pub fn as_u64(&self) -> Option<u64> {
+        match self.0 {
+            N::PosInt(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// i128 で表現できる整数の場合は値を返却する
+    /// u64 の範囲を超える整数は f64 で保持しているため None を返却する
+    pub fn as_i128(&self) -> Option<i128> {
+        match self.0 {
+            N::PosInt(n) => Some(n.into()),
+            N::NegInt(n) => Some(n.into()),
+            N::Float(_) => None,
+        }
+    }
+
+    /// u128 で表現できる整数の場合は値を返却する
+    /// u64 の範囲を超える整数は f64 で保持しているため None を返却する
+    pub fn as_u128(&self) -> Option<u128> {
+        self.as_u64().map(u128::from)
+    }
+
+    /// f64 に変換して返却する
+    /// 2^53 を超える整数は最も近い f64 に丸める
+    pub fn as_f64(&self) -> f64 {
+        match self.0 {
+            N::PosInt(n) => n as f64,
+            N::NegInt(n) => n as f64,
+            N::Float(n) => n,
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+impl Number {
+    /// 小数（指数表記を含む）か
+    pub fn is_f64(&self) -> bool {
+        is_float(&self.0)
+    }
+
+    /// i64 で表現できる整数の場合は値を返却する
+    pub fn as_i64(&self) -> Option<i64> {
+        if self.is_f64() {
+            None
+        } else {
+            self.0.parse().ok()
+        }
+    }
+
+    /// u64 で表現できる整数の場合は値を返却する
+    pub fn as_u64(&self) -> Option<u64> {
+        if self.is_f64() {
+            None
+        } else {
+            self.0.parse().ok()
+        }
+    }
+
+    /// i128 で表現できる整数の場合は値を返却する
+    pub fn as_i128(&self) -> Option<i128> {
+        if self.is_f64() {
+            None
+        } else {
+            self.0.parse().ok()
+        }
+    }
+
+    /// u128 で表現できる整数の場合は値を返却する
+    pub fn as_u128(&self) -> Option<u128> {
+        if self.is_f64() {
+            None
+        } else {
+            self.0.parse().ok()
+        }
+    }
+
+    /// f64 に変換して返却する
+    /// f64 で表現できない桁は最も近い f64 に丸める
+    pub fn as_f64(&self) -> f64 {
+        self.0.parse().expect("生成時に検証している")
+    }
+
+    /// 入力の表記を返却する
+    /// 10進数の多倍長型などへ変換する場合に利用する
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// 値を 0.d1d2d3... × 10^exponent の形に正規化し、符号・有効数字・指数を返却する
+    /// 有効数字は先頭と末尾の 0 を取り除き、値が 0 の場合は符号によらず (false, "", 0) となる
+    fn normalize(&self) -> (bool, String, i64) {
+        let (negative, s) = match self.0.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, self.0.as_str()),
+        };
+        let (mantissa, exponent) = match s.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => {
+                // i64 を超える指数は比較に影響しない範囲で飽和させる
+                let saturated = if exponent.starts_with('-') {
+                    i64::MIN / 2
+                } else {
+                    i64::MAX / 2
+                };
+                (mantissa, exponent.parse::<i64>().unwrap_or(saturated))
+            }
+            None => (s, 0),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+        let all = format!("{integer}{fraction}");
+        let digits = all.trim_start_matches('0');
+        let leading_zeros = (all.len() - digits.len()) as i64;
+        let digits = digits.trim_end_matches('0');
+        if digits.is_empty() {
+            return (false, String::new(), 0);
+        }
+
+        (
+            negative,
+            digits.to_string(),
+            exponent.saturating_add(integer.len() as i64 - leading_zeros),
+        )
+    }
+}
+
+#[cfg(not(feature = "arbitrary_precision"))]
+impl Ord for Number {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self.0, other.0) {
+            (N::Float(a), N::Float(b)) => float_key(a).total_cmp(&float_key(b)),
+            (N::Float(f), _) => compare_integer_float(other.integer(), f).reverse(),
+            (_, N::Float(f)) => compare_integer_float(self.integer(), f),
+            _ => self.integer().cmp(&other.integer()),
+        }
+    }
+}
+
+#[cfg(not(feature = "arbitrary_precision"))]
+impl std::hash::Hash for Number {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self.0 {
+            N::Float(f) => (true, float_key(f).to_bits()).hash(state),
+            _ => (false, self.integer()).hash(state),
+        }
+    }
+}
+
+#[cfg(not(feature = "arbitrary_precision"))]
+impl Number {
+    fn integer(&self) -> i128 {
+        match self.0 {
+            N::PosInt(n) => n.into(),
+            N::NegInt(n) => n.into(),
+            N::Float(_) => unreachable!("小数は整数として比較しない"),
+        }
+    }
+}
+
+/// `-0.0` を `0.0` と等しく扱うための比較用の値
+#[cfg(not(feature = "arbitrary_precision"))]
+fn float_key(f: f64) -> f64 {
+    if f == 0.0 { 0.0 } else { f }
+}
+
+/// 値が等しい場合は整数を先とする
+#[cfg(not(feature = "arbitrary_precision"))]
+fn compare_integer_float(i: i128, f: f64) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    if f.is_nan() {
+        // f64::total_cmp と同じく、符号が負の NaN は全ての値より前、それ以外は後とする
+        return if f.is_sign_negative() {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        };
+    }
+    // 整数は -2^63 以上 2^64 未満のため、範囲外の小数とは i128 に変換せずに比較する
+    if f >= 18446744073709551616.0 {
+        return Ordering::Less;
+    }
+    if f < -9223372036854775808.0 {
+        return Ordering::Greater;
+    }
+    match i.cmp(&(f.floor() as i128)) {
+        // i が f の整数部と等しい場合、f に端数があれば i が小さく、なければ整数を先とする
+        Ordering::Equal => Ordering::Less,
+        ordering => ordering,
+    }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+impl Ord for Number {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let (a_negative, a_digits, a_exponent) = self.normalize();
+        let (b_negative, b_digits, b_exponent) = other.normalize();
+        // 0 は符号によらず 0 とする
+        let signum = |negative: bool, digits: &str| match (negative, digits.is_empty()) {
+            (_, true) => 0,
+            (true, false) => -1,
+            (false, false) => 1,
+        };
+        let a_signum = signum(a_negative, &a_digits);
+        let b_signum = signum(b_negative, &b_digits);
+
+        // 有効数字の先頭と末尾の 0 は取り除いているため、指数が等しければ有効数字を辞書順に比較できる
+        let magnitude = (a_exponent, &a_digits).cmp(&(b_exponent, &b_digits));
+        let value = a_signum.cmp(&b_signum).then(match a_signum {
+            -1 => magnitude.reverse(),
+            _ => magnitude,
+        });
+
+        value.then(self.is_f64().cmp(&other.is_f64()))
+    }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+impl std::hash::Hash for Number {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (self.is_f64(), self.normalize()).hash(state);
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for Number {}
+
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+fn is_float(s: &str) -> bool {
+    s.contains(['.', 'e', 'E'])
+}
+
+#[cfg(not(feature = "arbitrary_precision"))]
+impl std::str::FromStr for Number {
+    type Err = ParseNumberError;
+
+    /// `.`・`e`・`E` を含まない表記は整数として解釈する
+    /// i64・u64 の範囲を超える整数は f64 として解釈し、f64 でも表現できない値はエラーとする
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        validate(s)?;
+        if !s.contains(['.', 'e', 'E']) {
+            if let Ok(n) = s.parse::<u64>() {
+                return Ok(n.into());
+            }
+            if let Ok(n) = s.parse::<i64>() {
+                return Ok(n.into());
+            }
+        }
+
+        finite(s).map(Number::from)
+    }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+impl std::str::FromStr for Number {
+    type Err = ParseNumberError;
+
+    /// JSONの数値の表記であることを検証し、表記をそのまま保持する
+    /// f64 で表現できる範囲を超える値はエラーとする
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        validate(s)?;
+        finite(s).map(|_| Self(s.to_string()))
+    }
+}
+
+/// 文字列を数値として解釈できなかったことを表現する
+#[derive(thiserror::Error, std::fmt::Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseNumberError {
+    /// JSONの数値の表記ではない（`NaN`・`inf`・`+1`・`01` など）
+    #[error("invalid number")]
+    Invalid,
+    /// f64 で表現できる範囲を超えている（`1e400` など）
+    #[error("number out of range")]
+    OutOfRange,
+}
+
+/// s がJSONの数値の表記であることを検証する
+fn validate(s: &str) -> Result<(), ParseNumberError> {
+    if is_json_number(s.as_bytes()) {
+        Ok(())
+    } else {
+        Err(ParseNumberError::Invalid)
+    }
+}
+
+/// 検証済みの s を f64 として解釈し、無限大になる値はエラーとする
+fn finite(s: &str) -> Result<f64, ParseNumberError> {
+    let f = s.parse::<f64>().map_err(|_| ParseNumberError::Invalid)?;
+    if f.is_finite() {
+        Ok(f)
+    } else {
+        Err(ParseNumberError::OutOfRange)
+    }
+}
+
+/// `-? (0 | [1-9][0-9]*) (. [0-9]+)? ([eE] [+-]? [0-9]+)?` に一致するか
+fn is_json_number(s: &[u8]) -> bool {
+    fn digits(s: &[u8]) -> usize {
+        s.iter().take_while(|c| c.is_ascii_digit()).count()
+    }
+
+    let mut s = s.strip_prefix(b"-").unwrap_or(s);
+    match s.first() {
+        Some(b'0') => s = &s[1..],
+        Some(b'1'..=b'9') => s = &s[digits(s)..],
+        _ => return false,
+    }
+    if let Some(rest) = s.strip_prefix(b".") {
+        let n = digits(rest);
+        if n == 0 {
+            return false;
+        }
+        s = &rest[n..];
+    }
+    if let Some(rest) = s.strip_prefix(b"e").or_else(|| s.strip_prefix(b"E")) {
+        let rest = rest
+            .strip_prefix(b"+")
+            .or_else(|| rest.strip_prefix(b"-"))
+            .unwrap_or(rest);
+        let n = digits(rest);
+        if n == 0 {
+            return false;
+        }
+        s = &rest[n..];
+    }
+    s.is_empty()
+}
+
+/// 整数は表記のまま、小数は f64 として解釈し直すと同じ値になる最短の表記で出力する
+/// 小数は整数と区別するため `1.0` のように出力し、NaN・無限大は `NaN`・`inf` となる
+/// `arbitrary_precision` フィーチャーが有効な場合は入力の表記をそのまま出力する
+impl std::fmt::Display for Number {
+    #[cfg(not(feature = "arbitrary_precision"))]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            N::PosInt(n) => write!(f, "{n}"),
+            N::NegInt(n) => write!(f, "{n}"),
+            N::Float(n) => write!(f, "{n:?}"),
+        }
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// 内部の表現によらず `Number(1)`・`Number(1.0)` のように出力する
+impl std::fmt::Debug for Number {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Number({self})")
+    }
+}
+
+#[cfg(not(feature = "arbitrary_precision"))]
+impl From<u64> for Number {
+    fn from(value: u64) -> Self {
+        Self(N::PosInt(value))
+    }
+}
+
+#[cfg(not(feature = "arbitrary_precision"))]
+impl From<i64> for Number {
+    fn from(value: i64) -> Self {
+        match u64::try_from(value) {
+            Ok(n) => Self(N::PosInt(n)),
+            Err(_) => Self(N::NegInt(value)),
+        }
+    }
+}
+
+#[cfg(not(feature = "arbitrary_precision"))]
+impl From<f64> for Number {
+    fn from(value: f64) -> Self {
+        Self(N::Float(value))
+    }
+}
+
+/// i64・u64 の範囲を超える整数は f64 に丸める
+#[cfg(not(feature = "arbitrary_precision"))]
+macro_rules! from_wide_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Number {
+                fn from(value: $ty) -> Self {
+                    if let Ok(n) = u64::try_from(value) {
+                        n.into()
+                    } else if let Ok(n) = i64::try_from(value) {
+                        n.into()
+                    } else {
+                        Self(N::Float(value as f64))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "arbitrary_precision"))]
+from_wide_integer!(i128, u128);
+
+#[cfg(feature = "arbitrary_precision")]
+impl From<u64> for Number {
+    fn from(value: u64) -> Self {
+        Self(value.to_string())
+    }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+impl From<i64> for Number {
+    fn from(value: i64) -> Self {
+        Self(value.to_string())
+    }
+}
+
+#[cfg(feature = "arbitrary_precision")]
+impl From<f64> for Number {
+    fn from(value: f64) -> Self {
+        Self(format!("{value:?}"))
+    }
+}
+
+/// 整数の表記をそのまま保持する
+#[cfg(feature = "arbitrary_precision")]
+macro_rules! from_integer_text {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Number {
+                fn from(value: $ty) -> Self {
+                    Self(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! from_integer {
+    ($via:ty: $($ty:ty),*) => {
+        $(
+            impl From<$ty> for Number {
+                fn from(value: $ty) -> Self {
+                    (value as $via).into()
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(u64: u8, u16, u32, usize);
+from_integer!(i64: i8, i16, i32, isize);
+from_integer!(f64: f32);
+
+#[cfg(feature = "arbitrary_precision")]
+from_integer_text!(i128, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest::rstest]
+    #[case("0", Some(0), Some(0))]
+    #[case("-12", Some(-12), None)]
+    #[case("18446744073709551615", None, Some(u64::MAX))]
+    #[case("-9223372036854775808", Some(i64::MIN), None)]
+    #[case("18446744073709551616", None, None)]
+    #[case("1.0", None, None)]
+    #[case("2.5e-3", None, None)]
+    #[case("1E2", None, None)]
+    #[case("1e+2", None, None)]
+    #[case("-0.5E-2", None, None)]
+    fn test_parse(#[case] input: &str, #[case] i: Option<i64>, #[case] u: Option<u64>) {
+        let number = input.parse::<Number>().unwrap();
+        assert_eq!(number.as_i64(), i);
+        assert_eq!(number.as_u64(), u);
+        assert_eq!(number.to_string().parse::<Number>().unwrap(), number);
+    }
+
+    #[rstest::rstest]
+    #[case("1e400")]
+    #[case("-1e400")]
+    #[case("1.8e308")]
+    fn test_out_of_range(#[case] input: &str) {
+        assert_eq!(input.parse::<Number>(), Err(ParseNumberError::OutOfRange));
+    }
+
+    #[rstest::rstest]
+    #[case("NaN")]
+    #[case("inf")]
+    #[case("-infinity")]
+    #[case("+1")]
+    #[case("01")]
+    #[case("1.")]
+    #[case(".5")]
+    #[case("1e")]
+    #[case("1e+")]
+    #[case("")]
+    fn test_invalid(#[case] input: &str) {
+        assert_eq!(input.parse::<Number>(), Err(ParseNumberError::Invalid));
+    }
+
+    #[rstest::rstest]
+    #[case("3", Some(3), Some(3))]
+    #[case("3.0", Some(3), Some(3))]
+    #[case("-1e2", Some(-100), None)]
+    #[case("-0.0", Some(0), Some(0))]
+    #[case("3.5", None, None)]
+    #[case("9007199254740992.0", Some(1 << 53), Some(1 << 53))]
+    #[case("9007199254740994.0", None, None)]
+    #[case("1e39", None, None)]
+    fn test_integral(#[case] input: &str, #[case] i: Option<i128>, #[case] u: Option<u128>) {
+        let number = input.parse::<Number>().unwrap();
+        assert_eq!(number.as_integral_i128(), i);
+        assert_eq!(number.as_integral_u128(), u);
+    }
+
+    #[rstest::rstest]
+    #[case(0, Some(0), Some(0))]
+    #[case(-1, Some(-1), None)]
+    #[case(u64::MAX as i128, Some(u64::MAX as i128), Some(u64::MAX as u128))]
+    #[case(i128::MIN, cfg!(feature = "arbitrary_precision").then_some(i128::MIN), None)]
+    #[case(
+        i128::MAX,
+        cfg!(feature = "arbitrary_precision").then_some(i128::MAX),
+        cfg!(feature = "arbitrary_precision").then_some(i128::MAX as u128)
+    )]
+    fn test_i128(#[case] value: i128, #[case] i: Option<i128>, #[case] u: Option<u128>) {
+        let number = Number::from(value);
+        assert_eq!(number.as_i128(), i);
+        assert_eq!(number.as_u128(), u);
+        assert_eq!(number.to_string().parse::<Number>().unwrap(), number);
+    }
+
+    #[cfg(not(feature = "arbitrary_precision"))]
+    #[rstest::rstest]
+    #[case("-0", "0")]
+    #[case("18446744073709551616", "1.8446744073709552e19")]
+    #[case("1.0", "1.0")]
+    #[case("2.5e-3", "0.0025")]
+    #[case("1E2", "100.0")]
+    fn test_display(#[case] input: &str, #[case] output: &str) {
+        assert_eq!(input.parse::<Number>().unwrap().to_string(), output);
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[rstest::rstest]
+    #[case("-0")]
+    #[case("18446744073709551616")]
+    #[case("0.10000000000000000001")]
+    #[case("1E2")]
+    fn test_display(#[case] input: &str) {
+        assert_eq!(input.parse::<Number>().unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Number::from(1u8), Number::from(1i64));
+        assert_ne!(Number::from(1), Number::from(1.0));
+        assert_eq!(Number::from(-1.5f32), Number::from(-1.5));
+    }
+
+    #[rstest::rstest]
+    #[case("1", "2")]
+    #[case("-1", "0")]
+    #[case("-9223372036854775808", "18446744073709551615")]
+    #[case("1", "1.0")]
+    #[case("1.5", "2")]
+    #[case("-2", "-1.5")]
+    #[case("-1.0", "-1e-300")]
+    #[case("18446744073709551615", "18446744073709551616.0")]
+    #[case("-1e300", "-9223372036854775808")]
+    #[case("1e-5", "1e-4")]
+    fn test_ord(#[case] a: &str, #[case] b: &str) {
+        let a = a.parse::<Number>().unwrap();
+        let b = b.parse::<Number>().unwrap();
+        assert!(a < b);
+        assert!(b > a);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::hash::BuildHasher;
+
+        let state = std::collections::hash_map::RandomState::new();
+        let hash = |n: &str| state.hash_one(n.parse::<Number>().unwrap());
+        assert_eq!(hash("-0.0"), hash("0.0"));
+        assert_eq!("-0.0".parse::<Number>().unwrap(), "0.0".parse().unwrap());
+        assert_ne!(hash("1"), hash("1.0"));
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[rstest::rstest]
+    #[case("1.50", "15e-1", true)]
+    #[case("1e2", "100.0", true)]
+    #[case("-0.0", "0e5", true)]
+    #[case("0.012", "1.2E-2", true)]
+    #[case("100", "1e2", false)]
+    #[case("0.1", "0.10000000000000000001", false)]
+    #[case("-1.5", "1.5", false)]
+    #[case("1.5", "15", false)]
+    fn test_eq_exact(#[case] a: &str, #[case] b: &str, #[case] expected: bool) {
+        let a = a.parse::<Number>().unwrap();
+        let b = b.parse::<Number>().unwrap();
+        assert_eq!(a == b, expected);
+    }
+}
@@ -0,0 +1,375 @@
+use crate::{MapKind, Node, Number, ObjectMap, Pointer};
+
+/// RFC 6902 の JSON Patch の操作を表現する
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub enum Operation<K: MapKind = crate::map::Standard> {
+    /// path に value を加える（Array では指す位置に挿入する）
+    Add { path: Pointer, value: Node<K> },
+    /// path の値を取り除く
+    Remove { path: Pointer },
+    /// path の値を value で置き換える
+    Replace { path: Pointer, value: Node<K> },
+    /// from の値を取り除き、path に加える
+    Move { from: Pointer, path: Pointer },
+    /// from の値を path に複製する
+    Copy { from: Pointer, path: Pointer },
+    /// path の値が value と等しいことを確かめる
+    Test { path: Pointer, value: Node<K> },
+}
+
+/// RFC 6902 の JSON Patch（操作の列）を表現する
+/// 操作の配列の Node から変換し、Node::apply_patch で適用する
+///
+/// # Examples
+///
+/// ```
+/// let patch = node::patch::Patch::try_from(&node::json!([
+///     { "op": "test", "path": "/version", "value": 1 },
+///     { "op": "replace", "path": "/version", "value": 2 },
+///     { "op": "add", "path": "/tags/0", "value": "new" },
+/// ]))
+/// .unwrap();
+/// let mut doc: node::Node = node::json!({ "version": 1, "tags": ["old"] });
+/// doc.apply_patch(&patch).unwrap();
+/// assert_eq!(doc, node::json!({ "version": 2, "tags": ["new", "old"] }));
+/// ```
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub struct Patch<K: MapKind = crate::map::Standard>(Vec<Operation<K>>);
+
+/// JSON Patch を解釈・適用できない理由と、その操作の位置（先頭を 0 とする）を表現する
+#[derive(thiserror::Error, std::fmt::Debug, Clone, PartialEq)]
+pub enum PatchError {
+    #[error("JSON Patch の {index} 番目の操作が不正です（{message}）")]
+    InvalidOperation { index: usize, message: String },
+    #[error("JSON Patch の {index} 番目の操作で `{path}` が存在しません")]
+    NotFound { index: usize, path: Pointer },
+    #[error("JSON Patch の {index} 番目の操作で `{path}` の値が一致しません")]
+    TestFailed { index: usize, path: Pointer },
+}
+
+impl<K: MapKind> Patch<K> {
+    /// 操作を返却する
+    pub fn operations(&self) -> &[Operation<K>] {
+        &self.0
+    }
+}
+
+impl<K: MapKind> FromIterator<Operation<K>> for Patch<K> {
+    fn from_iter<T: IntoIterator<Item = Operation<K>>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// 操作の Object の Array から変換する
+impl<K: MapKind> TryFrom<&Node<K>> for Patch<K> {
+    type Error = PatchError;
+
+    fn try_from(node: &Node<K>) -> Result<Self, PatchError> {
+        let invalid =
+            |index: usize, message: String| PatchError::InvalidOperation { index, message };
+        let Some(operations) = node.as_array() else {
+            return Err(invalid(
+                0,
+                format!(
+                    "JSON Patch は array でなければなりませんが {} でした",
+                    node.kind()
+                ),
+            ));
+        };
+
+        operations
+            .iter()
+            .enumerate()
+            .map(|(index, operation)| {
+                let pointer = |name: &str| -> Result<Pointer, PatchError> {
+                    let value = operation
+                        .get(name)
+                        .ok_or_else(|| invalid(index, format!("`{name}` がありません")))?;
+                    let pointer = value.as_str().ok_or_else(|| {
+                        invalid(index, format!("`{name}` は string でなければなりません"))
+                    })?;
+                    pointer.parse().map_err(|e| invalid(index, format!("{e}")))
+                };
+                let value = || {
+                    operation
+                        .get("value")
+                        .cloned()
+                        .ok_or_else(|| invalid(index, "`value` がありません".into()))
+                };
+
+                match operation.get("op").and_then(Node::as_str) {
+                    Some("add") => Ok(Operation::Add {
+                        path: pointer("path")?,
+                        value: value()?,
+                    }),
+                    Some("remove") => Ok(Operation::Remove {
+                        path: pointer("path")?,
+                    }),
+                    Some("replace") => Ok(Operation::Replace {
+                        path: pointer("path")?,
+                        value: value()?,
+                    }),
+                    Some("move") => Ok(Operation::Move {
+                        from: pointer("from")?,
+                        path: pointer("path")?,
+                    }),
+                    Some("copy") => Ok(Operation::Copy {
+                        from: pointer("from")?,
+                        path: pointer("path")?,
+                    }),
+                    Some("test") => Ok(Operation::Test {
+                        path: pointer("path")?,
+                        value: value()?,
+                    }),
+                    Some(op) => Err(invalid(index, format!("`{op}` は操作として解釈できません"))),
+                    None => Err(invalid(index, "`op` がありません".into())),
+                }
+            })
+            .collect()
+    }
+}
+
+/// 操作の Object の Array に変換する
+impl<K: MapKind> From<&Patch<K>> for Node<K> {
+    fn from(patch: &Patch<K>) -> Self {
+        patch
+            .0
+            .iter()
+            .map(|operation| {
+                let (op, from, path, value) = match operation {
+                    Operation::Add { path, value } => ("add", None, path, Some(value)),
+                    Operation::Remove { path } => ("remove", None, path, None),
+                    Operation::Replace { path, value } => ("replace", None, path, Some(value)),
+                    Operation::Move { from, path } => ("move", Some(from), path, None),
+                    Operation::Copy { from, path } => ("copy", Some(from), path, None),
+                    Operation::Test { path, value } => ("test", None, path, Some(value)),
+                };
+                Node::object()
+                    .field("op", op)
+                    .field_opt("from", from.map(|from| from.to_string()))
+                    .field("path", path.to_string())
+                    .field_opt("value", value.cloned())
+                    .build()
+            })
+            .collect()
+    }
+}
+
+impl<K: MapKind> Node<K> {
+    /// JSON Patch の操作を順に適用する
+    /// いずれかの操作が失敗した場合は PatchError を返却し、値は変更しない
+    pub fn apply_patch(&mut self, patch: &Patch<K>) -> Result<(), PatchError> {
+        let mut patched = self.clone();
+        for (index, operation) in patch.0.iter().enumerate() {
+            patched.apply_operation(index, operation)?;
+        }
+        *self = patched;
+
+        Ok(())
+    }
+
+    fn apply_operation(
+        &mut self,
+        index: usize,
+        operation: &Operation<K>,
+    ) -> Result<(), PatchError> {
+        let not_found = |path: &Pointer| PatchError::NotFound {
+            index,
+            path: path.clone(),
+        };
+
+        match operation {
+            Operation::Add { path, value } => self.patch_add(index, path, value.clone()),
+            Operation::Remove { path } => {
+                path.remove(self).map(drop).ok_or_else(|| not_found(path))
+            }
+            Operation::Replace { path, value } => {
+                let target = path.get_mut(self).ok_or_else(|| not_found(path))?;
+                *target = value.clone();
+                Ok(())
+            }
+            Operation::Move { from, path } => {
+                if from == path {
+                    return path.get(self).map(drop).ok_or_else(|| not_found(path));
+                }
+                if path.starts_with(from) {
+                    return Err(PatchError::InvalidOperation {
+                        index,
+                        message: format!("`{from}` を自身の子孫の `{path}` へ移動できません"),
+                    });
+                }
+                let value = from.remove(self).ok_or_else(|| not_found(from))?;
+                self.patch_add(index, path, value)
+            }
+            Operation::Copy { from, path } => {
+                let value = from.get(self).cloned().ok_or_else(|| not_found(from))?;
+                self.patch_add(index, path, value)
+            }
+            Operation::Test { path, value } => match path.get(self) {
+                Some(target) if test_eq(target, value) => Ok(()),
+                Some(_) => Err(PatchError::TestFailed {
+                    index,
+                    path: path.clone(),
+                }),
+                None => Err(not_found(path)),
+            },
+        }
+    }
+
+    /// 親の値は存在しなければならず、Array では指す位置に挿入する
+    fn patch_add(
+        &mut self,
+        index: usize,
+        path: &Pointer,
+        value: Node<K>,
+    ) -> Result<(), PatchError> {
+        let (Some(parent), Some(token)) = (path.parent(), path.tokens().last()) else {
+            *self = value;
+            return Ok(());
+        };
+        let not_found = || PatchError::NotFound {
+            index,
+            path: path.clone(),
+        };
+
+        match parent.get_mut(self).ok_or_else(not_found)? {
+            Node::Object(object) => {
+                object.insert(token.clone(), value);
+            }
+            Node::Array(array) => {
+                let position = match token.as_str() {
+                    "-" => array.len(),
+                    _ => Pointer::parse_index(token)
+                        .filter(|position| *position <= array.len())
+                        .ok_or_else(not_found)?,
+                };
+                array.insert(position, value);
+            }
+            _ => return Err(not_found()),
+        }
+
+        Ok(())
+    }
+}
+
+/// RFC 6902 に従い、数値は表記によらず値が等しければ等しいものとする
+/// Array は並び順も含めて、Object は並び順によらず比較する
+fn test_eq<K: MapKind>(a: &Node<K>, b: &Node<K>) -> bool {
+    match (a, b) {
+        (Node::Number(a), Node::Number(b)) => number_eq(a, b),
+        (Node::Array(a), Node::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| test_eq(a, b))
+        }
+        (Node::Object(a), Node::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, a)| b.get(key).is_some_and(|b| test_eq(a, b)))
+        }
+        (a, b) => a == b,
+    }
+}
+
+/// 整数の値は f64 に丸めずに比較し、小数部を持つ値のみ f64 として比較する
+fn number_eq(a: &Number, b: &Number) -> bool {
+    // 小数の表記でも値が整数であれば整数として扱う（2^53 を超える f64 は全て整数となる）
+    fn integer(n: &Number) -> Option<i128> {
+        n.as_i128().or_else(|| {
+            let f = n.as_f64();
+            (f.fract() == 0.0 && f.abs() < i128::MAX as f64).then_some(f as i128)
+        })
+    }
+
+    match (integer(a), integer(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a.as_f64() == b.as_f64(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+
+    fn patch(node: Node) -> Patch {
+        Patch::try_from(&node).unwrap()
+    }
+
+    #[rstest::rstest]
+    #[case(json!({ "foo": "bar" }), json!([{ "op": "add", "path": "/baz", "value": "qux" }]), json!({ "baz": "qux", "foo": "bar" }))]
+    #[case(json!({ "foo": ["bar", "baz"] }), json!([{ "op": "add", "path": "/foo/1", "value": "qux" }]), json!({ "foo": ["bar", "qux", "baz"] }))]
+    #[case(json!({ "foo": [1] }), json!([{ "op": "add", "path": "/foo/-", "value": [2] }]), json!({ "foo": [1, [2]] }))]
+    #[case(json!({ "baz": "qux", "foo": "bar" }), json!([{ "op": "remove", "path": "/baz" }]), json!({ "foo": "bar" }))]
+    #[case(json!({ "foo": ["bar", "qux", "baz"] }), json!([{ "op": "remove", "path": "/foo/1" }]), json!({ "foo": ["bar", "baz"] }))]
+    #[case(json!({ "baz": "qux" }), json!([{ "op": "replace", "path": "/baz", "value": "boo" }]), json!({ "baz": "boo" }))]
+    #[case(
+        json!({ "foo": { "bar": "baz", "waldo": "fred" }, "qux": { "corge": "grault" } }),
+        json!([{ "op": "move", "from": "/foo/waldo", "path": "/qux/thud" }]),
+        json!({ "foo": { "bar": "baz" }, "qux": { "corge": "grault", "thud": "fred" } })
+    )]
+    #[case(json!({ "foo": [1, 2, 3, 4] }), json!([{ "op": "move", "from": "/foo/1", "path": "/foo/3" }]), json!({ "foo": [1, 3, 4, 2] }))]
+    #[case(json!({ "a": [1] }), json!([{ "op": "copy", "from": "/a", "path": "/b" }]), json!({ "a": [1], "b": [1] }))]
+    #[case(json!({ "a": 1.0 }), json!([{ "op": "test", "path": "/a", "value": 1 }]), json!({ "a": 1.0 }))]
+    #[case(json!({ "a": [1e2, { "b": -0.0 }] }), json!([{ "op": "test", "path": "/a", "value": [100, { "b": 0 }] }]), json!({ "a": [1e2, { "b": -0.0 }] }))]
+    #[case(json!({ "a": 1 }), json!([{ "op": "add", "path": "", "value": [] }]), json!([]))]
+    fn test_apply_patch(#[case] mut doc: Node, #[case] operations: Node, #[case] expected: Node) {
+        let patch = patch(operations.clone());
+        assert_eq!(Node::from(&patch), operations);
+        doc.apply_patch(&patch).unwrap();
+        assert_eq!(doc, expected);
+    }
+
+    #[rstest::rstest]
+    #[case(
+        json!([{ "op": "add", "path": "/a/b", "value": 1 }]),
+        PatchError::NotFound { index: 0, path: "/a/b".parse().unwrap() }
+    )]
+    #[case(
+        json!([{ "op": "add", "path": "/list/3", "value": 1 }]),
+        PatchError::NotFound { index: 0, path: "/list/3".parse().unwrap() }
+    )]
+    #[case(
+        json!([{ "op": "remove", "path": "/list/1" }, { "op": "test", "path": "/list/0", "value": 2 }]),
+        PatchError::TestFailed { index: 1, path: "/list/0".parse().unwrap() }
+    )]
+    #[case(
+        json!([{ "op": "move", "from": "/list", "path": "/list/0" }]),
+        PatchError::InvalidOperation { index: 0, message: "`/list` を自身の子孫の `/list/0` へ移動できません".into() }
+    )]
+    #[case(
+        json!([{ "op": "test", "path": "/list/0", "value": 1.5 }]),
+        PatchError::TestFailed { index: 0, path: "/list/0".parse().unwrap() }
+    )]
+    fn test_apply_patch_error(#[case] operations: Node, #[case] expected: PatchError) {
+        let mut doc: Node = json!({ "list": [1, 2] });
+        assert_eq!(doc.apply_patch(&patch(operations)), Err(expected));
+        assert_eq!(doc, json!({ "list": [1, 2] }));
+    }
+
+    #[rstest::rstest]
+    #[case("9007199254740992", "9007199254740993", false)]
+    #[case("9007199254740993", "9007199254740993", true)]
+    #[case("9007199254740993", "9007199254740992.0", false)]
+    #[case("9007199254740994", "9007199254740994.0", true)]
+    #[case("18446744073709551615", "18446744073709551614", false)]
+    #[case("-9223372036854775808", "-9223372036854775807", false)]
+    #[case("0.1", "1e-1", true)]
+    fn test_test_exact_number(#[case] value: &str, #[case] target: &str, #[case] ok: bool) {
+        let mut doc: Node = json!({ "n": (target.parse::<Number>().unwrap()) });
+        let operations =
+            json!([{ "op": "test", "path": "/n", "value": (value.parse::<Number>().unwrap()) }]);
+        assert_eq!(doc.apply_patch(&patch(operations)).is_ok(), ok);
+    }
+
+    #[rstest::rstest]
+    #[case(json!({}), "JSON Patch の 0 番目の操作が不正です（JSON Patch は array でなければなりませんが object でした）")]
+    #[case(json!([{ "op": "add", "path": "/a" }]), "JSON Patch の 0 番目の操作が不正です（`value` がありません）")]
+    #[case(json!([{ "op": "remove", "path": "/a" }, { "op": "copy", "path": "/a" }]), "JSON Patch の 1 番目の操作が不正です（`from` がありません）")]
+    #[case(json!([{ "op": "remove", "path": "a" }]), "JSON Patch の 0 番目の操作が不正です（JSON Pointer `a` は `/` で始まらなければなりません）")]
+    #[case(json!([{ "op": "merge", "path": "/a" }]), "JSON Patch の 0 番目の操作が不正です（`merge` は操作として解釈できません）")]
+    fn test_invalid_patch(#[case] operations: Node, #[case] message: &str) {
+        assert_eq!(
+            Patch::try_from(&operations).unwrap_err().to_string(),
+            message
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{Kind, MapKind, Node};
+
+/// Node::get_path が辿る１段分の位置を表現する
+#[derive(std::fmt::Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    /// Object のキー
+    Key(String),
+    /// Array の添字
+    Index(usize),
+}
+
+/// キーは `.key`、添字は `[0]` の形式で出力する
+impl std::fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathSegment::Key(key) => write!(f, ".{key}"),
+            PathSegment::Index(index) => write!(f, "[{index}]"),
+        }
+    }
+}
+
+impl From<&str> for PathSegment {
+    fn from(value: &str) -> Self {
+        PathSegment::Key(value.into())
+    }
+}
+
+impl From<String> for PathSegment {
+    fn from(value: String) -> Self {
+        PathSegment::Key(value)
+    }
+}
+
+impl From<usize> for PathSegment {
+    fn from(value: usize) -> Self {
+        PathSegment::Index(value)
+    }
+}
+
+/// キーと添字を混在させた PathSegment の列を組み立てる
+/// `[PathSegment]` として Node::get_path に渡せる
+///
+/// # Examples
+///
+/// ```
+/// let path = node::Path::new().key("items").index(0).key("id");
+/// assert_eq!(path.to_string(), ".items[0].id");
+/// ```
+#[derive(std::fmt::Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Path(Vec<PathSegment>);
+
+impl Path {
+    /// 空のパスを生成して返却する
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Object のキーを末尾に加えて返却する
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.0.push(PathSegment::Key(key.into()));
+        self
+    }
+
+    /// Array の添字を末尾に加えて返却する
+    pub fn index(mut self, index: usize) -> Self {
+        self.0.push(PathSegment::Index(index));
+        self
+    }
+}
+
+impl std::ops::Deref for Path {
+    type Target = [PathSegment];
+
+    fn deref(&self) -> &[PathSegment] {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|segment| write!(f, "{segment}"))
+    }
+}
+
+impl FromIterator<PathSegment> for Path {
+    fn from_iter<T: IntoIterator<Item = PathSegment>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// Node::get_path で辿れなかった位置（先頭を 0 とする）とその理由を表現する
+#[derive(thiserror::Error, std::fmt::Debug, Clone, PartialEq)]
+pub enum PathError {
+    #[error("パスの位置 {position} の {segment} が存在しません")]
+    NotFound {
+        position: usize,
+        segment: PathSegment,
+    },
+    #[error("パスの位置 {position} の {segment} は {found} の値を辿れません")]
+    TypeMismatch {
+        position: usize,
+        segment: PathSegment,
+        found: Kind,
+    },
+}
+
+impl<K: MapKind> Node<K> {
+    /// キーと添字を順に辿った先の値を返却する
+    /// 辿れない場合は失敗した位置を持つ PathError を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use node::{Node, Path, PathError, PathSegment};
+    ///
+    /// let node: Node = Node::Object(node::Map::from([(
+    ///     "items".to_string(),
+    ///     Node::Array(vec![Node::Bool(true)]),
+    /// )]));
+    /// assert_eq!(node.get_path(&Path::new().key("items").index(0)), Ok(&Node::Bool(true)));
+    /// assert_eq!(
+    ///     node.get_path(&["items".into(), 1.into()]),
+    ///     Err(PathError::NotFound { position: 1, segment: PathSegment::Index(1) })
+    /// );
+    /// ```
+    pub fn get_path(&self, path: &[PathSegment]) -> Result<&Node<K>, PathError> {
+        path.iter()
+            .enumerate()
+            .try_fold(self, |node, (position, segment)| {
+                let next = match segment {
+                    PathSegment::Key(key) if node.is_object() => node.get(key),
+                    PathSegment::Index(index) if node.is_array() => node.get_index(*index),
+                    _ => return Err(mismatch(position, segment, node.kind())),
+                };
+                next.ok_or_else(|| PathError::NotFound {
+                    position,
+                    segment: segment.clone(),
+                })
+            })
+    }
+
+    /// Node::get_path と同様に辿った先の値の可変参照を返却する
+    pub fn get_path_mut(&mut self, path: &[PathSegment]) -> Result<&mut Node<K>, PathError> {
+        path.iter()
+            .enumerate()
+            .try_fold(self, |node, (position, segment)| {
+                let kind = node.kind();
+                let next = match segment {
+                    PathSegment::Key(key) if kind == Kind::Object => node.get_mut(key),
+                    PathSegment::Index(index) if kind == Kind::Array => node.get_index_mut(*index),
+                    _ => return Err(mismatch(position, segment, kind)),
+                };
+                next.ok_or_else(|| PathError::NotFound {
+                    position,
+                    segment: segment.clone(),
+                })
+            })
+    }
+}
+
+fn mismatch(position: usize, segment: &PathSegment, found: Kind) -> PathError {
+    PathError::TypeMismatch {
+        position,
+        segment: segment.clone(),
+        found,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Map;
+
+    fn node() -> Node {
+        Node::Object(Map::from([(
+            "a".to_string(),
+            Node::Array(vec![Node::Object(Map::from([(
+                "b".to_string(),
+                Node::Null,
+            )]))]),
+        )]))
+    }
+
+    #[rstest::rstest]
+    #[case(Path::new(), Ok(node()))]
+    #[case(Path::new().key("a").index(0).key("b"), Ok(Node::Null))]
+    #[case(
+        Path::new().key("a").index(1),
+        Err(PathError::NotFound { position: 1, segment: PathSegment::Index(1) })
+    )]
+    #[case(
+        Path::new().key("a").key("b"),
+        Err(PathError::TypeMismatch { position: 1, segment: "b".into(), found: Kind::Array })
+    )]
+    #[case(
+        Path::new().key("a").index(0).key("b").index(0),
+        Err(PathError::TypeMismatch { position: 3, segment: 0.into(), found: Kind::Null })
+    )]
+    fn test_get_path(#[case] path: Path, #[case] expected: Result<Node, PathError>) {
+        let mut node = node();
+        assert_eq!(node.get_path(&path).cloned(), expected);
+        assert_eq!(node.get_path_mut(&path).cloned(), expected);
+    }
+
+    #[test]
+    fn test_error_message() {
+        let error = node().get_path(&["a".into(), "b".into()]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "パスの位置 1 の .b は array の値を辿れません"
+        );
+    }
+}
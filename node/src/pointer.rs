@@ -0,0 +1,424 @@
+use crate::{Kind, MapKind, Node, ObjectMap};
+
+/// RFC 6901 の JSON Pointer（`/a/b/0` など）を、エスケープを戻した参照トークンの列として表現する
+/// 一度解析しておくと、複数の Node に対して繰り返し利用できる
+///
+/// # Examples
+///
+/// ```
+/// let pointer = "/a~1b/~0c/1".parse::<node::Pointer>().unwrap();
+/// assert_eq!(pointer.tokens(), ["a/b", "~c", "1"]);
+/// assert_eq!(pointer.to_string(), "/a~1b/~0c/1");
+/// assert!("a".parse::<node::Pointer>().is_err());
+/// ```
+#[derive(std::fmt::Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Pointer(Vec<String>);
+
+/// JSON Pointer として解釈できない理由を表現する
+#[derive(thiserror::Error, std::fmt::Debug, Clone, PartialEq, Eq)]
+pub enum PointerError {
+    #[error("JSON Pointer `{0}` は `/` で始まらなければなりません")]
+    MissingSlash(String),
+    #[error("JSON Pointer `{0}` の `~` の後は `0` か `1` でなければなりません")]
+    InvalidEscape(String),
+    #[error("JSON Pointer `{pointer}` の `{token}` が存在しません")]
+    NotFound { pointer: String, token: String },
+    #[error("JSON Pointer `{pointer}` の `{token}` は {found} の値を辿れません")]
+    TypeMismatch {
+        pointer: String,
+        token: String,
+        found: Kind,
+    },
+}
+
+impl Pointer {
+    /// 値全体を指す空の JSON Pointer を生成して返却する
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    /// 値全体を指すか
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// エスケープを戻した参照トークンを返却する
+    pub fn tokens(&self) -> &[String] {
+        &self.0
+    }
+
+    /// 参照トークンを末尾に加える
+    pub fn push(&mut self, token: impl Into<String>) {
+        self.0.push(token.into());
+    }
+
+    /// 参照トークンを先頭に加える
+    pub(crate) fn push_front(&mut self, token: impl Into<String>) {
+        self.0.insert(0, token.into());
+    }
+
+    /// 末尾の参照トークンを取り除いて返却する
+    pub fn pop(&mut self) -> Option<String> {
+        self.0.pop()
+    }
+
+    /// 親の値を指す JSON Pointer を返却する
+    /// 値全体を指す場合は None を返却する
+    pub fn parent(&self) -> Option<Pointer> {
+        self.0
+            .split_last()
+            .map(|(_, parent)| Pointer(parent.to_vec()))
+    }
+
+    /// other が指す値かその子孫を指すか
+    pub fn starts_with(&self, other: &Pointer) -> bool {
+        self.0.starts_with(&other.0)
+    }
+
+    /// 指す値を返却する
+    /// 存在しない場合は None を返却する
+    pub fn get<'a, K: MapKind>(&self, node: &'a Node<K>) -> Option<&'a Node<K>> {
+        self.0.iter().try_fold(node, |node, token| match node {
+            Node::Object(_) => node.get(token),
+            Node::Array(_) => node.get_index(Self::parse_index(token)?),
+            _ => None,
+        })
+    }
+
+    /// 指す値の可変参照を返却する
+    /// 存在しない場合は None を返却する
+    pub fn get_mut<'a, K: MapKind>(&self, node: &'a mut Node<K>) -> Option<&'a mut Node<K>> {
+        Self::resolve_mut(&self.0, node)
+    }
+
+    /// 指す位置に値を格納し、元の値を返却する
+    /// 途中や末尾の Object にキーがない場合は作成し、Node::Null は空の Object に置き換えてから辿る
+    /// Array では要素数と同じ添字か `-` の場合に末尾へ追加し、それより大きな添字は PointerError::NotFound とする
+    /// Object・Array・Null 以外を辿る場合は PointerError::TypeMismatch を返却する
+    pub fn set<K: MapKind>(
+        &self,
+        node: &mut Node<K>,
+        value: Node<K>,
+    ) -> Result<Option<Node<K>>, PointerError> {
+        let Some((last, parents)) = self.0.split_last() else {
+            return Ok(Some(std::mem::replace(node, value)));
+        };
+        let parent = parents
+            .iter()
+            .try_fold(node, |node, token| self.entry(node, token))?;
+        if parent.is_null() {
+            *parent = Node::Object(K::Map::default());
+        }
+
+        let kind = parent.kind();
+        match parent {
+            Node::Object(object) => Ok(object.insert(last.clone(), value)),
+            Node::Array(array) => {
+                let index = self.append_index(array.len(), last)?;
+                if index == array.len() {
+                    array.push(value);
+                    Ok(None)
+                } else {
+                    Ok(Some(std::mem::replace(&mut array[index], value)))
+                }
+            }
+            _ => Err(self.mismatch(last, kind)),
+        }
+    }
+
+    /// 指す値を取り除いて返却する
+    /// 存在しない場合や値全体を指す場合は None を返却する
+    pub fn remove<K: MapKind>(&self, node: &mut Node<K>) -> Option<Node<K>> {
+        let (last, parents) = self.0.split_last()?;
+        match Self::resolve_mut(parents, node)? {
+            Node::Object(object) => object.remove(last),
+            Node::Array(array) => {
+                let index = Self::parse_index(last)?;
+                (index < array.len()).then(|| array.remove(index))
+            }
+            _ => None,
+        }
+    }
+
+    /// 参照トークンを Array の添字として解釈する
+    /// 先頭が 0 の数字（`0` を除く）と末尾の次を表す `-` は添字として扱わない
+    pub fn parse_index(token: &str) -> Option<usize> {
+        if token != "0" && token.starts_with('0') {
+            return None;
+        }
+        if !token.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        token.parse().ok()
+    }
+
+    fn resolve_mut<'a, K: MapKind>(
+        tokens: &[String],
+        node: &'a mut Node<K>,
+    ) -> Option<&'a mut Node<K>> {
+        tokens.iter().try_fold(node, |node, token| match node {
+            Node::Object(_) => node.get_mut(token),
+            Node::Array(_) => node.get_index_mut(Self::parse_index(token)?),
+            _ => None,
+        })
+    }
+
+    /// Pointer::set で途中の値を辿る
+    /// 存在しない場合は Node::Null を格納してその可変参照を返却する
+    fn entry<'a, K: MapKind>(
+        &self,
+        node: &'a mut Node<K>,
+        token: &str,
+    ) -> Result<&'a mut Node<K>, PointerError> {
+        if node.is_null() {
+            *node = Node::Object(K::Map::default());
+        }
+
+        let kind = node.kind();
+        match node {
+            Node::Object(object) => {
+                if !object.contains_key(token) {
+                    object.insert(token.to_string(), Node::Null);
+                }
+                Ok(object.get_mut(token).expect("直前に格納している"))
+            }
+            Node::Array(array) => {
+                let index = self.append_index(array.len(), token)?;
+                if index == array.len() {
+                    array.push(Node::Null);
+                }
+                Ok(&mut array[index])
+            }
+            _ => Err(self.mismatch(token, kind)),
+        }
+    }
+
+    /// 要素数 len の Array で末尾への追加を含めた添字として解釈する
+    fn append_index(&self, len: usize, token: &str) -> Result<usize, PointerError> {
+        match token {
+            "-" => Ok(len),
+            _ => Self::parse_index(token)
+                .filter(|index| *index <= len)
+                .ok_or_else(|| PointerError::NotFound {
+                    pointer: self.to_string(),
+                    token: token.into(),
+                }),
+        }
+    }
+
+    fn mismatch(&self, token: &str, found: Kind) -> PointerError {
+        PointerError::TypeMismatch {
+            pointer: self.to_string(),
+            token: token.into(),
+            found,
+        }
+    }
+}
+
+impl std::str::FromStr for Pointer {
+    type Err = PointerError;
+
+    /// 空文字列は値全体を指す
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Ok(Self::root());
+        }
+        let Some(rest) = s.strip_prefix('/') else {
+            return Err(PointerError::MissingSlash(s.into()));
+        };
+
+        rest.split('/')
+            .map(|token| {
+                let mut unescaped = String::with_capacity(token.len());
+                let mut chars = token.chars();
+                // `~1` を先に置き換えると `~01` が `/` となってしまうため、１文字ずつ戻す
+                while let Some(c) = chars.next() {
+                    if c != '~' {
+                        unescaped.push(c);
+                        continue;
+                    }
+                    match chars.next() {
+                        Some('0') => unescaped.push('~'),
+                        Some('1') => unescaped.push('/'),
+                        _ => return Err(PointerError::InvalidEscape(s.into())),
+                    }
+                }
+                Ok(unescaped)
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// `~` と `/` をエスケープした JSON Pointer の表記を出力する
+impl std::fmt::Display for Pointer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0
+            .iter()
+            .try_for_each(|token| write!(f, "/{}", token.replace('~', "~0").replace('/', "~1")))
+    }
+}
+
+impl<K: MapKind> Node<K> {
+    /// JSON Pointer が指す値を返却する
+    /// 存在しない場合や JSON Pointer として解釈できない場合は None を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let node: node::Node = node::Node::Object(node::Map::from([(
+    ///     "a".to_string(),
+    ///     node::Node::Array(vec![node::Node::Null]),
+    /// )]));
+    /// assert_eq!(node.pointer("/a/0"), Some(&node::Node::Null));
+    /// assert_eq!(node.pointer("/a/1"), None);
+    /// assert_eq!(node.pointer(""), Some(&node));
+    /// ```
+    pub fn pointer(&self, pointer: &str) -> Option<&Node<K>> {
+        pointer.parse::<Pointer>().ok()?.get(self)
+    }
+
+    /// JSON Pointer が指す値の可変参照を返却する
+    /// 存在しない場合や JSON Pointer として解釈できない場合は None を返却する
+    pub fn pointer_mut(&mut self, pointer: &str) -> Option<&mut Node<K>> {
+        pointer.parse::<Pointer>().ok()?.get_mut(self)
+    }
+
+    /// JSON Pointer が指す位置に値を格納し、元の値を返却する
+    /// 途中の値の作成については Pointer::set に従う
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut node: node::Node = node::Node::Null;
+    /// node.set_pointer("/a/b", node::Node::Array(vec![])).unwrap();
+    /// node.set_pointer("/a/b/-", node::Node::Bool(true)).unwrap();
+    /// assert_eq!(node.pointer("/a/b/0"), Some(&node::Node::Bool(true)));
+    /// assert_eq!(node.remove_pointer("/a/b/0"), Some(node::Node::Bool(true)));
+    /// assert_eq!(node.pointer("/a/b/0"), None);
+    /// ```
+    pub fn set_pointer(
+        &mut self,
+        pointer: &str,
+        value: Node<K>,
+    ) -> Result<Option<Node<K>>, PointerError> {
+        pointer.parse::<Pointer>()?.set(self, value)
+    }
+
+    /// JSON Pointer が指す値を取り除いて返却する
+    /// 存在しない場合や JSON Pointer として解釈できない場合は None を返却する
+    pub fn remove_pointer(&mut self, pointer: &str) -> Option<Node<K>> {
+        pointer.parse::<Pointer>().ok()?.remove(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Map;
+
+    #[rstest::rstest]
+    #[case("", Ok(vec![]))]
+    #[case("/", Ok(vec![""]))]
+    #[case("/a~1b/~0c/~01", Ok(vec!["a/b", "~c", "~1"]))]
+    #[case("//x/", Ok(vec!["", "x", ""]))]
+    #[case("a", Err(PointerError::MissingSlash("a".into())))]
+    #[case("/a~2", Err(PointerError::InvalidEscape("/a~2".into())))]
+    #[case("/a~", Err(PointerError::InvalidEscape("/a~".into())))]
+    fn test_parse(#[case] input: &str, #[case] expected: Result<Vec<&str>, PointerError>) {
+        let pointer = input.parse::<Pointer>();
+        assert_eq!(
+            pointer.clone().map(|p| p.tokens().to_vec()),
+            expected.map(|tokens| tokens.into_iter().map(String::from).collect())
+        );
+        if let Ok(pointer) = pointer {
+            assert_eq!(pointer.to_string(), input);
+        }
+    }
+
+    #[rstest::rstest]
+    #[case("/a~1b/~0c/1", Some(Node::Number(3.into())))]
+    #[case("/a~1b/~0c/2", None)]
+    #[case("/a~1b/~0c/01", None)]
+    #[case("/a~1b/~0c/-", None)]
+    #[case("/a~1b/~0c/0/y", None)]
+    #[case("/e/0", None)]
+    #[case("/e", Some(Node::String("f".into())))]
+    fn test_pointer(#[case] pointer: &str, #[case] expected: Option<Node>) {
+        let mut node: Node = Node::Object(Map::from([
+            (
+                "a/b".to_string(),
+                Node::Object(Map::from([(
+                    "~c".to_string(),
+                    Node::Array(vec![Node::Number(2.into()), Node::Number(3.into())]),
+                )])),
+            ),
+            ("e".to_string(), Node::String("f".into())),
+        ]));
+        assert_eq!(node.pointer(pointer), expected.as_ref());
+
+        let pointer = pointer.parse::<Pointer>().unwrap();
+        assert_eq!(pointer.get_mut(&mut node).cloned(), expected);
+    }
+
+    fn config() -> Node {
+        Node::Object(Map::from([(
+            "servers".to_string(),
+            Node::Array(vec![Node::Object(Map::from([(
+                "port".to_string(),
+                Node::Number(80.into()),
+            )]))]),
+        )]))
+    }
+
+    #[rstest::rstest]
+    #[case("/servers/0/port", Ok(Some(Node::Number(80.into()))))]
+    #[case("/servers/0/host", Ok(None))]
+    #[case("/servers/-/port", Ok(None))]
+    #[case("/servers/1/port", Ok(None))]
+    #[case("/log/level", Ok(None))]
+    #[case(
+        "/servers/2",
+        Err(PointerError::NotFound { pointer: "/servers/2".into(), token: "2".into() })
+    )]
+    #[case(
+        "/servers/x",
+        Err(PointerError::NotFound { pointer: "/servers/x".into(), token: "x".into() })
+    )]
+    #[case(
+        "/servers/0/port/x",
+        Err(PointerError::TypeMismatch {
+            pointer: "/servers/0/port/x".into(),
+            token: "x".into(),
+            found: Kind::Number,
+        })
+    )]
+    fn test_set_pointer(
+        #[case] pointer: &str,
+        #[case] expected: Result<Option<Node>, PointerError>,
+    ) {
+        let mut node = config();
+        let value = Node::String("new".into());
+        assert_eq!(node.set_pointer(pointer, value.clone()), expected);
+        if expected.is_ok() {
+            let pointer = pointer.replace('-', "1");
+            assert_eq!(node.pointer(&pointer), Some(&value));
+        }
+    }
+
+    #[rstest::rstest]
+    #[case("/servers/0/port", Some(Node::Number(80.into())))]
+    #[case("/servers/0", Some(Node::Object(Map::from([("port".to_string(), Node::Number(80.into()))]))))]
+    #[case("/servers/1", None)]
+    #[case("/servers/-", None)]
+    #[case("/servers/0/port/x", None)]
+    #[case("", None)]
+    fn test_remove_pointer(#[case] pointer: &str, #[case] expected: Option<Node>) {
+        let mut node = config();
+        assert_eq!(node.remove_pointer(pointer), expected);
+        if expected.is_some() {
+            assert_eq!(node.pointer(pointer), None);
+        }
+    }
+}
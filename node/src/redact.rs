@@ -0,0 +1,216 @@
+use crate::{MapKind, Node, ObjectMap, Pointer, PointerError};
+
+/// Node::redact で一致した値の扱いを表す
+#[derive(std::fmt::Debug, Clone, PartialEq, Eq)]
+pub enum RedactAction {
+    /// 値を文字列に置き換える
+    Mask(String),
+    /// キーと値（Array の場合は要素）を取り除く
+    Remove,
+}
+
+impl Default for RedactAction {
+    fn default() -> Self {
+        RedactAction::Mask("***".into())
+    }
+}
+
+/// Node::redact で隠す値を表現する
+/// `/` で始まるパターンは JSON Pointer として値全体からの位置に一致し、`*` の参照トークンは任意の１段に一致する
+/// それ以外のパターンは全ての深さの Object のキーに一致する
+///
+/// # Examples
+///
+/// ```
+/// let redactor = node::Redactor::new(["password", "/credentials/*"]).unwrap();
+/// let mut node: node::Node = node::json!({
+///     "user": { "name": "x", "password": "secret" },
+///     "credentials": { "token": "t", "key": "k" },
+/// });
+/// node.redact(&redactor);
+/// assert_eq!(node, node::json!({
+///     "user": { "name": "x", "password": "***" },
+///     "credentials": { "token": "***", "key": "***" },
+/// }));
+/// ```
+#[derive(std::fmt::Debug, Clone, PartialEq, Eq)]
+pub struct Redactor {
+    keys: Vec<String>,
+    pointers: Vec<Pointer>,
+    action: RedactAction,
+}
+
+impl Redactor {
+    /// パターンから生成して返却する
+    /// 一致した値は `***` に置き換える
+    /// `/` で始まるパターンが JSON Pointer として解釈できない場合は PointerError を返却する
+    pub fn new<S: AsRef<str>>(patterns: impl IntoIterator<Item = S>) -> Result<Self, PointerError> {
+        let mut keys = Vec::new();
+        let mut pointers = Vec::new();
+        for pattern in patterns {
+            let pattern = pattern.as_ref();
+            if pattern.starts_with('/') {
+                pointers.push(pattern.parse()?);
+            } else {
+                keys.push(pattern.to_string());
+            }
+        }
+
+        Ok(Self {
+            keys,
+            pointers,
+            action: RedactAction::default(),
+        })
+    }
+
+    /// 一致した値の扱いを変更して返却する
+    pub fn action(mut self, action: RedactAction) -> Self {
+        self.action = action;
+        self
+    }
+
+    /// path の値を隠すか
+    /// key は path の値が Object のキーに対応する場合のみ Some とする
+    fn matches(&self, path: &Pointer, key: Option<&str>) -> bool {
+        key.is_some_and(|key| self.keys.iter().any(|k| k == key))
+            || self.pointers.iter().any(|pointer| {
+                pointer.tokens().len() == path.tokens().len()
+                    && pointer
+                        .tokens()
+                        .iter()
+                        .zip(path.tokens())
+                        .all(|(pattern, token)| pattern == "*" || pattern == token)
+            })
+    }
+}
+
+impl<K: MapKind> Node<K> {
+    /// Redactor のパターンに一致する値を再帰的に隠す
+    /// 隠した値の中は辿らない
+    pub fn redact(&mut self, redactor: &Redactor) {
+        self.redact_with(&mut Pointer::root(), redactor);
+    }
+
+    fn redact_with(&mut self, path: &mut Pointer, redactor: &Redactor) {
+        match self {
+            Node::Object(object) => {
+                let mut removed = Vec::new();
+                for (key, value) in object.iter_mut() {
+                    path.push(key);
+                    if redactor.matches(path, Some(key)) {
+                        match &redactor.action {
+                            RedactAction::Mask(mask) => *value = Node::String(mask.clone()),
+                            RedactAction::Remove => removed.push(key.to_string()),
+                        }
+                    } else {
+                        value.redact_with(path, redactor);
+                    }
+                    path.pop();
+                }
+                for key in removed {
+                    object.remove(&key);
+                }
+            }
+            Node::Array(array) => {
+                let mut removed = Vec::new();
+                for (index, value) in array.iter_mut().enumerate() {
+                    path.push(index.to_string());
+                    if redactor.matches(path, None) {
+                        match &redactor.action {
+                            RedactAction::Mask(mask) => *value = Node::String(mask.clone()),
+                            RedactAction::Remove => removed.push(index),
+                        }
+                    } else {
+                        value.redact_with(path, redactor);
+                    }
+                    path.pop();
+                }
+                for index in removed.into_iter().rev() {
+                    array.remove(index);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+
+    fn node() -> Node {
+        json!({
+            "password": "p",
+            "users": [
+                { "name": "a", "password": { "hash": "h" } },
+                { "name": "b", "tokens": ["t1", "t2"] },
+            ],
+            "0": "zero",
+        })
+    }
+
+    #[rstest::rstest]
+    #[case(
+        vec!["password"],
+        RedactAction::default(),
+        json!({
+            "password": "***",
+            "users": [
+                { "name": "a", "password": "***" },
+                { "name": "b", "tokens": ["t1", "t2"] },
+            ],
+            "0": "zero",
+        })
+    )]
+    #[case(
+        vec!["/users/*/name", "/users/1/tokens/0", "0"],
+        RedactAction::Mask("[hidden]".into()),
+        json!({
+            "password": "p",
+            "users": [
+                { "name": "[hidden]", "password": { "hash": "h" } },
+                { "name": "[hidden]", "tokens": ["[hidden]", "t2"] },
+            ],
+            "0": "[hidden]",
+        })
+    )]
+    #[case(
+        vec!["hash", "/users/1/tokens/*", "/password"],
+        RedactAction::Remove,
+        json!({
+            "users": [
+                { "name": "a", "password": {} },
+                { "name": "b", "tokens": [] },
+            ],
+            "0": "zero",
+        })
+    )]
+    #[case(
+        vec!["/users/0"],
+        RedactAction::Remove,
+        json!({
+            "password": "p",
+            "users": [{ "name": "b", "tokens": ["t1", "t2"] }],
+            "0": "zero",
+        })
+    )]
+    fn test_redact(
+        #[case] patterns: Vec<&str>,
+        #[case] action: RedactAction,
+        #[case] expected: Node,
+    ) {
+        let redactor = Redactor::new(patterns).unwrap().action(action);
+        let mut node = node();
+        node.redact(&redactor);
+        assert_eq!(node, expected);
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        assert_eq!(
+            Redactor::new(["/a~2"]),
+            Err(PointerError::InvalidEscape("/a~2".into()))
+        );
+    }
+}
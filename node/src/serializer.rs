@@ -0,0 +1,963 @@
+use std::io::Write;
+
+use crate::{MapKind, Node, Number, ObjectMap};
+
+/// 端末に表示するための色付きの出力
+#[cfg(feature = "color")]
+mod color;
+
+/// Node を構築せずに Rust の値を書き込む処理
+mod write_json;
+
+#[cfg(feature = "color")]
+pub use color::{ColoredFormatter, Theme, to_string_colored};
+pub use write_json::{WriteJson, write_json};
+
+/// 空白を含まない JSONの UTF-8 のバイト列に変換する
+/// HTTP の本文など、文字列を経由せずにバイト列を扱う場合に利用する
+///
+/// # Examples
+///
+/// ```
+/// let node: node::Node = node::json!({ "a": "あ" });
+/// assert_eq!(node::serializer::to_vec(&node), r#"{"a":"あ"}"#.as_bytes());
+/// ```
+pub fn to_vec<K: MapKind>(node: &Node<K>) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(128);
+    to_writer(&mut bytes, node).expect("Vec への書き込みは失敗しない");
+    bytes
+}
+
+/// 空白を含まない JSONの文字列に変換する
+///
+/// # Examples
+///
+/// ```
+/// let node: node::Node = node::json!({ "a": [1, 2.5, "x\n"], "b": null });
+/// assert_eq!(node::serializer::to_string(&node), r#"{"a":[1,2.5,"x\n"],"b":null}"#);
+/// ```
+pub fn to_string<K: MapKind>(node: &Node<K>) -> String {
+    String::from_utf8(to_vec(node)).expect("Serializer は UTF-8 のみを出力する")
+}
+
+/// ToNode を実装した Rust の値を空白を含まない JSONの文字列に変換する
+///
+/// # Examples
+///
+/// ```
+/// let value = (1, vec![Some("a"), None]);
+/// assert_eq!(node::serializer::to_string_from(&value), r#"[1,["a",null]]"#);
+/// ```
+pub fn to_string_from<T: crate::ToNode + ?Sized>(value: &T) -> String {
+    to_string(&value.to_node())
+}
+
+/// 空白を含まない JSONを writer に順に書き込む
+/// 全体の文字列を構築しないため、巨大な値もファイルなどに直接書き込める
+///
+/// # Examples
+///
+/// ```
+/// let node: node::Node = node::json!({ "a": [1, true] });
+/// let mut bytes = Vec::new();
+/// node::serializer::to_writer(&mut bytes, &node).unwrap();
+/// assert_eq!(bytes, br#"{"a":[1,true]}"#);
+/// ```
+pub fn to_writer<K: MapKind>(writer: &mut impl Write, node: &Node<K>) -> Result<(), Error> {
+    Serializer::new(writer).serialize(node)
+}
+
+/// ２文字の空白で字下げした JSONを writer に順に書き込む
+///
+/// # Examples
+///
+/// ```
+/// let node: node::Node = node::json!({ "a": [1, true], "b": {} });
+/// let mut bytes = Vec::new();
+/// node::serializer::to_writer_pretty(&mut bytes, &node).unwrap();
+/// assert_eq!(
+///     String::from_utf8(bytes).unwrap(),
+///     "{\n  \"a\": [\n    1,\n    true\n  ],\n  \"b\": {}\n}"
+/// );
+/// ```
+pub fn to_writer_pretty<K: MapKind>(writer: &mut impl Write, node: &Node<K>) -> Result<(), Error> {
+    Serializer::pretty(writer).serialize(node)
+}
+
+/// RFC 8785 の JSON Canonicalization Scheme に従った文字列に変換する
+/// キーを UTF-16 のコード単位の順に並べ、数値は倍精度浮動小数点数として ECMAScript と同じ表記で出力するため、
+/// 同じ内容の値は常に同じ文字列となり、ハッシュ値の計算や署名に利用できる
+/// NaN・無限大の数値は Error::NonFiniteNumber を返却する
+///
+/// # Examples
+///
+/// ```
+/// let node: node::Node = node::json!({ "b": [1.0, 1e21, 0.000001], "a": "\u{20ac}" });
+/// assert_eq!(
+///     node::serializer::to_canonical_string(&node).unwrap(),
+///     r#"{"a":"€","b":[1,1e+21,0.000001]}"#
+/// );
+/// ```
+pub fn to_canonical_string<K: MapKind>(node: &Node<K>) -> Result<String, Error> {
+    let mut bytes = Vec::with_capacity(128);
+    write_canonical(&mut bytes, node)?;
+    Ok(String::from_utf8(bytes).expect("Serializer は UTF-8 のみを出力する"))
+}
+
+fn write_canonical<K: MapKind>(writer: &mut Vec<u8>, node: &Node<K>) -> Result<(), Error> {
+    let options = SerializerOptions::default();
+    match node {
+        Node::String(s) => write_escaped_string(writer, s, &options)?,
+        Node::Number(n) => {
+            let value = n.as_f64();
+            if !value.is_finite() {
+                return Err(Error::NonFiniteNumber(n.clone()));
+            }
+            writer.extend_from_slice(ecmascript_number(value).as_bytes());
+        }
+        Node::Bool(b) => CompactFormatter.write_bool(writer, *b)?,
+        Node::Null => CompactFormatter.write_null(writer)?,
+        Node::Array(array) => {
+            writer.push(b'[');
+            for (i, element) in array.iter().enumerate() {
+                if i > 0 {
+                    writer.push(b',');
+                }
+                write_canonical(writer, element)?;
+            }
+            writer.push(b']');
+        }
+        Node::Object(object) => {
+            let mut entries = object.iter().collect::<Vec<_>>();
+            entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+            writer.push(b'{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    writer.push(b',');
+                }
+                write_escaped_string(writer, key, &options)?;
+                writer.push(b':');
+                write_canonical(writer, value)?;
+            }
+            writer.push(b'}');
+        }
+    }
+    Ok(())
+}
+
+/// 有限の数値を ECMAScript の Number.prototype.toString と同じ表記に変換する
+fn ecmascript_number(value: f64) -> String {
+    if value == 0.0 {
+        return "0".into();
+    }
+    if value < 0.0 {
+        return format!("-{}", ecmascript_number(-value));
+    }
+
+    // `{:e}` は最短の桁を `d.ddde-n` の形式で出力する
+    // 値は 0.digits × 10^n と表せる
+    let scientific = format!("{value:e}");
+    let (mantissa, exponent) = scientific.split_once('e').expect("`{:e}` は指数を含む");
+    let digits = mantissa.replace('.', "");
+    let k = digits.len() as i32;
+    let n = exponent.parse::<i32>().expect("指数は整数") + 1;
+
+    if k <= n && n <= 21 {
+        format!("{digits}{}", "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        format!("{}.{}", &digits[..n as usize], &digits[n as usize..])
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{digits}", "0".repeat(-n as usize))
+    } else {
+        let sign = if n > 0 { "+" } else { "-" };
+        let exponent = (n - 1).abs();
+        match digits.split_at(1) {
+            (first, "") => format!("{first}e{sign}{exponent}"),
+            (first, rest) => format!("{first}.{rest}e{sign}{exponent}"),
+        }
+    }
+}
+
+/// 出力時のエラーを表現する
+#[derive(thiserror::Error, std::fmt::Debug)]
+pub enum Error {
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    /// NonFiniteNumbers::Error の設定で NaN・無限大を出力しようとした
+    #[error("{0} は JSONの数値として出力できません")]
+    NonFiniteNumber(Number),
+}
+
+/// 空白を含まない JSONの表記を出力する
+/// JSONで表現できない NaN・無限大の数値は `null` として出力する
+impl<K: MapKind> std::fmt::Display for Node<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        to_writer(&mut FmtWriter(f), self).map_err(|_| std::fmt::Error)
+    }
+}
+
+/// std::fmt::Formatter を std::io::Write として扱う
+/// Formatter は UTF-8 として完結した単位で書き込むため、受け取ったバイト列は常に文字列として解釈できる
+struct FmtWriter<'a, 'b>(&'a mut std::fmt::Formatter<'b>);
+
+impl Write for FmtWriter<'_, '_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let s = std::str::from_utf8(buf).map_err(std::io::Error::other)?;
+        self.0.write_str(s).map_err(std::io::Error::other)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Serializer が値・区切りを書き込む方法を決める
+/// 既定の実装は空白を含まない JSONを出力するため、変更したい部分のみ実装すれば良い
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+///
+/// /// Array の要素の後にも `,` を置く
+/// struct TrailingComma;
+///
+/// impl node::serializer::Formatter for TrailingComma {
+///     fn begin_array_value<W: ?Sized + Write>(&mut self, _: &mut W, _: bool) -> std::io::Result<()> {
+///         Ok(())
+///     }
+///
+///     fn end_array_value<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+///         writer.write_all(b",")
+///     }
+/// }
+///
+/// let node: node::Node = node::json!([1, [2]]);
+/// let mut bytes = Vec::new();
+/// node::serializer::Serializer::with_formatter(&mut bytes, TrailingComma)
+///     .serialize(&node)
+///     .unwrap();
+/// assert_eq!(bytes, b"[1,[2,],]");
+/// ```
+pub trait Formatter {
+    fn write_null<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(b"null")
+    }
+
+    fn write_bool<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        value: bool,
+    ) -> std::io::Result<()> {
+        writer.write_all(if value { b"true" } else { b"false" })
+    }
+
+    /// NaN・無限大を除く数値を書き込む
+    fn write_number<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        value: &Number,
+    ) -> std::io::Result<()> {
+        write!(writer, "{value}")
+    }
+
+    /// NonFiniteNumbers::Literal の設定で NaN・無限大を書き込む
+    /// 既定の実装は JSON5 の `NaN`・`Infinity`・`-Infinity` を書き込む
+    fn write_non_finite<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        value: f64,
+    ) -> std::io::Result<()> {
+        writer.write_all(match value {
+            f64::INFINITY => b"Infinity",
+            f64::NEG_INFINITY => b"-Infinity",
+            _ => b"NaN",
+        })
+    }
+
+    /// RawValue など、検証済みの JSONの表記をそのまま書き込む
+    fn write_raw_fragment<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        fragment: &str,
+    ) -> std::io::Result<()> {
+        writer.write_all(fragment.as_bytes())
+    }
+
+    /// 文字列の値と Object のキーを書き込む
+    fn write_string<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        value: &str,
+        options: &SerializerOptions,
+    ) -> std::io::Result<()> {
+        write_escaped_string(writer, value, options)
+    }
+
+    fn begin_array<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(b"[")
+    }
+
+    fn end_array<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(b"]")
+    }
+
+    /// Array の要素の前に呼び出される
+    fn begin_array_value<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> std::io::Result<()> {
+        if first {
+            Ok(())
+        } else {
+            writer.write_all(b",")
+        }
+    }
+
+    /// Array の要素の後に呼び出される
+    fn end_array_value<W: ?Sized + Write>(&mut self, _writer: &mut W) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn begin_object<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(b"{")
+    }
+
+    fn end_object<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(b"}")
+    }
+
+    /// Object のキーの前に呼び出される
+    fn begin_object_key<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> std::io::Result<()> {
+        if first {
+            Ok(())
+        } else {
+            writer.write_all(b",")
+        }
+    }
+
+    /// Object のキーの後、値の前に呼び出される
+    fn begin_object_value<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(b":")
+    }
+
+    /// Object の値の後に呼び出される
+    fn end_object_value<W: ?Sized + Write>(&mut self, _writer: &mut W) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Serializer の出力を設定する
+/// 既定値は UTF-8 の文字をそのまま出力する
+///
+/// # Examples
+///
+/// ```
+/// let node: node::Node = node::json!("Hello, 世界🎉");
+/// let options = node::serializer::SerializerOptions {
+///     ensure_ascii: true,
+///     ..Default::default()
+/// };
+/// let mut serializer = node::serializer::Serializer::new(Vec::new()).options(options);
+/// serializer.serialize(&node).unwrap();
+/// assert_eq!(serializer.into_inner(), br#""Hello, \u4e16\u754c\ud83c\udf89""#);
+/// ```
+#[derive(std::fmt::Debug, Clone, PartialEq, Eq, Default)]
+pub struct SerializerOptions {
+    /// ASCII 以外の文字を `\uXXXX`（基本多言語面の外の文字はサロゲートペア）としてエスケープするか
+    pub ensure_ascii: bool,
+    /// 古い HTML への埋め込みなどのため、`/` を `\/` としてエスケープするか
+    pub escape_slash: bool,
+    /// NaN・無限大の数値の扱い
+    pub non_finite_numbers: NonFiniteNumbers,
+    /// Objectのキーを出力する順序
+    pub key_order: KeyOrder,
+}
+
+/// Objectのキーを出力する順序を表す
+/// Node が使う Map の種類によらず出力の順序を決める
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyOrder {
+    /// Map に格納されている順に出力する
+    /// `preserve_order` フィーチャーが有効な場合は入力の順序を再現できる
+    #[default]
+    Preserve,
+    /// キーの昇順に出力する
+    /// 同じ内容の値は常に同じ文字列となる
+    Sorted,
+}
+
+/// JSONで表現できない NaN・無限大の数値の扱いを表す
+/// Node::Number は Rust の値から変換すると NaN・無限大を持てる
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NonFiniteNumbers {
+    /// `null` として出力する
+    #[default]
+    Null,
+    /// Error::NonFiniteNumber を返却する
+    Error,
+    /// Formatter::write_non_finite で JSON5 のリテラルとして出力する
+    Literal,
+}
+
+/// 空白を含まない JSONを出力する Formatter
+#[derive(std::fmt::Debug, Clone, Copy, Default)]
+pub struct CompactFormatter;
+
+impl Formatter for CompactFormatter {}
+
+/// 要素ごとに改行し、入れ子の深さに応じて字下げする Formatter
+#[derive(std::fmt::Debug, Clone)]
+pub struct PrettyFormatter<'a> {
+    indent: &'a str,
+    depth: usize,
+    /// 現在の Object・Array に要素があるか
+    has_value: bool,
+}
+
+impl<'a> PrettyFormatter<'a> {
+    /// ２文字の空白で字下げする
+    pub fn new() -> Self {
+        Self::with_indent("  ")
+    }
+
+    /// indent を字下げの単位とする
+    pub fn with_indent(indent: &'a str) -> Self {
+        Self {
+            indent,
+            depth: 0,
+            has_value: false,
+        }
+    }
+
+    fn newline<W: ?Sized + Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(b"\n")?;
+        for _ in 0..self.depth {
+            writer.write_all(self.indent.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn begin<W: ?Sized + Write>(&mut self, writer: &mut W, bracket: &[u8]) -> std::io::Result<()> {
+        self.depth += 1;
+        self.has_value = false;
+        writer.write_all(bracket)
+    }
+
+    /// 空の場合は括弧の間に改行を入れない
+    fn end<W: ?Sized + Write>(&mut self, writer: &mut W, bracket: &[u8]) -> std::io::Result<()> {
+        self.depth -= 1;
+        if self.has_value {
+            self.newline(writer)?;
+        }
+        writer.write_all(bracket)
+    }
+
+    fn begin_value<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> std::io::Result<()> {
+        if !first {
+            writer.write_all(b",")?;
+        }
+        self.newline(writer)
+    }
+}
+
+impl Default for PrettyFormatter<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Formatter for PrettyFormatter<'_> {
+    fn begin_array<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.begin(writer, b"[")
+    }
+
+    fn end_array<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.end(writer, b"]")
+    }
+
+    fn begin_array_value<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> std::io::Result<()> {
+        self.begin_value(writer, first)
+    }
+
+    fn end_array_value<W: ?Sized + Write>(&mut self, _writer: &mut W) -> std::io::Result<()> {
+        self.has_value = true;
+        Ok(())
+    }
+
+    fn begin_object<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.begin(writer, b"{")
+    }
+
+    fn end_object<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.end(writer, b"}")
+    }
+
+    fn begin_object_key<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> std::io::Result<()> {
+        self.begin_value(writer, first)
+    }
+
+    fn begin_object_value<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(b": ")
+    }
+
+    fn end_object_value<W: ?Sized + Write>(&mut self, _writer: &mut W) -> std::io::Result<()> {
+        self.has_value = true;
+        Ok(())
+    }
+}
+
+/// Node を辿りながら Formatter に従って writer に書き込む
+pub struct Serializer<W, F = CompactFormatter> {
+    writer: W,
+    formatter: F,
+    options: SerializerOptions,
+}
+
+impl<W: Write> Serializer<W> {
+    /// 空白を含まない JSONを出力する
+    pub fn new(writer: W) -> Self {
+        Self::with_formatter(writer, CompactFormatter)
+    }
+}
+
+impl<'a, W: Write> Serializer<W, PrettyFormatter<'a>> {
+    /// ２文字の空白で字下げした JSONを出力する
+    pub fn pretty(writer: W) -> Self {
+        Self::with_formatter(writer, PrettyFormatter::new())
+    }
+}
+
+impl<W: Write, F: Formatter> Serializer<W, F> {
+    /// formatter に従って出力する
+    pub fn with_formatter(writer: W, formatter: F) -> Self {
+        Self {
+            writer,
+            formatter,
+            options: SerializerOptions::default(),
+        }
+    }
+
+    /// 出力の設定を変更する
+    pub fn options(mut self, options: SerializerOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// writer を返却する
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// node を書き込む
+    pub fn serialize<K: MapKind>(&mut self, node: &Node<K>) -> Result<(), Error> {
+        match node {
+            Node::String(s) => self.serialize_str(s),
+            Node::Number(n) => self.serialize_number(n),
+            Node::Bool(b) => self.serialize_bool(*b),
+            Node::Null => self.serialize_null(),
+            Node::Array(array) => {
+                let mut writer = self.begin_array()?;
+                for element in array {
+                    writer.item(element)?;
+                }
+                writer.end()
+            }
+            Node::Object(object) => self.serialize_entries(object.iter()),
+        }
+    }
+
+    /// `null` を書き込む
+    pub fn serialize_null(&mut self) -> Result<(), Error> {
+        Ok(self.formatter.write_null(&mut self.writer)?)
+    }
+
+    pub fn serialize_bool(&mut self, value: bool) -> Result<(), Error> {
+        Ok(self.formatter.write_bool(&mut self.writer, value)?)
+    }
+
+    /// NaN・無限大は SerializerOptions::non_finite_numbers に従って書き込む
+    pub fn serialize_number(&mut self, value: &Number) -> Result<(), Error> {
+        if is_finite(value) {
+            return Ok(self.formatter.write_number(&mut self.writer, value)?);
+        }
+        match self.options.non_finite_numbers {
+            NonFiniteNumbers::Null => self.serialize_null(),
+            NonFiniteNumbers::Error => Err(Error::NonFiniteNumber(value.clone())),
+            NonFiniteNumbers::Literal => Ok(self
+                .formatter
+                .write_non_finite(&mut self.writer, value.as_f64())?),
+        }
+    }
+
+    pub fn serialize_str(&mut self, value: &str) -> Result<(), Error> {
+        Ok(self
+            .formatter
+            .write_string(&mut self.writer, value, &self.options)?)
+    }
+
+    /// JSONの値として正しい表記をエスケープせずにそのまま書き込む
+    /// 表記は検証しないため、正しくない表記を渡すと出力も JSONとして正しくなくなる
+    pub fn serialize_raw(&mut self, fragment: &str) -> Result<(), Error> {
+        Ok(self
+            .formatter
+            .write_raw_fragment(&mut self.writer, fragment)?)
+    }
+
+    /// Array の書き込みを始める
+    /// 要素は ArrayWriter::item で書き込み、ArrayWriter::end で閉じる
+    pub fn begin_array(&mut self) -> Result<ArrayWriter<'_, W, F>, Error> {
+        self.formatter.begin_array(&mut self.writer)?;
+        Ok(ArrayWriter {
+            serializer: self,
+            first: true,
+        })
+    }
+
+    /// Object の書き込みを始める
+    /// キーと値は ObjectWriter::field で書き込んだ順に出力し、ObjectWriter::end で閉じる
+    pub fn begin_object(&mut self) -> Result<ObjectWriter<'_, W, F>, Error> {
+        self.formatter.begin_object(&mut self.writer)?;
+        Ok(ObjectWriter {
+            serializer: self,
+            first: true,
+        })
+    }
+
+    /// キーと値を SerializerOptions::key_order の順に並べた Object を書き込む
+    fn serialize_entries<'a, T: WriteJson + ?Sized + 'a>(
+        &mut self,
+        entries: impl Iterator<Item = (&'a str, &'a T)>,
+    ) -> Result<(), Error> {
+        let mut writer = self.begin_object()?;
+        match writer.serializer.options.key_order {
+            KeyOrder::Preserve => {
+                for (key, value) in entries {
+                    writer.field(key, value)?;
+                }
+            }
+            KeyOrder::Sorted => {
+                let mut entries = entries.collect::<Vec<_>>();
+                entries.sort_unstable_by_key(|(key, _)| *key);
+                for (key, value) in entries {
+                    writer.field(key, value)?;
+                }
+            }
+        }
+        writer.end()
+    }
+}
+
+/// Serializer::begin_array が返却する、Array の要素を順に書き込む値
+pub struct ArrayWriter<'a, W, F> {
+    serializer: &'a mut Serializer<W, F>,
+    first: bool,
+}
+
+impl<W: Write, F: Formatter> ArrayWriter<'_, W, F> {
+    /// 要素を書き込む
+    pub fn item<T: WriteJson + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let serializer = &mut *self.serializer;
+        serializer
+            .formatter
+            .begin_array_value(&mut serializer.writer, std::mem::take(&mut self.first))?;
+        value.write_json(serializer)?;
+        Ok(serializer
+            .formatter
+            .end_array_value(&mut serializer.writer)?)
+    }
+
+    /// Array を閉じる
+    pub fn end(self) -> Result<(), Error> {
+        Ok(self
+            .serializer
+            .formatter
+            .end_array(&mut self.serializer.writer)?)
+    }
+}
+
+/// Serializer::begin_object が返却する、Object のキーと値を順に書き込む値
+pub struct ObjectWriter<'a, W, F> {
+    serializer: &'a mut Serializer<W, F>,
+    first: bool,
+}
+
+impl<W: Write, F: Formatter> ObjectWriter<'_, W, F> {
+    /// キーと値を書き込む
+    pub fn field<T: WriteJson + ?Sized>(&mut self, key: &str, value: &T) -> Result<(), Error> {
+        let serializer = &mut *self.serializer;
+        serializer
+            .formatter
+            .begin_object_key(&mut serializer.writer, std::mem::take(&mut self.first))?;
+        serializer.serialize_str(key)?;
+        serializer
+            .formatter
+            .begin_object_value(&mut serializer.writer)?;
+        value.write_json(serializer)?;
+        Ok(serializer
+            .formatter
+            .end_object_value(&mut serializer.writer)?)
+    }
+
+    /// Object を閉じる
+    pub fn end(self) -> Result<(), Error> {
+        Ok(self
+            .serializer
+            .formatter
+            .end_object(&mut self.serializer.writer)?)
+    }
+}
+
+/// `"` で囲み、`"`・`\`・制御文字と options で指定した文字をエスケープした文字列を書き込む
+/// Formatter::write_string の既定の実装
+pub fn write_escaped_string<W: ?Sized + Write>(
+    writer: &mut W,
+    s: &str,
+    options: &SerializerOptions,
+) -> std::io::Result<()> {
+    writer.write_all(b"\"")?;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        let escaped = match c {
+            '"' => "\\\"",
+            '\\' => "\\\\",
+            '/' if options.escape_slash => "\\/",
+            '\u{08}' => "\\b",
+            '\u{0C}' => "\\f",
+            '\n' => "\\n",
+            '\r' => "\\r",
+            '\t' => "\\t",
+            c if c < '\u{20}' || (options.ensure_ascii && !c.is_ascii()) => "",
+            _ => continue,
+        };
+        writer.write_all(&s.as_bytes()[start..i])?;
+        if escaped.is_empty() {
+            for unit in c.encode_utf16(&mut [0; 2]) {
+                write!(writer, "\\u{unit:04x}")?;
+            }
+        } else {
+            writer.write_all(escaped.as_bytes())?;
+        }
+        start = i + c.len_utf8();
+    }
+    writer.write_all(&s.as_bytes()[start..])?;
+    writer.write_all(b"\"")
+}
+
+/// JSONの数値として出力できるかを判定する
+fn is_finite(n: &Number) -> bool {
+    #[cfg(not(feature = "arbitrary_precision"))]
+    return !n.is_f64() || n.as_f64().is_finite();
+
+    // JSONの数値の表記は必ず数字で終わる
+    #[cfg(feature = "arbitrary_precision")]
+    return n.as_str().ends_with(|c: char| c.is_ascii_digit());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+
+    #[rstest::rstest]
+    #[case(json!(null), "null")]
+    #[case(json!(true), "true")]
+    #[case(json!(-12), "-12")]
+    #[case(json!(18446744073709551615u64), "18446744073709551615")]
+    #[case(json!(0.1), "0.1")]
+    #[case(json!(1.0), "1.0")]
+    #[case(json!(f64::NAN), "null")]
+    #[case(json!(f64::NEG_INFINITY), "null")]
+    #[case(json!("a\"b\\c/d"), r#""a\"b\\c/d""#)]
+    #[case(json!("\u{08}\u{0C}\n\r\t\u{01}\u{1F}\u{7F}"), "\"\\b\\f\\n\\r\\t\\u0001\\u001f\u{7F}\"")]
+    #[case(json!("日本語🎉"), "\"日本語🎉\"")]
+    #[case(json!([]), "[]")]
+    #[case(json!({}), "{}")]
+    #[case(json!([1, [2, {}], "3"]), r#"[1,[2,{}],"3"]"#)]
+    #[case(json!({ "a\n": { "b": [null] } }), r#"{"a\n":{"b":[null]}}"#)]
+    fn test_to_string(#[case] node: Node, #[case] expected: &str) {
+        assert_eq!(to_string(&node), expected);
+    }
+
+    #[test]
+    fn test_to_vec() {
+        let node: Node = json!(["é", 1]);
+        assert_eq!(to_vec(&node), "[\"é\",1]".as_bytes());
+        assert_eq!(to_vec(&node), node.to_string().into_bytes());
+    }
+
+    #[test]
+    fn test_sorted_map() {
+        let node: Node = json!({ "b": 1, "a": 2 });
+        let node = node.into_map_kind::<crate::map::Sorted>();
+        assert_eq!(node.to_string(), r#"{"a":2,"b":1}"#);
+    }
+
+    #[rstest::rstest]
+    #[case(json!(1), "1")]
+    #[case(json!([]), "[]")]
+    #[case(json!([{}]), "[\n  {}\n]")]
+    #[case(json!([1, [2]]), "[\n  1,\n  [\n    2\n  ]\n]")]
+    #[case(json!({ "a": { "b": null } }), "{\n  \"a\": {\n    \"b\": null\n  }\n}")]
+    fn test_to_writer_pretty(#[case] node: Node, #[case] expected: &str) {
+        let mut bytes = Vec::new();
+        to_writer_pretty(&mut bytes, &node).unwrap();
+        assert_eq!(String::from_utf8(bytes).unwrap(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case("abc\u{7F}", "\"abc\u{7F}\"")]
+    #[case("é\n", r#""\u00e9\n""#)]
+    #[case("\u{FFFF}", r#""\uffff""#)]
+    #[case("\u{10000}\u{10FFFF}", r#""\ud800\udc00\udbff\udfff""#)]
+    fn test_ensure_ascii(#[case] value: &str, #[case] expected: &str) {
+        let options = SerializerOptions {
+            ensure_ascii: true,
+            ..Default::default()
+        };
+        let mut serializer = Serializer::new(Vec::new()).options(options);
+        let node: Node = json!({ value: value });
+        serializer.serialize(&node).unwrap();
+        assert_eq!(
+            String::from_utf8(serializer.into_inner()).unwrap(),
+            format!("{{{expected}:{expected}}}")
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(false, r#"{"a/b":"</script>"}"#)]
+    #[case(true, r#"{"a\/b":"<\/script>"}"#)]
+    fn test_escape_slash(#[case] escape_slash: bool, #[case] expected: &str) {
+        let node: Node = json!({ "a/b": "</script>" });
+        let options = SerializerOptions {
+            escape_slash,
+            ..Default::default()
+        };
+        let mut serializer = Serializer::new(Vec::new()).options(options);
+        serializer.serialize(&node).unwrap();
+        assert_eq!(serializer.into_inner(), expected.as_bytes());
+    }
+
+    #[rstest::rstest]
+    #[case(NonFiniteNumbers::Null, Some("[null,null,null]"))]
+    #[case(NonFiniteNumbers::Literal, Some("[NaN,Infinity,-Infinity]"))]
+    #[case(NonFiniteNumbers::Error, None)]
+    fn test_non_finite_numbers(
+        #[case] non_finite_numbers: NonFiniteNumbers,
+        #[case] expected: Option<&str>,
+    ) {
+        let node: Node = json!([f64::NAN, f64::INFINITY, f64::NEG_INFINITY]);
+        let options = SerializerOptions {
+            non_finite_numbers,
+            ..Default::default()
+        };
+        let mut serializer = Serializer::new(Vec::new()).options(options);
+        match (serializer.serialize(&node), expected) {
+            (Ok(()), Some(expected)) => assert_eq!(serializer.into_inner(), expected.as_bytes()),
+            (Err(Error::NonFiniteNumber(n)), None) => assert!(n.as_f64().is_nan()),
+            (result, _) => panic!("{result:?}"),
+        }
+    }
+
+    #[rstest::rstest]
+    #[case(0.0, "0")]
+    #[case(-0.0, "0")]
+    #[case(4.50, "4.5")]
+    #[case(-1.0, "-1")]
+    #[case(0.002, "0.002")]
+    #[case(0.000001, "0.000001")]
+    #[case(1e-7, "1e-7")]
+    #[case(123e-10, "1.23e-8")]
+    #[case(1e20, "100000000000000000000")]
+    #[case(1e21, "1e+21")]
+    #[case(1e30, "1e+30")]
+    #[case(333_333_333.333_333_3, "333333333.3333333")]
+    #[case(9007199254740993u64 as f64, "9007199254740992")]
+    #[case(5e-324, "5e-324")]
+    #[case(f64::MAX, "1.7976931348623157e+308")]
+    #[case(-1.5e-300, "-1.5e-300")]
+    fn test_ecmascript_number(#[case] value: f64, #[case] expected: &str) {
+        assert_eq!(ecmascript_number(value), expected);
+    }
+
+    #[test]
+    fn test_to_canonical_string() {
+        let node: Node = json!({
+            "\u{20ac}": "Euro Sign",
+            "\r": "Carriage Return",
+            "\u{fb33}": "Hebrew Letter Dalet With Dagesh",
+            "1": "One",
+            "\u{1f600}": "Emoji: Grinning Face",
+            "\u{80}": "Control",
+            "\u{f6}": ["Latin Small Letter O With Diaeresis", 9007199254740993u64, null, true]
+        });
+        assert_eq!(
+            to_canonical_string(&node).unwrap(),
+            "{\"\\r\":\"Carriage Return\",\"1\":\"One\",\"\u{80}\":\"Control\",\
+             \"\u{f6}\":[\"Latin Small Letter O With Diaeresis\",9007199254740992,null,true],\
+             \"\u{20ac}\":\"Euro Sign\",\"\u{1f600}\":\"Emoji: Grinning Face\",\
+             \"\u{fb33}\":\"Hebrew Letter Dalet With Dagesh\"}"
+        );
+        let node: Node = json!([f64::NAN]);
+        assert!(matches!(
+            to_canonical_string(&node),
+            Err(Error::NonFiniteNumber(_))
+        ));
+    }
+
+    fn serialize_with_key_order<K: MapKind>(node: &Node<K>, key_order: KeyOrder) -> String {
+        let options = SerializerOptions {
+            key_order,
+            ..Default::default()
+        };
+        let mut serializer = Serializer::new(Vec::new()).options(options);
+        serializer.serialize(node).unwrap();
+        String::from_utf8(serializer.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn test_key_order_sorted() {
+        let node: Node = json!({ "b": { "d": 1, "c": 2 }, "a": [{ "f": 3, "e": 4 }] });
+        let node = node.into_map_kind::<crate::map::Hashed>();
+        assert_eq!(
+            serialize_with_key_order(&node, KeyOrder::Sorted),
+            r#"{"a":[{"e":4,"f":3}],"b":{"c":2,"d":1}}"#
+        );
+    }
+
+    #[cfg(feature = "preserve_order")]
+    #[rstest::rstest]
+    #[case(KeyOrder::Preserve, r#"{"b":{"d":1,"c":2},"a":[{"f":3,"e":4}]}"#)]
+    #[case(KeyOrder::Sorted, r#"{"a":[{"e":4,"f":3}],"b":{"c":2,"d":1}}"#)]
+    fn test_key_order(#[case] key_order: KeyOrder, #[case] expected: &str) {
+        let node: Node = json!({ "b": { "d": 1, "c": 2 }, "a": [{ "f": 3, "e": 4 }] });
+        assert_eq!(serialize_with_key_order(&node, key_order), expected);
+    }
+
+    #[test]
+    fn test_pretty_formatter_with_indent() {
+        let node: Node = json!({ "a": [1] });
+        let mut serializer =
+            Serializer::with_formatter(Vec::new(), PrettyFormatter::with_indent("\t"));
+        serializer.serialize(&node).unwrap();
+        assert_eq!(serializer.into_inner(), b"{\n\t\"a\": [\n\t\t1\n\t]\n}");
+    }
+}
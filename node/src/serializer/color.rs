@@ -0,0 +1,236 @@
+use std::io::Write;
+
+use super::{Formatter, PrettyFormatter, Serializer, SerializerOptions};
+use crate::{MapKind, Node, Number};
+
+/// to_string_colored が値の種類ごとに使う色を設定する
+/// 各項目は ANSI エスケープシーケンスの SGR のパラメーター（`1;34` など）で、空文字列の場合は色を付けない
+/// 既定値は jq と同じ配色となる
+#[derive(std::fmt::Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    /// Objectのキー
+    pub key: String,
+    pub string: String,
+    pub number: String,
+    /// `true`・`false`
+    pub boolean: String,
+    pub null: String,
+    /// 括弧・`,`・`:`
+    pub punctuation: String,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            key: "34;1".into(),
+            string: "0;32".into(),
+            number: "0;39".into(),
+            boolean: "0;39".into(),
+            null: "1;30".into(),
+            punctuation: "1;39".into(),
+        }
+    }
+}
+
+/// 端末に表示するため、値の種類ごとに ANSI エスケープシーケンスで色を付けた JSONの文字列に変換する
+/// ２文字の空白で字下げする
+///
+/// # Examples
+///
+/// ```
+/// let node: node::Node = node::json!({ "a": [1, null] });
+/// let theme = node::serializer::Theme {
+///     punctuation: String::new(),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     node::serializer::to_string_colored(&node, &theme),
+///     "{\n  \x1b[34;1m\"a\"\x1b[0m: [\n    \x1b[0;39m1\x1b[0m,\n    \x1b[1;30mnull\x1b[0m\n  ]\n}"
+/// );
+/// ```
+pub fn to_string_colored<K: MapKind>(node: &Node<K>, theme: &Theme) -> String {
+    let formatter = ColoredFormatter::new(PrettyFormatter::new(), theme);
+    let mut serializer = Serializer::with_formatter(Vec::with_capacity(128), formatter);
+    serializer
+        .serialize(node)
+        .expect("Vec への書き込みは失敗しない");
+    String::from_utf8(serializer.into_inner()).expect("Serializer は UTF-8 のみを出力する")
+}
+
+/// 他の Formatter の出力を Theme の色で囲む Formatter
+pub struct ColoredFormatter<'a, F> {
+    inner: F,
+    theme: &'a Theme,
+    /// Objectのキーを書き込む途中か
+    in_key: bool,
+}
+
+impl<'a, F: Formatter> ColoredFormatter<'a, F> {
+    /// inner が書き込む値・区切りに theme の色を付ける
+    pub fn new(inner: F, theme: &'a Theme) -> Self {
+        Self {
+            inner,
+            theme,
+            in_key: false,
+        }
+    }
+}
+
+/// color の色で f が書き込む内容を囲む
+fn colored<W: ?Sized + Write>(
+    writer: &mut W,
+    color: &str,
+    f: impl FnOnce(&mut W) -> std::io::Result<()>,
+) -> std::io::Result<()> {
+    if color.is_empty() {
+        return f(writer);
+    }
+    write!(writer, "\x1b[{color}m")?;
+    f(writer)?;
+    writer.write_all(b"\x1b[0m")
+}
+
+impl<F: Formatter> Formatter for ColoredFormatter<'_, F> {
+    fn write_null<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        colored(writer, &self.theme.null, |w| self.inner.write_null(w))
+    }
+
+    fn write_bool<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        value: bool,
+    ) -> std::io::Result<()> {
+        colored(writer, &self.theme.boolean, |w| {
+            self.inner.write_bool(w, value)
+        })
+    }
+
+    fn write_number<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        value: &Number,
+    ) -> std::io::Result<()> {
+        colored(writer, &self.theme.number, |w| {
+            self.inner.write_number(w, value)
+        })
+    }
+
+    fn write_non_finite<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        value: f64,
+    ) -> std::io::Result<()> {
+        colored(writer, &self.theme.number, |w| {
+            self.inner.write_non_finite(w, value)
+        })
+    }
+
+    fn write_string<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        value: &str,
+        options: &SerializerOptions,
+    ) -> std::io::Result<()> {
+        let color = if self.in_key {
+            &self.theme.key
+        } else {
+            &self.theme.string
+        };
+        colored(writer, color, |w| {
+            self.inner.write_string(w, value, options)
+        })
+    }
+
+    fn begin_array<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        colored(writer, &self.theme.punctuation, |w| {
+            self.inner.begin_array(w)
+        })
+    }
+
+    fn end_array<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        colored(writer, &self.theme.punctuation, |w| self.inner.end_array(w))
+    }
+
+    fn begin_array_value<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> std::io::Result<()> {
+        colored(writer, &self.theme.punctuation, |w| {
+            self.inner.begin_array_value(w, first)
+        })
+    }
+
+    fn end_array_value<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.inner.end_array_value(writer)
+    }
+
+    fn begin_object<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        colored(writer, &self.theme.punctuation, |w| {
+            self.inner.begin_object(w)
+        })
+    }
+
+    fn end_object<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        colored(writer, &self.theme.punctuation, |w| {
+            self.inner.end_object(w)
+        })
+    }
+
+    fn begin_object_key<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> std::io::Result<()> {
+        self.in_key = true;
+        colored(writer, &self.theme.punctuation, |w| {
+            self.inner.begin_object_key(w, first)
+        })
+    }
+
+    fn begin_object_value<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.in_key = false;
+        colored(writer, &self.theme.punctuation, |w| {
+            self.inner.begin_object_value(w)
+        })
+    }
+
+    fn end_object_value<W: ?Sized + Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.inner.end_object_value(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+
+    #[test]
+    fn test_to_string_colored() {
+        let node: Node = json!({ "k": ["v", true, 1.5] });
+        assert_eq!(
+            to_string_colored(&node, &Theme::default()),
+            "\x1b[1;39m{\x1b[0m\x1b[1;39m\n  \x1b[0m\x1b[34;1m\"k\"\x1b[0m\x1b[1;39m: \x1b[0m\
+             \x1b[1;39m[\x1b[0m\x1b[1;39m\n    \x1b[0m\x1b[0;32m\"v\"\x1b[0m\
+             \x1b[1;39m,\n    \x1b[0m\x1b[0;39mtrue\x1b[0m\
+             \x1b[1;39m,\n    \x1b[0m\x1b[0;39m1.5\x1b[0m\
+             \x1b[1;39m\n  ]\x1b[0m\x1b[1;39m\n}\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_empty_theme() {
+        let node: Node = json!({ "k": [null] });
+        let theme = Theme {
+            key: String::new(),
+            string: String::new(),
+            number: String::new(),
+            boolean: String::new(),
+            null: String::new(),
+            punctuation: String::new(),
+        };
+        let mut bytes = Vec::new();
+        super::super::to_writer_pretty(&mut bytes, &node).unwrap();
+        assert_eq!(to_string_colored(&node, &theme).into_bytes(), bytes);
+    }
+}
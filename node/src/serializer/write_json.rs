@@ -0,0 +1,388 @@
+use std::io::Write;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use super::{Error, Formatter, Serializer};
+use crate::{MapKind, Node, Number};
+
+/// Node を構築せずに値を Serializer に書き込む
+/// ToNode と異なり途中の Node を確保しないため、頻繁に出力する型で利用する
+/// `#[derive(macro_deserialize::Serialize)]` は ToNode とあわせてこのトレイトも実装する
+///
+/// # Examples
+///
+/// ```
+/// use node::serializer::{Error, Formatter, Serializer, WriteJson};
+///
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl WriteJson for Point {
+///     fn write_json<W: std::io::Write, F: Formatter>(
+///         &self,
+///         serializer: &mut Serializer<W, F>,
+///     ) -> Result<(), Error> {
+///         let mut object = serializer.begin_object()?;
+///         object.field("x", &self.x)?;
+///         object.field("y", &self.y)?;
+///         object.end()
+///     }
+/// }
+///
+/// let mut bytes = Vec::new();
+/// node::serializer::write_json(&vec![Point { x: 1, y: -2 }], &mut bytes).unwrap();
+/// assert_eq!(bytes, br#"[{"x":1,"y":-2}]"#);
+/// ```
+pub trait WriteJson {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error>;
+}
+
+/// 空白を含まない JSONとして value を writer に書き込む
+pub fn write_json<T: WriteJson + ?Sized>(value: &T, writer: &mut impl Write) -> Result<(), Error> {
+    value.write_json(&mut Serializer::new(writer))
+}
+
+impl<K: MapKind> WriteJson for Node<K> {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error> {
+        serializer.serialize(self)
+    }
+}
+
+impl WriteJson for Number {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error> {
+        serializer.serialize_number(self)
+    }
+}
+
+impl WriteJson for str {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+impl WriteJson for String {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+/// １文字の文字列として書き込む
+impl WriteJson for char {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error> {
+        serializer.serialize_str(self.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl WriteJson for bool {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error> {
+        serializer.serialize_bool(*self)
+    }
+}
+
+/// `null` として書き込む
+impl WriteJson for () {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error> {
+        serializer.serialize_null()
+    }
+}
+
+macro_rules! write_json_number {
+    ($($ty:ty),*) => {
+        $(
+            impl WriteJson for $ty {
+                fn write_json<W: Write, F: Formatter>(
+                    &self,
+                    serializer: &mut Serializer<W, F>,
+                ) -> Result<(), Error> {
+                    serializer.serialize_number(&Number::from(*self))
+                }
+            }
+        )*
+    };
+}
+
+write_json_number!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+macro_rules! write_json_non_zero {
+    ($($ty:ty),*) => {
+        $(
+            impl WriteJson for std::num::NonZero<$ty> {
+                fn write_json<W: Write, F: Formatter>(
+                    &self,
+                    serializer: &mut Serializer<W, F>,
+                ) -> Result<(), Error> {
+                    self.get().write_json(serializer)
+                }
+            }
+        )*
+    };
+}
+
+write_json_non_zero!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+impl<T: WriteJson + ?Sized> WriteJson for &T {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error> {
+        (**self).write_json(serializer)
+    }
+}
+
+macro_rules! write_json_pointer {
+    ($($pointer:ident),*) => {
+        $(
+            impl<T: WriteJson + ?Sized> WriteJson for $pointer<T> {
+                fn write_json<W: Write, F: Formatter>(
+                    &self,
+                    serializer: &mut Serializer<W, F>,
+                ) -> Result<(), Error> {
+                    (**self).write_json(serializer)
+                }
+            }
+        )*
+    };
+}
+
+write_json_pointer!(Box, Rc, Arc);
+
+impl<T: WriteJson + ToOwned + ?Sized> WriteJson for std::borrow::Cow<'_, T> {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error> {
+        (**self).write_json(serializer)
+    }
+}
+
+/// None は `null` として書き込む
+impl<T: WriteJson> WriteJson for Option<T> {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error> {
+        match self {
+            Some(value) => value.write_json(serializer),
+            None => serializer.serialize_null(),
+        }
+    }
+}
+
+impl<T: WriteJson> WriteJson for [T] {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error> {
+        let mut array = serializer.begin_array()?;
+        for item in self {
+            array.item(item)?;
+        }
+        array.end()
+    }
+}
+
+impl<T: WriteJson, const N: usize> WriteJson for [T; N] {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error> {
+        self.as_slice().write_json(serializer)
+    }
+}
+
+impl<T: WriteJson> WriteJson for Vec<T> {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error> {
+        self.as_slice().write_json(serializer)
+    }
+}
+
+/// 要素を順に並べた Array として書き込む
+macro_rules! write_json_tuple {
+    ($(($($name:ident),+)),*) => {
+        $(
+            impl<$($name: WriteJson),+> WriteJson for ($($name,)+) {
+                #[allow(non_snake_case)]
+                fn write_json<W: Write, F: Formatter>(
+                    &self,
+                    serializer: &mut Serializer<W, F>,
+                ) -> Result<(), Error> {
+                    let ($($name,)+) = self;
+                    let mut array = serializer.begin_array()?;
+                    $(array.item($name)?;)+
+                    array.end()
+                }
+            }
+        )*
+    };
+}
+
+write_json_tuple!(
+    (T0),
+    (T0, T1),
+    (T0, T1, T2),
+    (T0, T1, T2, T3),
+    (T0, T1, T2, T3, T4),
+    (T0, T1, T2, T3, T4, T5),
+    (T0, T1, T2, T3, T4, T5, T6),
+    (T0, T1, T2, T3, T4, T5, T6, T7),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)
+);
+
+/// キーは SerializerOptions::key_order の順に並べる
+impl<Q: AsRef<str>, T: WriteJson> WriteJson for std::collections::BTreeMap<Q, T> {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error> {
+        serializer.serialize_entries(self.iter().map(|(key, value)| (key.as_ref(), value)))
+    }
+}
+
+/// キーは SerializerOptions::key_order の順に並べる
+impl<Q: AsRef<str>, T: WriteJson, S> WriteJson for std::collections::HashMap<Q, T, S> {
+    fn write_json<W: Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), Error> {
+        serializer.serialize_entries(self.iter().map(|(key, value)| (key.as_ref(), value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serializer::{KeyOrder, SerializerOptions, to_string};
+    use crate::{FromNode, ToNode};
+
+    fn written<T: WriteJson + ToNode>(value: &T) -> String {
+        let mut bytes = Vec::new();
+        write_json(value, &mut bytes).unwrap();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn test_write_json() {
+        let value = (
+            Some('x'),
+            vec![None, Some(1.5f32)],
+            [(), ()],
+            ("s", -1i64, true),
+            std::collections::BTreeMap::from([("b", 2u8), ("a", 1u8)]),
+            std::num::NonZeroUsize::new(7),
+        );
+        assert_eq!(
+            written(&value),
+            r#"["x",[null,1.5],[null,null],["s",-1,true],{"a":1,"b":2},7]"#
+        );
+        assert_eq!(written(&value), to_string(&value.to_node()));
+    }
+
+    #[test]
+    fn test_tuple_12() {
+        let value = (
+            0u8,
+            1i16,
+            2u32,
+            3i64,
+            4usize,
+            5.5f64,
+            "6",
+            '7',
+            true,
+            (),
+            Some(10),
+            [11],
+        );
+        assert_eq!(
+            written(&value),
+            r#"[0,1,2,3,4,5.5,"6","7",true,null,10,[11]]"#
+        );
+        assert_eq!(written(&value), to_string(&value.to_node()));
+        assert_eq!(
+            <(
+                u8,
+                i16,
+                u32,
+                i64,
+                usize,
+                f64,
+                String,
+                char,
+                bool,
+                (),
+                Option<i32>,
+                [u8; 1]
+            )>::from_node(&value.to_node())
+            .unwrap(),
+            (
+                0,
+                1,
+                2,
+                3,
+                4,
+                5.5,
+                "6".to_string(),
+                '7',
+                true,
+                (),
+                Some(10),
+                [11]
+            )
+        );
+    }
+
+    #[test]
+    fn test_key_order() {
+        let value = std::collections::HashMap::from([("b", 1), ("c", 2), ("a", 3)]);
+        let options = SerializerOptions {
+            key_order: KeyOrder::Sorted,
+            ..Default::default()
+        };
+        let mut serializer = Serializer::new(Vec::new()).options(options);
+        value.write_json(&mut serializer).unwrap();
+        assert_eq!(serializer.into_inner(), br#"{"a":3,"b":1,"c":2}"#);
+    }
+
+    #[test]
+    fn test_non_finite_number() {
+        let mut bytes = Vec::new();
+        write_json(&[f64::NAN], &mut bytes).unwrap();
+        assert_eq!(bytes, b"[null]");
+    }
+}
@@ -0,0 +1,242 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::serializer::{self, Formatter, Serializer, WriteJson};
+use crate::{Error, FromNode, MapKind, Node, ToNode};
+
+/// Duration・SystemTime を数値として読み書きする場合の単位
+/// FromNode・ToNode は TimeUnit::Seconds で変換する
+///
+/// # Examples
+///
+/// ```
+/// use node::{FromNode, TimeUnit};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let node: node::Node = node::json!({ "timeout": "1h30m", "at": 1500 });
+/// assert_eq!(Duration::from_node(&node["timeout"]).unwrap(), Duration::from_secs(5400));
+/// assert_eq!(
+///     TimeUnit::Millis.system_time_from_node(&node["at"]).unwrap(),
+///     UNIX_EPOCH + Duration::from_millis(1500)
+/// );
+/// assert_eq!(TimeUnit::Millis.duration_to_node(&Duration::from_secs(2)), node::json!(2000));
+/// ```
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeUnit {
+    #[default]
+    Seconds,
+    Millis,
+}
+
+impl TimeUnit {
+    /// この単位の数値、または `1h30m` `250ms` のように数値と単位を並べた文字列から変換する
+    /// 文字列の単位には d・h・m・s・ms・us・ns を利用できる
+    pub fn duration_from_node<K: MapKind>(self, node: &Node<K>) -> Result<Duration, Error> {
+        match node {
+            Node::String(s) => parse_duration(s),
+            Node::Number(n) => {
+                let value = match (self, n.as_u64()) {
+                    (TimeUnit::Seconds, Some(secs)) => return Ok(Duration::from_secs(secs)),
+                    (TimeUnit::Millis, Some(millis)) => return Ok(Duration::from_millis(millis)),
+                    (TimeUnit::Seconds, None) => n.as_f64(),
+                    (TimeUnit::Millis, None) => n.as_f64() / 1000.0,
+                };
+                Duration::try_from_secs_f64(value).map_err(|e| {
+                    Error::invalid_value::<Duration>(format!("{n} は範囲外です（{e}）"))
+                })
+            }
+            _ => Err(Error::type_mismatch::<Duration, K>(node)),
+        }
+    }
+
+    /// この単位の数値に変換する
+    /// 単位に満たない端数がある場合は小数とする
+    pub fn duration_to_node(self, duration: &Duration) -> Node {
+        match self {
+            TimeUnit::Seconds if duration.subsec_nanos() == 0 => duration.as_secs().into(),
+            TimeUnit::Seconds => duration.as_secs_f64().into(),
+            TimeUnit::Millis => match u64::try_from(duration.as_millis()) {
+                Ok(millis) if duration.subsec_nanos().is_multiple_of(1_000_000) => millis.into(),
+                _ => (duration.as_secs_f64() * 1000.0).into(),
+            },
+        }
+    }
+
+    /// UNIX エポックからのこの単位の経過時間から変換する
+    /// 負の値はエポックより前の時刻とする
+    pub fn system_time_from_node<K: MapKind>(self, node: &Node<K>) -> Result<SystemTime, Error> {
+        let Node::Number(n) = node else {
+            return Err(Error::type_mismatch::<SystemTime, K>(node));
+        };
+        let before_epoch = n.as_f64() < 0.0;
+        let elapsed = if before_epoch {
+            self.duration_from_node::<K>(&Node::Number((-n.as_f64()).into()))?
+        } else {
+            self.duration_from_node(node)?
+        };
+        let time = if before_epoch {
+            UNIX_EPOCH.checked_sub(elapsed)
+        } else {
+            UNIX_EPOCH.checked_add(elapsed)
+        };
+        time.ok_or_else(|| Error::invalid_value::<SystemTime>(format!("{n} は範囲外です")))
+    }
+
+    /// UNIX エポックからのこの単位の経過時間に変換する
+    pub fn system_time_to_node(self, time: &SystemTime) -> Node {
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(elapsed) => self.duration_to_node(&elapsed),
+            Err(e) => match self.duration_to_node(&e.duration()) {
+                Node::Number(n) => match n.as_u64() {
+                    Some(v) if v <= i64::MAX as u64 => (-(v as i64)).into(),
+                    _ => (-n.as_f64()).into(),
+                },
+                node => node,
+            },
+        }
+    }
+}
+
+impl FromNode for Duration {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        TimeUnit::Seconds.duration_from_node(node)
+    }
+}
+
+impl FromNode for SystemTime {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        TimeUnit::Seconds.system_time_from_node(node)
+    }
+}
+
+impl ToNode for Duration {
+    fn to_node(&self) -> Node {
+        TimeUnit::Seconds.duration_to_node(self)
+    }
+}
+
+impl ToNode for SystemTime {
+    fn to_node(&self) -> Node {
+        TimeUnit::Seconds.system_time_to_node(self)
+    }
+}
+
+/// ToNode と同じく秒数の数値として書き込む
+macro_rules! write_json_time {
+    ($($ty:ty),*) => {
+        $(
+            impl WriteJson for $ty {
+                fn write_json<W: std::io::Write, F: Formatter>(
+                    &self,
+                    serializer: &mut Serializer<W, F>,
+                ) -> Result<(), serializer::Error> {
+                    serializer.serialize(&self.to_node())
+                }
+            }
+        )*
+    };
+}
+
+write_json_time!(Duration, SystemTime);
+
+/// `1h30m` のような数値と単位の並びを合計する
+fn parse_duration(s: &str) -> Result<Duration, Error> {
+    let error = || Error::invalid_value::<Duration>(format!("`{s}` を期間として解釈できません"));
+
+    let mut rest = s;
+    let mut total = Duration::ZERO;
+    if rest.is_empty() {
+        return Err(error());
+    }
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .ok_or_else(error)?;
+        let value: f64 = rest[..digits].parse().map_err(|_| error())?;
+        rest = &rest[digits..];
+        let unit = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        let secs = match &rest[..unit] {
+            "d" => 86400.0,
+            "h" => 3600.0,
+            "m" => 60.0,
+            "s" => 1.0,
+            "ms" => 1e-3,
+            "us" => 1e-6,
+            "ns" => 1e-9,
+            _ => return Err(error()),
+        };
+        rest = &rest[unit..];
+        let part = Duration::try_from_secs_f64(value * secs).map_err(|_| error())?;
+        total = total.checked_add(part).ok_or_else(error)?;
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+
+    #[rstest::rstest]
+    #[case(json!(90), TimeUnit::Seconds, Ok(Duration::from_secs(90)))]
+    #[case(json!(1.5), TimeUnit::Seconds, Ok(Duration::from_millis(1500)))]
+    #[case(json!(250), TimeUnit::Millis, Ok(Duration::from_millis(250)))]
+    #[case(json!(0.5), TimeUnit::Millis, Ok(Duration::from_micros(500)))]
+    #[case(json!("1h30m"), TimeUnit::Millis, Ok(Duration::from_secs(5400)))]
+    #[case(json!("2d"), TimeUnit::Seconds, Ok(Duration::from_secs(172800)))]
+    #[case(json!("1s250ms"), TimeUnit::Seconds, Ok(Duration::from_millis(1250)))]
+    #[case(json!("0.5m"), TimeUnit::Seconds, Ok(Duration::from_secs(30)))]
+    #[case(json!(-1), TimeUnit::Seconds, Err(()))]
+    #[case(json!(""), TimeUnit::Seconds, Err(()))]
+    #[case(json!("10"), TimeUnit::Seconds, Err(()))]
+    #[case(json!("1x"), TimeUnit::Seconds, Err(()))]
+    #[case(json!("h"), TimeUnit::Seconds, Err(()))]
+    #[case(json!("1h 30m"), TimeUnit::Seconds, Err(()))]
+    #[case(json!(true), TimeUnit::Seconds, Err(()))]
+    fn test_duration_from_node(
+        #[case] node: Node,
+        #[case] unit: TimeUnit,
+        #[case] expected: Result<Duration, ()>,
+    ) {
+        assert_eq!(unit.duration_from_node(&node).map_err(|_| ()), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(Duration::from_secs(3), TimeUnit::Seconds, json!(3))]
+    #[case(Duration::from_millis(1500), TimeUnit::Seconds, json!(1.5))]
+    #[case(Duration::from_millis(1500), TimeUnit::Millis, json!(1500))]
+    #[case(Duration::from_micros(500), TimeUnit::Millis, json!(0.5))]
+    fn test_duration_to_node(
+        #[case] duration: Duration,
+        #[case] unit: TimeUnit,
+        #[case] expected: Node,
+    ) {
+        assert_eq!(unit.duration_to_node(&duration), expected);
+        assert_eq!(unit.duration_from_node(&expected).unwrap(), duration);
+    }
+
+    #[rstest::rstest]
+    #[case(json!(1_700_000_000), TimeUnit::Seconds, UNIX_EPOCH + Duration::from_secs(1_700_000_000))]
+    #[case(json!(-60), TimeUnit::Seconds, UNIX_EPOCH - Duration::from_secs(60))]
+    #[case(json!(1500), TimeUnit::Millis, UNIX_EPOCH + Duration::from_millis(1500))]
+    #[case(json!(-1500), TimeUnit::Millis, UNIX_EPOCH - Duration::from_millis(1500))]
+    fn test_system_time(#[case] node: Node, #[case] unit: TimeUnit, #[case] expected: SystemTime) {
+        assert_eq!(unit.system_time_from_node(&node).unwrap(), expected);
+        assert_eq!(unit.system_time_to_node(&expected), node);
+    }
+
+    #[test]
+    fn test_trait() {
+        let duration = Duration::from_millis(2500);
+        assert_eq!(Duration::from_node(&duration.to_node()).unwrap(), duration);
+        let time = UNIX_EPOCH + Duration::from_secs(10);
+        assert_eq!(time.to_node(), json!(10));
+        assert_eq!(SystemTime::from_node(&time.to_node()).unwrap(), time);
+        assert_eq!(crate::serializer::to_string(&duration.to_node()), "2.5");
+        let mut json = Vec::new();
+        crate::serializer::write_json(&(duration, time), &mut json).unwrap();
+        assert_eq!(json, b"[2.5,10]");
+        assert!(SystemTime::from_node(&Node::<crate::map::Standard>::String("10".into())).is_err());
+    }
+}
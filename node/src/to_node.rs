@@ -0,0 +1,213 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::{Node, Number, ToNode};
+
+impl ToNode for Node {
+    fn to_node(&self) -> Node {
+        self.clone()
+    }
+}
+
+impl ToNode for Number {
+    fn to_node(&self) -> Node {
+        Node::Number(self.clone())
+    }
+}
+
+impl ToNode for str {
+    fn to_node(&self) -> Node {
+        Node::String(self.into())
+    }
+}
+
+impl ToNode for String {
+    fn to_node(&self) -> Node {
+        Node::String(self.clone())
+    }
+}
+
+/// １文字の文字列に変換する
+impl ToNode for char {
+    fn to_node(&self) -> Node {
+        Node::String(self.to_string())
+    }
+}
+
+impl ToNode for bool {
+    fn to_node(&self) -> Node {
+        Node::Bool(*self)
+    }
+}
+
+/// Node::Null に変換する
+impl ToNode for () {
+    fn to_node(&self) -> Node {
+        Node::Null
+    }
+}
+
+macro_rules! to_node_number {
+    ($($ty:ty),*) => {
+        $(
+            impl ToNode for $ty {
+                fn to_node(&self) -> Node {
+                    Node::Number((*self).into())
+                }
+            }
+        )*
+    };
+}
+
+to_node_number!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+macro_rules! to_node_non_zero {
+    ($($ty:ty),*) => {
+        $(
+            impl ToNode for std::num::NonZero<$ty> {
+                fn to_node(&self) -> Node {
+                    self.get().to_node()
+                }
+            }
+        )*
+    };
+}
+
+to_node_non_zero!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+impl<T: ToNode + ?Sized> ToNode for &T {
+    fn to_node(&self) -> Node {
+        (**self).to_node()
+    }
+}
+
+macro_rules! to_node_pointer {
+    ($($pointer:ident),*) => {
+        $(
+            impl<T: ToNode + ?Sized> ToNode for $pointer<T> {
+                fn to_node(&self) -> Node {
+                    (**self).to_node()
+                }
+            }
+        )*
+    };
+}
+
+to_node_pointer!(Box, Rc, Arc);
+
+impl<T: ToNode + ToOwned + ?Sized> ToNode for std::borrow::Cow<'_, T> {
+    fn to_node(&self) -> Node {
+        (**self).to_node()
+    }
+}
+
+/// None は Node::Null に変換する
+impl<T: ToNode> ToNode for Option<T> {
+    fn to_node(&self) -> Node {
+        self.as_ref().map_or(Node::Null, ToNode::to_node)
+    }
+}
+
+impl<T: ToNode> ToNode for [T] {
+    fn to_node(&self) -> Node {
+        Node::Array(self.iter().map(ToNode::to_node).collect())
+    }
+}
+
+impl<T: ToNode, const N: usize> ToNode for [T; N] {
+    fn to_node(&self) -> Node {
+        self.as_slice().to_node()
+    }
+}
+
+impl<T: ToNode> ToNode for Vec<T> {
+    fn to_node(&self) -> Node {
+        self.as_slice().to_node()
+    }
+}
+
+/// 要素を順に並べた Array に変換する
+macro_rules! to_node_tuple {
+    ($(($($name:ident),+)),*) => {
+        $(
+            impl<$($name: ToNode),+> ToNode for ($($name,)+) {
+                #[allow(non_snake_case)]
+                fn to_node(&self) -> Node {
+                    let ($($name,)+) = self;
+                    Node::Array(vec![$($name.to_node()),+])
+                }
+            }
+        )*
+    };
+}
+
+to_node_tuple!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H),
+    (A, B, C, D, E, F, G, H, I),
+    (A, B, C, D, E, F, G, H, I, J),
+    (A, B, C, D, E, F, G, H, I, J, L),
+    (A, B, C, D, E, F, G, H, I, J, L, M)
+);
+
+impl<Q: AsRef<str>, T: ToNode> ToNode for std::collections::BTreeMap<Q, T> {
+    fn to_node(&self) -> Node {
+        Node::Object(
+            self.iter()
+                .map(|(key, value)| (key.as_ref().to_string(), value.to_node()))
+                .collect(),
+        )
+    }
+}
+
+impl<Q: AsRef<str>, T: ToNode, S> ToNode for std::collections::HashMap<Q, T, S> {
+    fn to_node(&self) -> Node {
+        Node::Object(
+            self.iter()
+                .map(|(key, value)| (key.as_ref().to_string(), value.to_node()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+
+    #[rstest::rstest]
+    #[case(Box::new(()), json!(null))]
+    #[case(Box::new('x'), json!("x"))]
+    #[case(Box::new("s"), json!("s"))]
+    #[case(Box::new(-3i8), json!(-3))]
+    #[case(Box::new(1.5f32), json!(1.5))]
+    #[case(Box::new(std::num::NonZeroI16::new(-2).unwrap()), json!(-2))]
+    #[case(Box::new(Some(true)), json!(true))]
+    #[case(Box::new(Rc::new(Arc::<str>::from("p"))), json!("p"))]
+    #[case(Box::new(std::borrow::Cow::Borrowed("c")), json!("c"))]
+    #[case(Box::new(None::<u8>), json!(null))]
+    #[case(Box::new(vec![Some(1), None]), json!([1, null]))]
+    #[case(Box::new([[1u64]; 2]), json!([[1], [1]]))]
+    #[case(Box::new((1, "a", (false,))), json!([1, "a", [false]]))]
+    #[case(
+        Box::new(std::collections::BTreeMap::from([("a", vec![1]), ("b", vec![])])),
+        json!({ "a": [1], "b": [] })
+    )]
+    #[case(
+        Box::new(std::collections::HashMap::from([("k".to_string(), json!({ "n": null }))])),
+        json!({ "k": { "n": null } })
+    )]
+    fn test_to_node(#[case] value: Box<dyn ToNode>, #[case] expected: Node) {
+        assert_eq!(value.to_node(), expected);
+    }
+}
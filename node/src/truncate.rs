@@ -0,0 +1,130 @@
+use crate::{MapKind, Node, ObjectMap};
+
+/// Node::truncate で残す量を設定する
+/// None の項目は切り詰めない
+#[derive(std::fmt::Debug, Clone, PartialEq, Eq)]
+pub struct TruncateOptions {
+    /// Array の先頭から残す要素の数
+    pub max_items: Option<usize>,
+    /// Object の先頭から残すキーの数
+    pub max_keys: Option<usize>,
+    /// 文字列（Object のキーを除く）の先頭から残す文字の数
+    pub max_string_chars: Option<usize>,
+    /// 残す Object・Array の深さ（値全体を 0 とする）
+    pub max_depth: Option<usize>,
+    /// 切り詰めた位置に置く印
+    /// Array では要素、Object ではキーと値、文字列では末尾として加え、深さを超えた Object・Array はこの文字列に置き換える
+    pub marker: String,
+}
+
+impl Default for TruncateOptions {
+    fn default() -> Self {
+        Self {
+            max_items: Some(10),
+            max_keys: Some(10),
+            max_string_chars: Some(100),
+            max_depth: None,
+            marker: "…".into(),
+        }
+    }
+}
+
+impl<K: MapKind> Node<K> {
+    /// 設定に従って切り詰めた複製を返却する
+    /// ログや画面に巨大な値の概要を表示する場合に利用する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let node: node::Node = node::json!({ "items": [1, 2, 3], "text": "abcdef" });
+    /// let preview = node.truncate(&node::TruncateOptions {
+    ///     max_items: Some(2),
+    ///     max_string_chars: Some(3),
+    ///     ..Default::default()
+    /// });
+    /// assert_eq!(preview, node::json!({ "items": [1, 2, "…"], "text": "abc…" }));
+    /// ```
+    pub fn truncate(&self, options: &TruncateOptions) -> Node<K> {
+        self.truncate_at(0, options)
+    }
+
+    fn truncate_at(&self, depth: usize, options: &TruncateOptions) -> Node<K> {
+        let limit = |max: Option<usize>| max.unwrap_or(usize::MAX);
+        let deeper = |node: &Node<K>| node.truncate_at(depth + 1, options);
+
+        match self {
+            Node::Array(_) | Node::Object(_) if depth >= limit(options.max_depth) => {
+                Node::String(options.marker.clone())
+            }
+            Node::String(s) => match s.char_indices().nth(limit(options.max_string_chars)) {
+                Some((end, _)) => Node::String(format!("{}{}", &s[..end], options.marker)),
+                None => self.clone(),
+            },
+            Node::Array(array) => {
+                let max = limit(options.max_items);
+                let mut truncated = array.iter().take(max).map(deeper).collect::<Vec<_>>();
+                if array.len() > max {
+                    truncated.push(Node::String(options.marker.clone()));
+                }
+                Node::Array(truncated)
+            }
+            Node::Object(object) => {
+                let max = limit(options.max_keys);
+                let mut truncated = object
+                    .iter()
+                    .take(max)
+                    .map(|(key, value)| (key.to_string(), deeper(value)))
+                    .collect::<K::Map<_>>();
+                if object.len() > max {
+                    truncated.insert(options.marker.clone(), Node::String(options.marker.clone()));
+                }
+                Node::Object(truncated)
+            }
+            _ => self.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+
+    fn unlimited() -> TruncateOptions {
+        TruncateOptions {
+            max_items: None,
+            max_keys: None,
+            max_string_chars: None,
+            max_depth: None,
+            marker: "...".into(),
+        }
+    }
+
+    #[rstest::rstest]
+    #[case(unlimited(), json!({ "a": [1, [2, 3]], "b": "あいう", "c": {} }))]
+    #[case(
+        TruncateOptions { max_items: Some(1), ..unlimited() },
+        json!({ "a": [1, "..."], "b": "あいう", "c": {} })
+    )]
+    #[case(
+        TruncateOptions { max_keys: Some(1), ..unlimited() },
+        json!({ "a": [1, [2, 3]], "...": "..." })
+    )]
+    #[case(
+        TruncateOptions { max_string_chars: Some(2), ..unlimited() },
+        json!({ "a": [1, [2, 3]], "b": "あい...", "c": {} })
+    )]
+    #[case(
+        TruncateOptions { max_string_chars: Some(3), ..unlimited() },
+        json!({ "a": [1, [2, 3]], "b": "あいう", "c": {} })
+    )]
+    #[case(
+        TruncateOptions { max_depth: Some(1), ..unlimited() },
+        json!({ "a": "...", "b": "あいう", "c": "..." })
+    )]
+    #[case(TruncateOptions { max_depth: Some(0), ..unlimited() }, json!("..."))]
+    fn test_truncate(#[case] options: TruncateOptions, #[case] expected: Node) {
+        let node: Node = json!({ "a": [1, [2, 3]], "b": "あいう", "c": {} });
+        assert_eq!(node.truncate(&options), expected);
+    }
+}
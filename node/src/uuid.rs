@@ -0,0 +1,63 @@
+use ::uuid::Uuid;
+
+use crate::from_node::as_str;
+use crate::serializer::{self, Formatter, Serializer, WriteJson};
+use crate::{Error, FromNode, MapKind, Node, ToNode};
+
+/// ハイフン区切りの他、uuid が解釈できる表記の文字列から変換する
+impl FromNode for Uuid {
+    fn from_node<K: MapKind>(node: &Node<K>) -> Result<Self, Error> {
+        let s = as_str::<Self, K>(node)?;
+        Uuid::parse_str(s)
+            .map_err(|e| Error::invalid_value::<Self>(format!("`{s}` を解釈できません（{e}）")))
+    }
+}
+
+/// 小文字のハイフン区切りの文字列に変換する
+impl ToNode for Uuid {
+    fn to_node(&self) -> Node {
+        Node::String(self.hyphenated().to_string())
+    }
+}
+
+impl WriteJson for Uuid {
+    fn write_json<W: std::io::Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), serializer::Error> {
+        serializer.serialize_str(self.hyphenated().encode_lower(&mut Uuid::encode_buffer()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+
+    #[rstest::rstest]
+    #[case("67e55044-10b1-426f-9247-bb680e5fe0c8")]
+    #[case("67E55044-10B1-426F-9247-BB680E5FE0C8")]
+    #[case("67e5504410b1426f9247bb680e5fe0c8")]
+    fn test_uuid(#[case] input: &str) {
+        let node: Node = json!(input);
+        let uuid = Uuid::from_node(&node).unwrap();
+        assert_eq!(
+            uuid.to_node(),
+            json!("67e55044-10b1-426f-9247-bb680e5fe0c8")
+        );
+        let mut json = Vec::new();
+        serializer::write_json(&uuid, &mut json).unwrap();
+        assert_eq!(json, br#""67e55044-10b1-426f-9247-bb680e5fe0c8""#);
+    }
+
+    #[rstest::rstest]
+    #[case(json!("67e55044-10b1-426f-9247"), "値を Uuid に変換できません（`67e55044-10b1-426f-9247` を解釈できません")]
+    #[case(json!("67e55044-10b1-426f-9247-bb680e5fe0cg"), "値を Uuid に変換できません（`67e55044-10b1-426f-9247-bb680e5fe0cg` を解釈できません")]
+    #[case(json!(null), "null の値は Uuid に変換できません")]
+    fn test_error(#[case] node: Node, #[case] expected: &str) {
+        match Uuid::from_node(&node) {
+            Err(e) => assert!(e.to_string().starts_with(expected), "{e}"),
+            result => panic!("{result:?}"),
+        }
+    }
+}
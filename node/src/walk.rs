@@ -0,0 +1,143 @@
+use crate::{MapKind, Node, ObjectMap, Pointer};
+
+impl<K: MapKind> Node<K> {
+    /// 全ての値を深さ優先（親を子より先）に辿り、JSON Pointer と値の可変参照を f に渡す
+    /// f で置き換えた値は、置き換えた後の中身を辿る
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut node: node::Node = node::json!({
+    ///     "users": [{ "email": "A@EXAMPLE.COM" }, { "email": "B@Example.com" }],
+    /// });
+    /// node.walk_mut(|path, node| {
+    ///     if let ["users", _, "email"] = path.tokens().iter().map(String::as_str).collect::<Vec<_>>()[..] {
+    ///         if let Some(email) = node.as_string_mut() {
+    ///             *email = email.to_lowercase();
+    ///         }
+    ///     }
+    /// });
+    /// assert_eq!(node.pointer("/users/1/email"), Some(&node::Node::from("b@example.com")));
+    /// ```
+    pub fn walk_mut(&mut self, mut f: impl FnMut(&Pointer, &mut Node<K>)) {
+        self.walk_mut_with(&mut Pointer::root(), &mut f);
+    }
+
+    /// 全ての値を深さ優先（親を子より先）に、JSON Pointer と組にして返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let node: node::Node = node::json!({ "a": [null, 1], "b": null });
+    /// let nulls = node
+    ///     .iter_all()
+    ///     .filter(|(_, node)| node.is_null())
+    ///     .map(|(path, _)| path.to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(nulls, ["/a/0", "/b"]);
+    /// ```
+    pub fn iter_all(&self) -> impl Iterator<Item = (Pointer, &Node<K>)> {
+        let mut stack = vec![(Pointer::root(), self)];
+        std::iter::from_fn(move || {
+            let (pointer, node) = stack.pop()?;
+
+            // 先頭の子から返却するため、逆順に積む
+            let children: Vec<(String, &Node<K>)> = match node {
+                Node::Object(object) => object
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value))
+                    .collect(),
+                Node::Array(array) => array
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| (index.to_string(), value))
+                    .collect(),
+                _ => Vec::new(),
+            };
+            stack.extend(children.into_iter().rev().map(|(token, value)| {
+                let mut pointer = pointer.clone();
+                pointer.push(token);
+                (pointer, value)
+            }));
+
+            Some((pointer, node))
+        })
+    }
+
+    fn walk_mut_with(&mut self, pointer: &mut Pointer, f: &mut impl FnMut(&Pointer, &mut Node<K>)) {
+        f(pointer, self);
+
+        match self {
+            Node::Object(object) => {
+                for (key, value) in object.iter_mut() {
+                    pointer.push(key);
+                    value.walk_mut_with(pointer, f);
+                    pointer.pop();
+                }
+            }
+            Node::Array(array) => {
+                for (index, value) in array.iter_mut().enumerate() {
+                    pointer.push(index.to_string());
+                    value.walk_mut_with(pointer, f);
+                    pointer.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Node, json};
+
+    #[test]
+    fn test_iter_all() {
+        let node: Node = json!({ "a/b": [1, { "c": null }], "d": true });
+        let all = node
+            .iter_all()
+            .map(|(path, node)| (path.to_string(), node.kind().to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            all,
+            [
+                ("", "object"),
+                ("/a~1b", "array"),
+                ("/a~1b/0", "number"),
+                ("/a~1b/1", "object"),
+                ("/a~1b/1/c", "null"),
+                ("/d", "boolean"),
+            ]
+            .map(|(path, kind)| (path.to_string(), kind.to_string()))
+        );
+        assert!(
+            node.iter_all()
+                .all(|(path, value)| node.pointer(&path.to_string()) == Some(value))
+        );
+    }
+
+    #[test]
+    fn test_walk_mut() {
+        let mut node: Node = json!({ "a/b": [1, { "c": null }], "d": true });
+        let mut paths = vec![];
+        node.walk_mut(|path, node| {
+            paths.push(path.to_string());
+            if node.is_null() {
+                *node = json!([false]);
+            }
+        });
+        assert_eq!(
+            paths,
+            [
+                "",
+                "/a~1b",
+                "/a~1b/0",
+                "/a~1b/1",
+                "/a~1b/1/c",
+                "/a~1b/1/c/0",
+                "/d"
+            ]
+        );
+        assert_eq!(node, json!({ "a/b": [1, { "c": [false] }], "d": true }));
+    }
+}
@@ -0,0 +1,236 @@
+use bumpalo::Bump;
+use bumpalo::collections::Vec as BumpVec;
+use node::{ArenaNode, Node};
+
+use crate::options::ParserOptions;
+use crate::tree::TreeBuilder;
+use crate::{Budget, Error, Key, Parser};
+
+/// reader から値を１つ解析し、bump に確保した node::ArenaNode を返却する
+/// 木は bump を破棄する際に一度に解放されるため、リクエストごとに解析して捨てる場合にメモリ確保の負荷を抑えられる
+/// 値を読み出す前に reader の末尾に到達した場合はエラーを返却する
+///
+/// # Examples
+///
+/// ```
+/// let bump = bumpalo::Bump::new();
+/// let node = parser::arena::parse_in(&bump, r#"{"b": [1, "x"], "a": null}"#.as_bytes()).unwrap();
+/// assert_eq!(node.get("b").and_then(|b| b.get_index(1)), Some(&node::ArenaNode::String("x")));
+/// assert_eq!(node.to_node(), parser::from_str(r#"{"a": null, "b": [1, "x"]}"#).unwrap());
+/// ```
+pub fn parse_in<T>(bump: &Bump, reader: T) -> Result<ArenaNode<'_>, Error>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    parse_in_with_options(bump, reader, ParserOptions::default())
+}
+
+/// 挙動を設定して parse_in と同様に解析する
+/// Object はキーの昇順に並べるため ParserOptions::preserve_order は評価しない
+pub fn parse_in_with_options<T>(
+    bump: &Bump,
+    reader: T,
+    options: ParserOptions,
+) -> Result<ArenaNode<'_>, Error>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    Parser::with_options(reader, options).parse_in(bump)
+}
+
+/// bump に確保した node::ArenaNode を構築する
+/// Object の要素は閉じる際にまとめてキーの昇順に並べる
+struct ArenaBuilder<'b> {
+    bump: &'b Bump,
+}
+
+/// Objectの要素とキーの位置を表現する
+/// キーの重複は Object を閉じる際にまとめて評価する
+struct Member<'b> {
+    key: &'b str,
+    value: ArenaNode<'b>,
+    line: std::ops::Range<usize>,
+    pos: std::ops::Range<usize>,
+}
+
+impl<T> Parser<T>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    /// Parser::parse と同様に値を１つ解析し、bump に確保した node::ArenaNode を返却する
+    /// Parser::reset と組み合わせると、Parser と bump の両方を使い回せる
+    pub fn parse_in<'b>(&mut self, bump: &'b Bump) -> Result<ArenaNode<'b>, Error> {
+        let node = self
+            .build(&mut ArenaBuilder { bump })?
+            .ok_or_else(|| self.missing_value())?;
+        if self.reject_trailing_characters {
+            self.ensure_end()?;
+        }
+
+        Ok(node)
+    }
+
+    /// 要素をキーの昇順に並べ、重複したキーを ParserOptions::duplicate_keys に従って１つにまとめる
+    fn close_object<'b>(
+        &mut self,
+        bump: &'b Bump,
+        mut members: BumpVec<'b, Member<'b>>,
+    ) -> Result<&'b [(&'b str, ArenaNode<'b>)], Error> {
+        // 同じキーは入力に現れた順に並べる
+        members.sort_unstable_by(|a, b| {
+            a.key
+                .cmp(b.key)
+                .then(a.line.start.cmp(&b.line.start))
+                .then(a.pos.start.cmp(&b.pos.start))
+        });
+
+        let mut object: BumpVec<'b, (&'b str, ArenaNode<'b>)> =
+            BumpVec::with_capacity_in(members.len(), bump);
+        for member in members {
+            match object.last_mut() {
+                Some((key, value)) if *key == member.key => {
+                    let key = Key {
+                        name: member.key.to_string(),
+                        line: member.line,
+                        pos: member.pos,
+                    };
+                    if self.accept_duplicate(&key)? {
+                        *value = member.value;
+                    }
+                }
+                _ => object.push((member.key, member.value)),
+            }
+        }
+
+        Ok(object.into_bump_slice())
+    }
+}
+
+impl<'b, T> TreeBuilder<T> for ArenaBuilder<'b>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    type Node = ArenaNode<'b>;
+    type Array = BumpVec<'b, ArenaNode<'b>>;
+    type Object = BumpVec<'b, Member<'b>>;
+    /// 値は Object に加える際に設定する
+    type Key = Member<'b>;
+
+    fn start_array(&mut self, _: &mut Parser<T>) -> Self::Array {
+        BumpVec::new_in(self.bump)
+    }
+
+    fn start_object(&mut self, _: &mut Parser<T>) -> Self::Object {
+        BumpVec::new_in(self.bump)
+    }
+
+    fn key(
+        &mut self,
+        parser: &mut Parser<T>,
+        _: &mut Budget,
+        name: String,
+        _: usize,
+    ) -> Result<Option<Member<'b>>, Error> {
+        let (line, pos) = parser.events.span();
+        Ok(Some(Member {
+            key: self.bump.alloc_str(&name),
+            value: ArenaNode::Null,
+            line,
+            pos,
+        }))
+    }
+
+    fn leaf(
+        &mut self,
+        _: &mut Parser<T>,
+        _: &mut Budget,
+        node: Node,
+    ) -> Result<ArenaNode<'b>, Error> {
+        Ok(match node {
+            Node::String(s) => ArenaNode::String(self.bump.alloc_str(&s)),
+            Node::Number(n) => {
+                ArenaNode::Number(bumpalo::format!(in self.bump, "{}", n).into_bump_str())
+            }
+            Node::Bool(b) => ArenaNode::Bool(b),
+            _ => ArenaNode::Null,
+        })
+    }
+
+    fn end_array(&mut self, _: &mut Parser<T>, array: Self::Array) -> ArenaNode<'b> {
+        ArenaNode::Array(array.into_bump_slice())
+    }
+
+    fn end_object(
+        &mut self,
+        parser: &mut Parser<T>,
+        members: Self::Object,
+    ) -> Result<ArenaNode<'b>, Error> {
+        Ok(ArenaNode::Object(parser.close_object(self.bump, members)?))
+    }
+
+    fn push(&mut self, array: &mut Self::Array, node: ArenaNode<'b>) {
+        array.push(node);
+    }
+
+    fn insert(
+        &mut self,
+        _: &mut Parser<T>,
+        members: &mut Self::Object,
+        mut member: Member<'b>,
+        node: ArenaNode<'b>,
+    ) -> Result<(), Error> {
+        member.value = node;
+        members.push(member);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::DuplicateKeys;
+
+    #[rstest::rstest]
+    #[case("[]")]
+    #[case(r#""a\"b""#)]
+    #[case(r#"{"日本語": ["値", 1, -2.5e3, true, null], "b": {}}"#)]
+    #[case(r#"{"z": 1, "a": {"y": [{}], "x": 18446744073709551615}}"#)]
+    fn test_parse_in(#[case] input: &str) {
+        let bump = Bump::new();
+        assert_eq!(
+            parse_in(&bump, input.as_bytes()).unwrap().to_node(),
+            crate::from_str(input).unwrap()
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(DuplicateKeys::Overwrite, Ok("3"))]
+    #[case(DuplicateKeys::KeepFirst, Ok("1"))]
+    #[case(DuplicateKeys::Error, Err("行: 1..1 位置: 18..20 でObjectのキー `a` が重複しています".to_string()))]
+    fn test_duplicate_keys(
+        #[case] duplicate_keys: DuplicateKeys,
+        #[case] expected: Result<&str, String>,
+    ) {
+        let bump = Bump::new();
+        let options = ParserOptions {
+            duplicate_keys,
+            ..Default::default()
+        };
+        let result =
+            parse_in_with_options(&bump, r#"{"a": 1, "b": 2, "a": 3}"#.as_bytes(), options);
+        match expected {
+            Ok(n) => {
+                let node = result.unwrap();
+                assert_eq!(node.get("a"), Some(&ArenaNode::Number(n)));
+                assert_eq!(node.get("b"), Some(&ArenaNode::Number("2")));
+            }
+            Err(message) => assert_eq!(result.unwrap_err().to_string(), message),
+        }
+    }
+
+    #[test]
+    fn test_missing_value() {
+        let bump = Bump::new();
+        assert!(parse_in(&bump, " ".as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use node::Node;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
+
+use crate::boundary::Boundary;
+use crate::options::ParserOptions;
+use crate::{Error, Parser};
+
+/// tokio::io::AsyncBufRead から読み取れる文字列からJSONデータを構築する
+/// 受信したバイト列を値の区切りまで蓄えてから Parser で解析するため、解析中にスレッドをブロックしない
+/// 行と位置は Parser と同様に reader の先頭から数える
+///
+/// # Examples
+///
+/// ```
+/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// runtime.block_on(async {
+///     let input: &[u8] = b"{\"id\": 1}\n{\"id\": 2}";
+///     let mut parser = parser::asynchronous::AsyncParser::new(input);
+///     assert!(matches!(parser.parse().await, Ok(node::Node::Object(_))));
+///     assert!(matches!(parser.parse_next().await, Ok(Some(node::Node::Object(_)))));
+///     assert!(matches!(parser.parse_next().await, Ok(None)));
+/// });
+/// ```
+pub struct AsyncParser<R>
+where
+    R: AsyncBufRead + Unpin,
+{
+    reader: R,
+    parser: Parser<std::collections::VecDeque<u8>>,
+    boundary: Boundary,
+    /// reader の末尾に到達したか
+    eof: bool,
+    /// reader から読み出したバイト数
+    bytes: usize,
+    max_bytes: Option<usize>,
+}
+
+impl<R> AsyncParser<R>
+where
+    R: AsyncBufRead + Unpin,
+{
+    /// パーサーを生成して返却する
+    pub fn new(reader: R) -> Self {
+        Self::with_options(reader, ParserOptions::default())
+    }
+
+    /// 挙動を設定したパーサーを生成して返却する
+    pub fn with_options(reader: R, options: ParserOptions) -> Self {
+        Self {
+            reader,
+            boundary: Boundary::new(options.allow_comments),
+            eof: false,
+            bytes: 0,
+            max_bytes: options.max_bytes,
+            parser: Parser::with_options(std::collections::VecDeque::new(), options),
+        }
+    }
+
+    /// Parser::parse と同様に値を１つ解析して返却する
+    /// 値の区切りを読み出すまで reader からの読み出しを待機する
+    /// ParserOptions::reject_trailing_characters が有効な場合は reader の末尾まで読み出してから解析する
+    /// reader 自体のエラーは Error::LexerError を返却する
+    pub async fn parse(&mut self) -> Result<Node, Error> {
+        self.wait().await?;
+        self.parser.parse()
+    }
+
+    /// Parser::parse_next と同様に値を１つ解析し、reader の末尾に到達した場合は None を返却する
+    pub async fn parse_next(&mut self) -> Result<Option<Node>, Error> {
+        self.wait().await?;
+        self.parser.parse_next()
+    }
+
+    /// 値を１つ解析できるだけのバイト列を読み出すまで待機する
+    async fn wait(&mut self) -> Result<(), Error> {
+        while !self.eof && (self.boundary.values == 0 || self.parser.reject_trailing_characters) {
+            self.fill().await?;
+        }
+        self.boundary.values = self.boundary.values.saturating_sub(1);
+
+        Ok(())
+    }
+
+    /// reader から読み出せるだけ読み出し、解析待ちのバイト列に追加する
+    async fn fill(&mut self) -> Result<(), Error> {
+        let chunk = self
+            .reader
+            .fill_buf()
+            .await
+            .map_err(|e| Error::LexerError(e.to_string()))?;
+
+        if chunk.is_empty() {
+            self.eof = true;
+            return Ok(());
+        }
+
+        // 値の区切りまで蓄える間も上限を超えないようにする
+        self.bytes += chunk.len();
+        if let Some(max_bytes) = self.max_bytes
+            && self.bytes > max_bytes
+        {
+            return Err(Error::SizeLimitExceeded(max_bytes));
+        }
+
+        self.boundary.scan(chunk);
+        self.parser.get_mut().extend(chunk);
+
+        let len = chunk.len();
+        self.reader.consume(len);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    /// １バイトずつしか読み出せない reader から全ての値を解析する
+    async fn parse_all(input: &str, options: ParserOptions) -> Vec<Result<Node, String>> {
+        let reader = tokio::io::BufReader::with_capacity(1, input.as_bytes());
+        let mut parser = AsyncParser::with_options(reader, options);
+        let mut nodes = vec![];
+
+        loop {
+            match parser.parse_next().await {
+                Ok(None) => break,
+                Ok(Some(node)) => nodes.push(Ok(node)),
+                Err(e) => {
+                    nodes.push(Err(e.to_string()));
+                    break;
+                }
+            }
+        }
+
+        nodes
+    }
+
+    #[rstest]
+    #[case(
+        r#"{"a": ["}", 1.5, "\"]"]}"#,
+        vec![Ok(Node::Object(node::Map::from([(
+            "a".to_string(),
+            Node::Array(vec![
+                Node::String("}".into()),
+                Node::Number(1.5.into()),
+                Node::String("\"]".into()),
+            ])
+        )])))]
+    )]
+    #[case(
+        "1 -2.5e3\ntrue\"x\"null[]",
+        vec![
+            Ok(Node::Number(1.into())),
+            Ok(Node::Number((-2500.0).into())),
+            Ok(Node::Bool(true)),
+            Ok(Node::String("x".into())),
+            Ok(Node::Null),
+            Ok(Node::Array(vec![])),
+        ]
+    )]
+    #[case(
+        "[1, 2",
+        vec![Err("行: 1..1 位置: 5..5 で構文エラーが発生しました（Arrayの要素の後は `,` か `]` でなければなりません）".into())]
+    )]
+    #[tokio::test]
+    async fn test_async_parser(#[case] input: &str, #[case] expected: Vec<Result<Node, String>>) {
+        assert_eq!(parse_all(input, ParserOptions::default()).await, expected);
+    }
+
+    #[tokio::test]
+    async fn test_async_parser_with_comments() {
+        let options = ParserOptions {
+            allow_comments: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            parse_all("[1 /* ] */, // ]\n 2] 3", options).await,
+            vec![
+                Ok(Node::Array(vec![
+                    Node::Number(1.into()),
+                    Node::Number(2.into())
+                ])),
+                Ok(Node::Number(3.into())),
+            ]
+        );
+    }
+}
@@ -1,8 +1,9 @@
 use macro_deserialize::Deserialize;
 use node::FromNode;
+use node::Map;
 use parser::Parser;
-use std::collections::BTreeMap;
 
+#[allow(clippy::disallowed_names)]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     #[derive(Deserialize, Debug)]
     #[allow(dead_code)]
@@ -23,22 +24,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         false_value: bool,
     }
 
-    let object = node::Node::Object(BTreeMap::from([
-        ("string".into(), node::Node::String("Hello, World!".into())),
-        ("i8".into(), node::Node::Number(-10f64)),
-        ("i16".into(), node::Node::Number(-20f64)),
-        ("i32".into(), node::Node::Number(-30f64)),
-        ("i64".into(), node::Node::Number(-40f64)),
-        ("isize".into(), node::Node::Number(-50f64)),
-        ("u8".into(), node::Node::Number(10f64)),
-        ("u16".into(), node::Node::Number(20f64)),
-        ("u32".into(), node::Node::Number(30f64)),
-        ("u64".into(), node::Node::Number(40f64)),
-        ("usize".into(), node::Node::Number(50f64)),
-        ("f64".into(), node::Node::Number(60.123f64)),
-        ("true_value".into(), node::Node::True),
-        ("false_value".into(), node::Node::False),
-    ]));
+    let object: node::Node = node::json!({
+        "string": "Hello, World!",
+        "i8": -10,
+        "i16": -20,
+        "i32": -30,
+        "i64": -40,
+        "isize": -50,
+        "u8": 10,
+        "u16": 20,
+        "u32": 30,
+        "u64": 40,
+        "usize": 50,
+        "f64": 60.123,
+        "true_value": true,
+        "false_value": false,
+    });
 
     let foo = Foo::from_node(&object)?;
 
@@ -67,26 +68,26 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("{:#?}", bar);
 
-    let bar = Bar::from_node(&node::Node::Object(BTreeMap::new()))?;
+    let bar = Bar::from_node(&node::Node::<node::map::Standard>::Object(Map::new()))?;
 
     println!("{:#?}", bar);
 
-    let object = node::Node::Object(BTreeMap::from([
-        ("string".into(), node::Node::Null),
-        ("i8".into(), node::Node::Null),
-        ("i16".into(), node::Node::Null),
-        ("i32".into(), node::Node::Null),
-        ("i64".into(), node::Node::Null),
-        ("isize".into(), node::Node::Null),
-        ("u8".into(), node::Node::Null),
-        ("u16".into(), node::Node::Null),
-        ("u32".into(), node::Node::Null),
-        ("u64".into(), node::Node::Null),
-        ("usize".into(), node::Node::Null),
-        ("f64".into(), node::Node::Null),
-        ("true_value".into(), node::Node::Null),
-        ("false_value".into(), node::Node::Null),
-    ]));
+    let object: node::Node = node::json!({
+        "string": null,
+        "i8": null,
+        "i16": null,
+        "i32": null,
+        "i64": null,
+        "isize": null,
+        "u8": null,
+        "u16": null,
+        "u32": null,
+        "u64": null,
+        "usize": null,
+        "f64": null,
+        "true_value": null,
+        "false_value": null,
+    });
 
     let bar = Bar::from_node(&object)?;
 
@@ -111,23 +112,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         d: Option<usize>,
     }
 
-    let object = node::Node::Object(BTreeMap::from([
-        (
-            "b".into(),
-            node::Node::Object(BTreeMap::from([
-                ("c".into(), node::Node::Number(12f64)),
-                ("d".into(), node::Node::Null),
-            ])),
-        ),
-        (
-            "optional_b".into(),
-            node::Node::Object(BTreeMap::from([
-                ("c".into(), node::Node::Number(12f64)),
-                ("d".into(), node::Node::Null),
-            ])),
-        ),
-        ("optional_b_2".into(), node::Node::Null),
-    ]));
+    let object: node::Node = node::json!({
+        "b": { "c": 12, "d": null },
+        "optional_b": { "c": 12, "d": null },
+        "optional_b_2": null,
+    });
 
     let a = A::from_node(&object);
 
@@ -148,52 +137,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         v: Vec<i16>,
     }
 
-    let object = node::Node::Object(BTreeMap::from([
-        (
-            "usize".into(),
-            node::Node::Array(vec![
-                node::Node::Number(10f64),
-                node::Node::Number(11f64),
-                node::Node::Number(22f64),
-            ]),
-        ),
-        (
-            "nested_string".into(),
-            node::Node::Array(vec![
-                node::Node::Array(vec![
-                    node::Node::String("a".into()),
-                    node::Node::String("b".into()),
-                    node::Node::String("c".into()),
-                ]),
-                node::Node::Array(vec![
-                    node::Node::String("d".into()),
-                    node::Node::String("e".into()),
-                    node::Node::String("f".into()),
-                ]),
-            ]),
-        ),
-        (
-            "optional_f64".into(),
-            node::Node::Array(vec![
-                node::Node::Number(10f64),
-                node::Node::Null,
-                node::Node::Number(22f64),
-            ]),
-        ),
-        (
-            "deep_nested_i16".into(),
-            node::Node::Array(vec![
-                node::Node::Object(BTreeMap::from([(
-                    "v".into(),
-                    node::Node::Array(vec![node::Node::Number(-10f64), node::Node::Number(22f64)]),
-                )])),
-                node::Node::Object(BTreeMap::from([(
-                    "v".into(),
-                    node::Node::Array(vec![node::Node::Number(-66f64), node::Node::Number(77f64)]),
-                )])),
-            ]),
-        ),
-    ]));
+    let object: node::Node = node::json!({
+        "usize": [10, 11, 22],
+        "nested_string": [["a", "b", "c"], ["d", "e", "f"]],
+        "optional_f64": [10, null, 22],
+        "deep_nested_i16": [{ "v": [-10, 22] }, { "v": [-66, 77] }],
+    });
 
     let vector = Vector::from_node(&object);
 
@@ -207,25 +156,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         t3: Option<(Option<usize>, String, Option<i16>)>,
     }
 
-    let object = node::Node::Object(BTreeMap::from([
-        (
-            "t".into(),
-            node::Node::Array(vec![
-                node::Node::Number(4f64),
-                node::Node::String("Hello, World!".into()),
-                node::Node::Number(-8f64),
-            ]),
-        ),
-        (
-            "t2".into(),
-            node::Node::Array(vec![
-                node::Node::Number(4f64),
-                node::Node::String("Hello, World!".into()),
-                node::Node::Null,
-            ]),
-        ),
-        ("t3".into(), node::Node::Null),
-    ]));
+    let object: node::Node = node::json!({
+        "t": [4, "Hello, World!", -8],
+        "t2": [4, "Hello, World!", null],
+        "t3": null,
+    });
 
     let derive_tuple = DeriveTuple::from_node(&object);
 
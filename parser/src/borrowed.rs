@@ -0,0 +1,239 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use node::{BorrowedNode, Node};
+
+use crate::lexer::unescape;
+use crate::options::ParserOptions;
+use crate::tree::TreeBuilder;
+use crate::{Budget, Error, Key, Parser};
+
+/// 文字列全体を１つの JSON の値として解析し、入力の文字列を借用する node::BorrowedNode を返却する
+/// エスケープを含まない文字列とキーは入力を参照し、メモリを確保しない
+/// 値の後に空白以外の文字が続く場合は Error::TrailingCharacters を返却する
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// let input = r#"{"name": "Alice", "quote": "a\"b"}"#;
+/// let node = parser::borrowed::parse_borrowed(input).unwrap();
+/// assert!(matches!(node.get("name"), Some(node::BorrowedNode::String(Cow::Borrowed("Alice")))));
+/// assert!(matches!(node.get("quote"), Some(node::BorrowedNode::String(Cow::Owned(_)))));
+/// assert_eq!(node.into_owned(), parser::from_str(input).unwrap());
+/// ```
+pub fn parse_borrowed(input: &str) -> Result<BorrowedNode<'_>, Error> {
+    parse_borrowed_with_options(input, ParserOptions::default())
+}
+
+/// 挙動を設定して parse_borrowed と同様に解析する
+/// ParserOptions::reject_trailing_characters によらず値の後の文字を検証する
+pub fn parse_borrowed_with_options(
+    input: &str,
+    options: ParserOptions,
+) -> Result<BorrowedNode<'_>, Error> {
+    let mut parser = Parser::with_options(input.as_bytes(), options);
+    // 文字列は入力から切り出すため、Lexer には内容を保持させない
+    parser.events.set_discard(true);
+
+    let node = parser
+        .build(&mut BorrowedBuilder { input })?
+        .ok_or_else(|| parser.missing_value())?;
+    parser.ensure_end()?;
+
+    Ok(node)
+}
+
+/// 文字列を input から切り出した node::BorrowedNode を構築する
+/// Lexer は内容を保持しないため、文字列のバイト数は切り出した際に数える
+struct BorrowedBuilder<'a> {
+    input: &'a str,
+}
+
+impl<'a> Parser<&'a [u8]> {
+    /// 直前に読み出した文字列を input から切り出して返却する
+    /// エスケープを含む場合のみ、Lexer と同じくエスケープを解釈した文字列を構築する
+    fn string(&self, input: &'a str) -> Cow<'a, str> {
+        let bytes = self.events.string_bytes();
+        let raw = &input[bytes.start + 1..bytes.end - 1];
+        if !raw.contains('\\') {
+            return Cow::Borrowed(raw);
+        }
+
+        let mut unescaped = String::with_capacity(raw.len());
+        let mut chars = raw.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    let c = chars
+                        .next()
+                        .expect("Lexer が閉じたエスケープのみ受け付ける");
+                    let c = unescape(c, || chars.next().ok_or(()))
+                        .ok()
+                        .flatten()
+                        .expect("Lexer が解釈できるエスケープのみ受け付ける");
+                    unescaped.push(c);
+                }
+                c => unescaped.push(c),
+            }
+        }
+
+        Cow::Owned(unescaped)
+    }
+}
+
+impl<'a> TreeBuilder<&'a [u8]> for BorrowedBuilder<'a> {
+    type Node = BorrowedNode<'a>;
+    type Array = Vec<BorrowedNode<'a>>;
+    type Object = BTreeMap<Cow<'a, str>, BorrowedNode<'a>>;
+    type Key = (Cow<'a, str>, Key);
+
+    fn start_array(&mut self, _: &mut Parser<&'a [u8]>) -> Self::Array {
+        vec![]
+    }
+
+    fn start_object(&mut self, _: &mut Parser<&'a [u8]>) -> Self::Object {
+        BTreeMap::new()
+    }
+
+    fn key(
+        &mut self,
+        parser: &mut Parser<&'a [u8]>,
+        budget: &mut Budget,
+        _: String,
+        _: usize,
+    ) -> Result<Option<Self::Key>, Error> {
+        let name = parser.string(self.input);
+        budget.charge_string(name.len(), parser.events.span())?;
+        let (line, pos) = parser.events.span();
+        // Key::name は重複した場合のみ複製して設定する
+        let name_on_duplicate = String::new();
+        Ok(Some((
+            name,
+            Key {
+                name: name_on_duplicate,
+                line,
+                pos,
+            },
+        )))
+    }
+
+    fn leaf(
+        &mut self,
+        parser: &mut Parser<&'a [u8]>,
+        budget: &mut Budget,
+        node: Node,
+    ) -> Result<BorrowedNode<'a>, Error> {
+        Ok(match node {
+            Node::String(_) => {
+                let value = parser.string(self.input);
+                budget.charge_string(value.len(), parser.events.span())?;
+                BorrowedNode::String(value)
+            }
+            Node::Number(n) => BorrowedNode::Number(n),
+            Node::Bool(b) => BorrowedNode::Bool(b),
+            _ => BorrowedNode::Null,
+        })
+    }
+
+    fn end_array(&mut self, _: &mut Parser<&'a [u8]>, array: Self::Array) -> BorrowedNode<'a> {
+        BorrowedNode::Array(array)
+    }
+
+    fn end_object(
+        &mut self,
+        _: &mut Parser<&'a [u8]>,
+        object: Self::Object,
+    ) -> Result<BorrowedNode<'a>, Error> {
+        Ok(BorrowedNode::Object(object))
+    }
+
+    fn push(&mut self, array: &mut Self::Array, node: BorrowedNode<'a>) {
+        array.push(node);
+    }
+
+    fn insert(
+        &mut self,
+        parser: &mut Parser<&'a [u8]>,
+        object: &mut Self::Object,
+        (name, mut key): Self::Key,
+        node: BorrowedNode<'a>,
+    ) -> Result<(), Error> {
+        if object.contains_key(&name) {
+            key.name = name.to_string();
+            if !parser.accept_duplicate(&key)? {
+                return Ok(());
+            }
+        }
+        object.insert(name, node);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::DuplicateKeys;
+
+    #[rstest::rstest]
+    #[case(r#""plain""#)]
+    #[case(r#""a\"b\\c""#)]
+    #[case(r#""a\nb\t\u00e9\ud83d\ude00\/""#)]
+    #[case(r#"{"k\u0001": "\b\f\r"}"#)]
+    #[case(r#"{"日本語": ["値", 1, -2.5, true, null], "b": {}}"#)]
+    #[case(r#"[[], {"a": {"b": [""]}}]"#)]
+    fn test_parse_borrowed(#[case] input: &str) {
+        assert_eq!(
+            parse_borrowed(input).unwrap().into_owned(),
+            crate::from_str(input).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_borrows_input() {
+        let input = r#"{"key": ["value", "esc\"aped"]}"#;
+        let node = parse_borrowed(input).unwrap();
+        let BorrowedNode::Object(object) = &node else {
+            unreachable!()
+        };
+        assert!(object.keys().all(|key| matches!(key, Cow::Borrowed(_))));
+        assert!(matches!(
+            node.get("key").and_then(|array| array.get_index(0)),
+            Some(BorrowedNode::String(Cow::Borrowed("value")))
+        ));
+        assert_eq!(
+            node.get("key")
+                .and_then(|array| array.get_index(1))
+                .and_then(BorrowedNode::as_str),
+            Some("esc\"aped")
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(DuplicateKeys::Overwrite, Ok(2.into()))]
+    #[case(DuplicateKeys::KeepFirst, Ok(1.into()))]
+    #[case(DuplicateKeys::Error, Err("行: 1..1 位置: 10..12 でObjectのキー `a` が重複しています".to_string()))]
+    fn test_duplicate_keys(
+        #[case] duplicate_keys: DuplicateKeys,
+        #[case] expected: Result<node::Number, String>,
+    ) {
+        let options = ParserOptions {
+            duplicate_keys,
+            ..Default::default()
+        };
+        let result = parse_borrowed_with_options(r#"{"a": 1, "a": 2}"#, options);
+        match expected {
+            Ok(n) => assert_eq!(result.unwrap().get("a"), Some(&BorrowedNode::Number(n))),
+            Err(message) => assert_eq!(result.unwrap_err().to_string(), message),
+        }
+    }
+
+    #[test]
+    fn test_trailing_characters() {
+        assert!(matches!(
+            parse_borrowed("[1] x"),
+            Err(Error::TrailingCharacters(_, _, 'x'))
+        ));
+    }
+}
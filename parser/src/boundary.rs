@@ -0,0 +1,136 @@
+/// 解析せずにバイト列を走査し、読み出し終えたトークンと最上位の値の区切りを数える
+/// 分割して届くバイト列について、Parser が読み出しの途中で末尾に到達しないかを判定するために利用する
+/// 構文の誤りは検証せず、Lexer よりも細かく区切らないため、数えた分までは Lexer が末尾に到達しない
+pub(crate) struct Boundary {
+    depth: usize,
+    scan: Scan,
+    allow_comments: bool,
+    /// 読み出し終えたトークンの数
+    pub(crate) tokens: usize,
+    /// 区切りまで読み出したがまだ解析していない最上位の値の数
+    pub(crate) values: usize,
+}
+
+/// 走査中の位置を表現する
+enum Scan {
+    /// トークンの間
+    Between,
+    /// 文字列の中
+    String,
+    /// 文字列中の `\` の直後
+    Escape,
+    /// 数値・true・false・null の中
+    Literal,
+    /// コメントの開始かもしれない `/` の直後
+    Slash,
+    /// `// ...` の中
+    LineComment,
+    /// `/* ... */` の中
+    BlockComment,
+    /// `/* ... */` の中の `*` の直後
+    BlockCommentStar,
+}
+
+impl Boundary {
+    pub(crate) fn new(allow_comments: bool) -> Self {
+        Self {
+            depth: 0,
+            scan: Scan::Between,
+            allow_comments,
+            tokens: 0,
+            values: 0,
+        }
+    }
+
+    pub(crate) fn scan(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.scan_byte(*b);
+        }
+    }
+
+    fn scan_byte(&mut self, b: u8) {
+        match self.scan {
+            Scan::String => match b {
+                b'\\' => self.scan = Scan::Escape,
+                b'"' => {
+                    self.scan = Scan::Between;
+                    self.end_token();
+                }
+                _ => {}
+            },
+            Scan::Escape => self.scan = Scan::String,
+            Scan::Literal => {
+                // 区切り文字を読み出すまでは数値・リテラルが続く可能性がある
+                if matches!(
+                    b,
+                    b' ' | b'\t'
+                        | b'\n'
+                        | b'\r'
+                        | b','
+                        | b':'
+                        | b'['
+                        | b']'
+                        | b'{'
+                        | b'}'
+                        | b'"'
+                        | b'/'
+                ) {
+                    self.scan = Scan::Between;
+                    self.end_token();
+                    self.scan_byte(b);
+                }
+            }
+            Scan::Slash => match b {
+                b'/' => self.scan = Scan::LineComment,
+                b'*' => self.scan = Scan::BlockComment,
+                // Parser がエラーを返却する
+                _ => {
+                    self.scan = Scan::Between;
+                    self.scan_byte(b);
+                }
+            },
+            Scan::LineComment => {
+                if b == b'\n' {
+                    self.scan = Scan::Between;
+                }
+            }
+            Scan::BlockComment => {
+                if b == b'*' {
+                    self.scan = Scan::BlockCommentStar;
+                }
+            }
+            Scan::BlockCommentStar => match b {
+                b'/' => self.scan = Scan::Between,
+                b'*' => {}
+                _ => self.scan = Scan::BlockComment,
+            },
+            Scan::Between => match b {
+                b'"' => self.scan = Scan::String,
+                b'-' | b'0'..=b'9' | b't' | b'f' | b'n' => self.scan = Scan::Literal,
+                b'{' | b'[' => {
+                    self.tokens += 1;
+                    self.depth += 1;
+                }
+                b'}' | b']' => {
+                    // 対応しない閉じ括弧は Parser がエラーを返却する
+                    self.depth = self.depth.saturating_sub(1);
+                    self.end_token();
+                }
+                // 最上位に現れた場合は Parser がエラーを返却する
+                b',' | b':' => self.end_token(),
+                b'/' if self.allow_comments => self.scan = Scan::Slash,
+                // それ以外の文字は Lexer と同様に読み飛ばす
+                _ => {}
+            },
+        }
+    }
+
+    /// トークンを読み出し終えた
+    /// 最上位ではトークンの終わりが値の終わりとなる
+    fn end_token(&mut self) {
+        self.tokens += 1;
+        if self.depth == 0 {
+            self.values += 1;
+        }
+    }
+}
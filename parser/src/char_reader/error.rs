@@ -15,4 +15,6 @@ pub enum Error {
     InvalidCodepoint(u32, usize, usize),
     #[error("{0}")]
     ReadError(String),
+    #[error("入力が上限（{0}バイト）を超えました")]
+    SizeLimitExceeded(usize),
 }
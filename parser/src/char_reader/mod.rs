@@ -35,6 +35,13 @@ where
     position: usize,
     peek_buffer: std::collections::VecDeque<(char, usize, usize)>,
     peek_offset: usize,
+    /// reader から読み出したバイト数
+    bytes: usize,
+    max_bytes: Option<usize>,
+    /// Some の場合は読み出した文字を蓄える
+    recorded: Option<String>,
+    /// read・consume で読み出した文字のバイト数
+    offset: usize,
 }
 
 impl<T> CharReader<T>
@@ -51,9 +58,61 @@ where
             position: 0,
             peek_buffer: std::collections::VecDeque::new(),
             peek_offset: 0,
+            bytes: 0,
+            max_bytes: None,
+            recorded: None,
+            offset: 0,
         }
     }
 
+    /// reader を差し替え、１行目の先頭から読み出し直す
+    /// peek のバッファや設定は再利用し、差し替える前の reader を返却する
+    pub fn reset(&mut self, reader: T) -> T {
+        self.line = 1;
+        self.position = 0;
+        self.peek_buffer.clear();
+        self.peek_offset = 0;
+        self.bytes = 0;
+        self.offset = 0;
+        if let Some(recorded) = self.recorded.as_mut() {
+            recorded.clear();
+        }
+
+        std::mem::replace(&mut self.reader, reader)
+    }
+
+    /// reader から読み出せるバイト数の上限を設定する
+    /// 上限を超えて読み出すと Error::SizeLimitExceeded を返却する
+    pub fn set_max_bytes(&mut self, max_bytes: Option<usize>) {
+        self.max_bytes = max_bytes;
+    }
+
+    /// true の場合、以降に read・consume で読み出した文字を蓄える
+    /// peek しただけの文字は蓄えない
+    pub fn set_recording(&mut self, recording: bool) {
+        self.recorded = recording.then(String::new);
+    }
+
+    /// 蓄えた文字を取り出して返却する
+    pub fn take_recorded(&mut self) -> String {
+        self.recorded
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// read・consume で読み出した文字のバイト数を返却する
+    /// peek しただけの文字は含まないため、次に read する文字の入力中の位置となる
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// 内部の reader への可変参照を返却する
+    /// peek 済みの文字には影響しない
+    pub(crate) fn get_mut(&mut self) -> &mut T {
+        &mut self.reader
+    }
+
     /// 1文字先読みする
     /// 内部的には std::io::BufRead は1文字進む
     /// 外部的には peek 後に read しても peek と同じようを返す（peek していない場合は普通に std::io::BufRead から UTF-8 を１文字読む）
@@ -62,9 +121,8 @@ where
             Ok(self
                 .peek_buffer
                 .get(self.peek_buffer.len() - self.peek_offset)
-                .map(|v| {
+                .inspect(|_| {
                     self.peek_offset -= 1;
-                    v
                 })
                 .expect("peek_offsetアサイン時にpeek_bufferの内容を確認している"))
         } else {
@@ -94,12 +152,20 @@ where
     pub fn consume(&mut self, i: usize) -> Result<String, Error> {
         let mut acc = Vec::new();
         for _ in 0..i {
+            if self.peek_offset == self.peek_buffer.len() {
+                self.peek_offset = self.peek_offset.saturating_sub(1);
+            }
             let (c, _, _) = self.peek_buffer.pop_front().ok_or(Error::ConsumeError)?;
-            self.peek_offset = self.peek_offset.saturating_sub(1);
             acc.push(c);
         }
 
-        Ok(acc.into_iter().collect::<String>())
+        let consumed = acc.into_iter().collect::<String>();
+        self.offset += consumed.len();
+        if let Some(recorded) = self.recorded.as_mut() {
+            recorded.push_str(&consumed);
+        }
+
+        Ok(consumed)
     }
 
     /// peek で蓄えられた文字があればそれを、なければ reader から UTF-8 で１文字読み取り返却する
@@ -107,17 +173,30 @@ where
     /// 多バイトの UTF-8 文字で続き文字が違反している場合は Error::InvalidUTF8 を返却する
     /// 読み取れた u32 が UTF-8 の文字に変換できない場合は Error::InvalidCodepoint を返却する
     pub fn read(&mut self) -> Result<(char, usize, usize), Error> {
+        let result = self.read_char();
+        if let Ok((c, _, _)) = result.as_ref() {
+            self.offset += c.len_utf8();
+            if let Some(recorded) = self.recorded.as_mut() {
+                recorded.push(*c);
+            }
+        }
+
+        result
+    }
+
+    fn read_char(&mut self) -> Result<(char, usize, usize), Error> {
         if self.peek_buffer.is_empty() {
             self.next()
         } else {
             // peek と良く似ているがこちらは実体を返却する
+            // peek のカーソルが先頭にある場合のみ、取り出した文字の分だけ未peekの文字数が減る
+            if self.peek_offset == self.peek_buffer.len() {
+                self.peek_offset -= 1;
+            }
+
             Ok(self
                 .peek_buffer
                 .pop_front()
-                .map(|v| {
-                    self.peek_offset = self.peek_offset.saturating_sub(1);
-                    v
-                })
                 .expect("peek_bufferを確認済みであるため必ず値は取れる"))
         }
     }
@@ -134,6 +213,7 @@ where
                     Ok(v)
                 }
             })?;
+        self.count(1)?;
 
         // utf8_char_width が利用できるようになればそちらを利用したほうが良い
         let codepoint = if 0b11111000 & buf[0] == 0b11110000 {
@@ -166,7 +246,7 @@ where
         self.position += 1;
 
         char::from_u32(codepoint)
-            .ok_or_else(|| Error::InvalidCodepoint(codepoint, self.line, self.position))
+            .ok_or(Error::InvalidCodepoint(codepoint, self.line, self.position))
             .map(|c| {
                 let r = (c, self.line, self.position);
 
@@ -179,28 +259,47 @@ where
             })
     }
 
+    fn count(&mut self, bytes: usize) -> Result<(), Error> {
+        self.bytes += bytes;
+
+        match self.max_bytes {
+            Some(max_bytes) if self.bytes > max_bytes => Err(Error::SizeLimitExceeded(max_bytes)),
+            _ => Ok(()),
+        }
+    }
+
     fn read_rest<const N: usize>(&mut self) -> Result<[u8; N], Error> {
         let mut rest = [0u8; N];
-        self.reader
-            .read(&mut rest)
-            .map_err(|e| match e.kind() {
-                std::io::ErrorKind::UnexpectedEof => Error::EOF(self.line, self.position),
-                _ => Error::ReadError(e.to_string()),
-            })
-            .and_then(|v| {
-                if v == 0 {
-                    Err(Error::EOF(self.line, self.position))
-                } else {
-                    Ok(())
-                }
-            })?;
+        let mut filled = 0;
+
+        // 一度の read で多バイト文字の残りが全て読み出せるとは限らない
+        while filled < N {
+            let v = self
+                .reader
+                .read(&mut rest[filled..])
+                .map_err(|e| match e.kind() {
+                    std::io::ErrorKind::UnexpectedEof => Error::EOF(self.line, self.position),
+                    _ => Error::ReadError(e.to_string()),
+                })?;
+
+            if v == 0 {
+                break;
+            }
+            filled += v;
+        }
+
+        self.count(filled)?;
 
-        for i in rest.iter() {
+        for i in rest[..filled].iter() {
             if i & 0b1100_0000 != 0b1000_0000 {
                 return Err(Error::InvalidUTF8(*i, self.line, self.position));
             }
         }
 
+        if filled < N {
+            return Err(Error::EOF(self.line, self.position));
+        }
+
         Ok(rest)
     }
 }
@@ -424,13 +523,39 @@ mod tests {
         let handle = std::io::BufReader::new(cursor);
         let mut char_reader = CharReader::new(handle);
 
-        let expected = ((0b1111_0111 as u32) & 0b0000_0111) << 18
-            | ((0b1011_1111 as u32) & 0b0011_1111) << 12
-            | ((0b1011_1111 as u32) & 0b0011_1111) << 6
-            | (0b1011_1111 as u32) & 0b0011_1111;
+        let expected = (0b1111_0111_u32 & 0b0000_0111) << 18
+            | (0b1011_1111_u32 & 0b0011_1111) << 12
+            | (0b1011_1111_u32 & 0b0011_1111) << 6
+            | 0b1011_1111_u32 & 0b0011_1111;
 
         let result = char_reader.read();
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), Error::InvalidCodepoint(expected, 1, 1));
     }
+
+    #[test]
+    fn test_read_after_peek_back() {
+        let source = "abcd";
+        let cursor = std::io::Cursor::new(source);
+        let handle = std::io::BufReader::new(cursor);
+        let mut char_reader = CharReader::new(handle);
+
+        for _ in 0..3 {
+            char_reader.peek().unwrap();
+        }
+        for _ in 0..3 {
+            char_reader.peek_back().unwrap();
+        }
+
+        // peek した文字の読み出しと peek を交互に行っても順序が崩れない
+        assert_eq!(char_reader.peek().unwrap().0, 'a');
+        assert_eq!(char_reader.read().unwrap().0, 'a');
+        assert_eq!(char_reader.peek().unwrap().0, 'b');
+        assert_eq!(char_reader.read().unwrap().0, 'b');
+        assert_eq!(char_reader.peek().unwrap().0, 'c');
+        assert_eq!(char_reader.peek().unwrap().0, 'd');
+        assert_eq!(char_reader.read().unwrap().0, 'c');
+        assert_eq!(char_reader.read().unwrap().0, 'd');
+        assert!(char_reader.read().is_err());
+    }
 }
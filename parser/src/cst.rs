@@ -0,0 +1,823 @@
+use node::Node;
+
+use crate::Error;
+use crate::lexer::{Data, Kind, Lexer, Token};
+use crate::options::ParserOptions;
+use crate::raw::RawValue;
+
+/// 空白・コメントを含めて入力を保持する JSON文書を表現する
+/// to_string で元の入力と同じ文字列に書き戻せるため、値を編集しても差分は編集箇所に留まる
+///
+/// # Examples
+///
+/// ```
+/// let input = "{\n  // 設定\n  \"name\": \"a\\\"c\",\n  \"size\": 1.50\n}\n";
+/// let options = parser::options::ParserOptions {
+///     allow_comments: true,
+///     ..Default::default()
+/// };
+/// let document = parser::cst::parse_lossless_with_options(input.as_bytes(), options).unwrap();
+/// assert_eq!(document.to_string(), input);
+///
+/// let object = document.value.as_object().unwrap();
+/// assert_eq!(object.get("name").unwrap().to_node(), node::Node::String("a\"c".into()));
+/// assert_eq!(object.get("size").unwrap().as_scalar().unwrap().raw(), "1.50");
+/// ```
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub struct Document {
+    /// 値の前の空白・コメント
+    pub leading: String,
+    pub value: Value,
+    /// 値の後の空白・コメント
+    pub trailing: String,
+}
+
+/// 空白・コメントを含めて入力を保持する値を表現する
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub enum Value {
+    Scalar(Scalar),
+    Array(Array),
+    Object(Object),
+}
+
+/// String型・Number型・bool型・null型の値を元の表記とともに表現する
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub struct Scalar {
+    /// 入力中の表記（文字列はエスケープと `"` を含む）
+    raw: String,
+    node: Node,
+}
+
+/// Arrayを表現する
+/// `[` leading 値 trailing `,` leading 値 ... close `]` の順に並ぶ
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub struct Array {
+    pub elements: Vec<Element>,
+    /// 最後の要素の後にカンマがあるか
+    pub trailing_comma: bool,
+    /// `]` の前の空白・コメント
+    pub close: String,
+}
+
+/// Arrayの要素を前後の空白・コメントとともに表現する
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub struct Element {
+    /// 値の前の空白・コメント
+    pub leading: String,
+    pub value: Value,
+    /// 値と `,` の間の空白・コメント（後に `,` が続かない場合は空）
+    pub trailing: String,
+}
+
+/// Objectを表現する
+/// 重複したキーも含めて、入力に現れた順にメンバーを保持する
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub struct Object {
+    pub members: Vec<Member>,
+    /// 最後のメンバーの後にカンマがあるか
+    pub trailing_comma: bool,
+    /// `}` の前の空白・コメント
+    pub close: String,
+}
+
+/// Objectのメンバーを前後の空白・コメントとともに表現する
+/// leading キー before_colon `:` after_colon 値 trailing の順に並ぶ
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub struct Member {
+    pub leading: String,
+    key: Scalar,
+    pub before_colon: String,
+    pub after_colon: String,
+    pub value: Value,
+    /// 値と `,` の間の空白・コメント（後に `,` が続かない場合は空）
+    pub trailing: String,
+}
+
+/// 文字列全体を空白・コメントを保持したまま１つの JSON文書として解析して返却する
+/// 値の後に空白・コメント以外の文字が続く場合は Error::TrailingCharacters を返却する
+pub fn parse_lossless<T>(reader: T) -> Result<Document, Error>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    parse_lossless_with_options(reader, ParserOptions::default())
+}
+
+/// 挙動を設定して、空白・コメントを保持したまま１つの JSON文書として解析して返却する
+/// ParserOptions のうち allow_comments・allow_trailing_commas・max_depth・max_bytes・top_level を評価する
+pub fn parse_lossless_with_options<T>(reader: T, options: ParserOptions) -> Result<Document, Error>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    let mut lexer = if options.allow_comments {
+        Lexer::with_comments(reader)
+    } else {
+        Lexer::new(reader)
+    };
+    lexer.set_max_bytes(options.max_bytes);
+    lexer.set_recording(true);
+
+    LosslessParser { lexer, options }.document()
+}
+
+impl Scalar {
+    /// Object・Array 以外の Node を serializer で出力した表記とともに保持する
+    fn from_node(node: Node) -> Self {
+        Self {
+            raw: node::serializer::to_string(&node),
+            node,
+        }
+    }
+
+    /// 入力中の表記を返却する
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// 表記を解釈した値を返却する
+    pub fn node(&self) -> &Node {
+        &self.node
+    }
+}
+
+impl Value {
+    /// Node から空白・コメントを持たない値を構築する
+    /// Object・Array の要素は `, ` で、Objectのキーと値は `: ` で区切る
+    pub fn from_node(node: &Node) -> Self {
+        match node {
+            Node::Array(array) => Value::Array(Array {
+                elements: array
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| Element {
+                        leading: separator(i),
+                        value: Value::from_node(value),
+                        trailing: String::new(),
+                    })
+                    .collect(),
+                trailing_comma: false,
+                close: String::new(),
+            }),
+            Node::Object(object) => Value::Object(Object {
+                members: object
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (key, value))| Member {
+                        leading: separator(i),
+                        key: Scalar::from_node(Node::String(key.to_string())),
+                        before_colon: String::new(),
+                        after_colon: " ".into(),
+                        value: Value::from_node(value),
+                        trailing: String::new(),
+                    })
+                    .collect(),
+                trailing_comma: false,
+                close: String::new(),
+            }),
+            _ => Value::Scalar(Scalar::from_node(node.clone())),
+        }
+    }
+
+    /// 値を node に置き換える
+    /// 値の前後の空白・コメントは保持する
+    pub fn set(&mut self, node: &Node) {
+        *self = Value::from_node(node);
+    }
+
+    /// JSON Pointer が指す値の可変参照を返却する
+    /// 存在しない場合や JSON Pointer として解釈できない場合は None を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let input = "{\n  \"name\": \"a\",\n  \"ports\": [80]\n}\n";
+    /// let mut document = parser::cst::parse_lossless(input.as_bytes()).unwrap();
+    /// document.value.pointer_mut("/name").unwrap().set(&node::json!("b"));
+    /// let ports = document.value.pointer_mut("/ports").unwrap();
+    /// ports.as_array_mut().unwrap().push(&node::json!(443));
+    /// let object = document.value.as_object_mut().unwrap();
+    /// object.insert("tls", &node::json!({ "enabled": true }));
+    /// assert_eq!(
+    ///     document.to_string(),
+    ///     "{\n  \"name\": \"b\",\n  \"ports\": [80, 443],\n  \"tls\": {\"enabled\": true}\n}\n"
+    /// );
+    /// ```
+    pub fn pointer_mut(&mut self, pointer: &str) -> Option<&mut Value> {
+        let pointer = pointer.parse::<node::Pointer>().ok()?;
+        pointer
+            .tokens()
+            .iter()
+            .try_fold(self, |value, token| match value {
+                Value::Array(array) => array.get_mut(node::Pointer::parse_index(token)?),
+                Value::Object(object) => object.get_mut(token),
+                Value::Scalar(_) => None,
+            })
+    }
+
+    /// Scalar の場合は参照を返却する
+    pub fn as_scalar(&self) -> Option<&Scalar> {
+        match self {
+            Value::Scalar(scalar) => Some(scalar),
+            _ => None,
+        }
+    }
+
+    /// Array の場合は参照を返却する
+    pub fn as_array(&self) -> Option<&Array> {
+        match self {
+            Value::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    /// Array の場合は可変参照を返却する
+    pub fn as_array_mut(&mut self) -> Option<&mut Array> {
+        match self {
+            Value::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    /// Object の場合は参照を返却する
+    pub fn as_object(&self) -> Option<&Object> {
+        match self {
+            Value::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// Object の場合は可変参照を返却する
+    pub fn as_object_mut(&mut self) -> Option<&mut Object> {
+        match self {
+            Value::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// 値の内側の空白・コメントも含めた入力中の表記を RawValue として返却する
+    /// allow_comments を有効にして解析した場合、コメントを含む表記は JSONとして出力できない点に注意する
+    pub fn to_raw_value(&self) -> RawValue {
+        RawValue::from_validated(self.to_string())
+    }
+
+    /// 空白・コメントを取り除いた Node に変換する
+    /// 重複したキーは後に現れた値となる
+    pub fn to_node(&self) -> Node {
+        match self {
+            Value::Scalar(scalar) => scalar.node.clone(),
+            Value::Array(array) => {
+                Node::Array(array.elements.iter().map(|e| e.value.to_node()).collect())
+            }
+            Value::Object(object) => Node::Object(
+                object
+                    .members
+                    .iter()
+                    .map(|m| (m.key().to_string(), m.value.to_node()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl Array {
+    /// index 番目の要素の値を返却する
+    pub fn get(&self, index: usize) -> Option<&Value> {
+        self.elements.get(index).map(|e| &e.value)
+    }
+
+    /// index 番目の要素の値の可変参照を返却する
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Value> {
+        self.elements.get_mut(index).map(|e| &mut e.value)
+    }
+
+    /// 末尾に要素を加える
+    /// 要素の前の空白・コメントは直前の要素に揃える
+    pub fn push(&mut self, node: &Node) {
+        let leading = match self.elements.last() {
+            Some(last) => next_leading(&last.leading),
+            None => String::new(),
+        };
+        self.elements.push(Element {
+            leading,
+            value: Value::from_node(node),
+            trailing: String::new(),
+        });
+    }
+
+    /// index 番目の要素を取り除き、その値を返却する
+    /// 範囲外の場合は None を返却する
+    pub fn remove(&mut self, index: usize) -> Option<Value> {
+        if index >= self.elements.len() {
+            return None;
+        }
+        let removed = self.elements.remove(index);
+        if index == self.elements.len()
+            && let Some(last) = self.elements.last_mut()
+        {
+            // 末尾の要素の後には `,` が続かない
+            last.trailing = removed.trailing;
+        }
+        Some(removed.value)
+    }
+}
+
+impl Object {
+    /// キーに対応する値を返却する
+    /// キーが重複している場合は to_node と同様に後に現れた値を返却する
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.members
+            .iter()
+            .rev()
+            .find(|m| m.key() == key)
+            .map(|m| &m.value)
+    }
+
+    /// キーに対応する値の可変参照を返却する
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Value> {
+        self.members
+            .iter_mut()
+            .rev()
+            .find(|m| m.key() == key)
+            .map(|m| &mut m.value)
+    }
+
+    /// キーに対応する値を node に置き換える
+    /// キーがない場合は末尾に加え、前の空白・コメントと `:` の前後の空白は直前のメンバーに揃える
+    pub fn insert(&mut self, key: &str, node: &Node) {
+        if let Some(value) = self.get_mut(key) {
+            value.set(node);
+            return;
+        }
+
+        let (leading, before_colon, after_colon) = match self.members.last() {
+            Some(last) => (
+                next_leading(&last.leading),
+                last.before_colon.clone(),
+                last.after_colon.clone(),
+            ),
+            None => (String::new(), String::new(), " ".into()),
+        };
+        self.members.push(Member {
+            leading,
+            key: Scalar::from_node(Node::String(key.into())),
+            before_colon,
+            after_colon,
+            value: Value::from_node(node),
+            trailing: String::new(),
+        });
+    }
+
+    /// キーに対応するメンバーを全て取り除き、to_node と同様に後に現れた値を返却する
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        let mut removed = None;
+        while let Some(index) = self.members.iter().rposition(|m| m.key() == key) {
+            let member = self.members.remove(index);
+            if index == self.members.len()
+                && let Some(last) = self.members.last_mut()
+            {
+                // 末尾のメンバーの後には `,` が続かない
+                last.trailing = member.trailing;
+            }
+            removed.get_or_insert(member.value);
+        }
+        removed
+    }
+}
+
+impl Member {
+    /// エスケープを解釈したキーを返却する
+    pub fn key(&self) -> &str {
+        match &self.key.node {
+            Node::String(key) => key,
+            _ => unreachable!("キーは String型のみ"),
+        }
+    }
+
+    /// 入力中のキーの表記を返却する
+    pub fn raw_key(&self) -> &str {
+        &self.key.raw
+    }
+}
+
+impl std::fmt::Display for Document {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}{}", self.leading, self.value, self.trailing)
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Scalar(scalar) => f.write_str(&scalar.raw),
+            Value::Array(array) => {
+                f.write_str("[")?;
+                for (i, element) in array.elements.iter().enumerate() {
+                    write!(f, "{}{}", element.leading, element.value)?;
+                    if i + 1 < array.elements.len() || array.trailing_comma {
+                        write!(f, "{},", element.trailing)?;
+                    }
+                }
+                write!(f, "{}]", array.close)
+            }
+            Value::Object(object) => {
+                f.write_str("{")?;
+                for (i, member) in object.members.iter().enumerate() {
+                    write!(
+                        f,
+                        "{}{}{}:{}{}",
+                        member.leading,
+                        member.key.raw,
+                        member.before_colon,
+                        member.after_colon,
+                        member.value
+                    )?;
+                    if i + 1 < object.members.len() || object.trailing_comma {
+                        write!(f, "{},", member.trailing)?;
+                    }
+                }
+                write!(f, "{}}}", object.close)
+            }
+        }
+    }
+}
+
+/// 元の表記を蓄える Lexer から Document を構築する
+struct LosslessParser<T>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    lexer: Lexer<T>,
+    options: ParserOptions,
+}
+
+impl<T> LosslessParser<T>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    fn document(mut self) -> Result<Document, Error> {
+        let (leading, token, raw) = self.next()?;
+        if token.data == Data::EOF {
+            return Err(syntax_error(&token, Kind::VALUE, "値がありません"));
+        }
+        if self.options.top_level == crate::options::TopLevel::ObjectOrArray
+            && !matches!(token.data, Data::LeftBrace | Data::LeftBracket)
+        {
+            return Err(syntax_error(
+                &token,
+                &[Kind::LeftBrace, Kind::LeftBracket],
+                "最上位の値はObjectかArrayでなければなりません",
+            ));
+        }
+
+        let value = self.value(token, raw, 0)?;
+        let (trailing, token, raw) = self.next()?;
+        match token.data {
+            Data::EOF => Ok(Document {
+                leading,
+                value,
+                trailing,
+            }),
+            _ => Err(Error::TrailingCharacters(
+                token.line,
+                token.pos,
+                raw.chars().next().unwrap_or_default(),
+            )),
+        }
+    }
+
+    /// 前の空白・コメント、トークン、トークンの表記を読み出す
+    fn next(&mut self) -> Result<(String, Token, String), Error> {
+        let next = self.lexer.skip_whitespace()?;
+        let leading = self.lexer.take_recorded();
+
+        // Lexer が読み飛ばす文字は保持できないためエラーとする
+        if let Some((c, line, pos)) = next
+            && !crate::lexer::starts_token(c)
+        {
+            return Err(crate::lexer::error::Error::InvalidToken(
+                c.to_string(),
+                line..line,
+                pos..pos,
+            )
+            .into());
+        }
+
+        let token = self.lexer.read()?;
+        let raw = self.lexer.take_recorded();
+        Ok((leading, token, raw))
+    }
+
+    fn value(&mut self, token: Token, raw: String, depth: usize) -> Result<Value, Error> {
+        let node = match token.data {
+            Data::LeftBracket => return self.array(token, depth + 1).map(Value::Array),
+            Data::LeftBrace => return self.object(token, depth + 1).map(Value::Object),
+            Data::String(value) => Node::String(value),
+            Data::Number(value) => Node::Number(value),
+            Data::True => Node::Bool(true),
+            Data::False => Node::Bool(false),
+            Data::Null => Node::Null,
+            _ => {
+                return Err(syntax_error(
+                    &token,
+                    Kind::VALUE,
+                    "bool型・null型・String型・Number型・Object・Arrayのいずれかでなければなりません",
+                ));
+            }
+        };
+
+        Ok(Value::Scalar(Scalar { raw, node }))
+    }
+
+    fn enter(&self, token: &Token, depth: usize) -> Result<(), Error> {
+        if depth > self.options.max_depth {
+            Err(Error::DepthLimitExceeded(
+                token.line.clone(),
+                token.pos.clone(),
+                self.options.max_depth,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn array(&mut self, open: Token, depth: usize) -> Result<Array, Error> {
+        self.enter(&open, depth)?;
+        let mut elements = vec![];
+
+        loop {
+            let (leading, token, raw) = self.next()?;
+            if token.data == Data::RightBracket
+                && (elements.is_empty() || self.options.allow_trailing_commas)
+            {
+                return Ok(Array {
+                    trailing_comma: !elements.is_empty(),
+                    elements,
+                    close: leading,
+                });
+            }
+
+            let value = self.value(token, raw, depth)?;
+            let (trailing, token, _) = self.next()?;
+            match token.data {
+                Data::Comma => elements.push(Element {
+                    leading,
+                    value,
+                    trailing,
+                }),
+                Data::RightBracket => {
+                    elements.push(Element {
+                        leading,
+                        value,
+                        trailing: String::new(),
+                    });
+                    return Ok(Array {
+                        elements,
+                        trailing_comma: false,
+                        close: trailing,
+                    });
+                }
+                _ => {
+                    return Err(syntax_error(
+                        &token,
+                        &[Kind::Comma, Kind::RightBracket],
+                        "Arrayの要素の後は `,` か `]` でなければなりません",
+                    ));
+                }
+            }
+        }
+    }
+
+    fn object(&mut self, open: Token, depth: usize) -> Result<Object, Error> {
+        self.enter(&open, depth)?;
+        let mut members = vec![];
+
+        loop {
+            let (leading, token, raw) = self.next()?;
+            let key = match token.data {
+                Data::RightBrace if members.is_empty() || self.options.allow_trailing_commas => {
+                    return Ok(Object {
+                        trailing_comma: !members.is_empty(),
+                        members,
+                        close: leading,
+                    });
+                }
+                Data::String(key) => Scalar {
+                    raw,
+                    node: Node::String(key),
+                },
+                _ => {
+                    return Err(syntax_error(
+                        &token,
+                        &[Kind::String],
+                        "ObjectのキーはString型でなければなりません",
+                    ));
+                }
+            };
+
+            let (before_colon, token, _) = self.next()?;
+            if token.data != Data::Colon {
+                return Err(syntax_error(
+                    &token,
+                    &[Kind::Colon],
+                    "Objectのキーの後は`:`でなければなりません",
+                ));
+            }
+
+            let (after_colon, token, raw) = self.next()?;
+            let value = self.value(token, raw, depth)?;
+            let (trailing, token, _) = self.next()?;
+            let mut member = Member {
+                leading,
+                key,
+                before_colon,
+                after_colon,
+                value,
+                trailing,
+            };
+            match token.data {
+                Data::Comma => members.push(member),
+                Data::RightBrace => {
+                    let close = std::mem::take(&mut member.trailing);
+                    members.push(member);
+                    return Ok(Object {
+                        members,
+                        trailing_comma: false,
+                        close,
+                    });
+                }
+                _ => {
+                    return Err(syntax_error(
+                        &token,
+                        &[Kind::Comma, Kind::RightBrace],
+                        "Objectの解析の継続（`,`）、終了（`}`）のいずれもでありません",
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// from_node で構築する i 番目の要素の前の空白
+fn separator(i: usize) -> String {
+    if i == 0 { String::new() } else { " ".into() }
+}
+
+/// 直前の要素の前の空白・コメントから、後に加える要素の前の空白を決める
+/// `[1]` のように直前の要素の前に何もない場合は `, ` で区切る
+fn next_leading(last: &str) -> String {
+    if last.is_empty() {
+        " ".into()
+    } else {
+        last.into()
+    }
+}
+
+fn syntax_error(token: &Token, expected: &[Kind], message: &str) -> Error {
+    Error::UnexpectedToken {
+        line: token.line.clone(),
+        pos: token.pos.clone(),
+        expected: expected.to_vec(),
+        found: token.data.kind(),
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[rstest::rstest]
+    #[case("1")]
+    #[case("  \"a\\n\\u3042\"\n")]
+    #[case("[]")]
+    #[case("[ ]")]
+    #[case("{}")]
+    #[case("[1 , 2.50,-3e1 ,true,false , null]")]
+    #[case("{ \"b\" : 1 , \"a\":[ {} ,[ ] ] }\n")]
+    #[case("/* head */ {\n  // comment\n  \"a\": 1, /* x */\n  \"b\": [1, 2,], // y\n}\n// tail")]
+    fn test_round_trip(#[case] input: &str) {
+        let options = ParserOptions {
+            allow_comments: true,
+            allow_trailing_commas: true,
+            ..Default::default()
+        };
+        let document = parse_lossless_with_options(input.as_bytes(), options).unwrap();
+        assert_eq!(document.to_string(), input);
+        assert_eq!(
+            document.value.to_node(),
+            crate::Parser::with_options(
+                input.as_bytes(),
+                ParserOptions {
+                    allow_comments: true,
+                    allow_trailing_commas: true,
+                    ..Default::default()
+                }
+            )
+            .parse()
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_unescape() {
+        // 表記はエスケープのまま保持し、値はエスケープを解釈する
+        let document = parse_lossless(r#"{"k\t": "a\n"}"#.as_bytes()).unwrap();
+        let object = document.value.as_object().unwrap();
+        assert_eq!(object.members[0].key(), "k\t");
+        assert_eq!(object.members[0].raw_key(), r#""k\t""#);
+        let value = object.get("k\t").unwrap().as_scalar().unwrap();
+        assert_eq!(value.raw(), r#""a\n""#);
+        assert_eq!(value.node(), &Node::String("a\n".into()));
+    }
+
+    #[test]
+    fn test_edit() {
+        let input = "{\n  \"a\": 1,\n  \"b\": \"x\" // keep\n}";
+        let options = ParserOptions {
+            allow_comments: true,
+            ..Default::default()
+        };
+        let mut document = parse_lossless_with_options(input.as_bytes(), options).unwrap();
+        let object = document.value.as_object_mut().unwrap();
+        assert_eq!(object.members[1].raw_key(), "\"b\"");
+        *object.get_mut("a").unwrap() = Value::Array(Array {
+            elements: vec![],
+            trailing_comma: false,
+            close: String::new(),
+        });
+        assert_eq!(
+            document.to_string(),
+            "{\n  \"a\": [],\n  \"b\": \"x\" // keep\n}"
+        );
+    }
+
+    #[test]
+    fn test_write_back() {
+        let input = "// config\n{\n  \"a\" : 1, // one\n  \"b\" : [ 1, 2 ],\n  \"c\" : \"x\"\n}\n";
+        let options = ParserOptions {
+            allow_comments: true,
+            ..Default::default()
+        };
+        let mut document = parse_lossless_with_options(input.as_bytes(), options).unwrap();
+        let object = document.value.as_object_mut().unwrap();
+        object.insert("a", &node::json!(null));
+        object.insert("d", &node::json!({ "e": [true], "f": {} }));
+        assert!(object.remove("c").is_some());
+        assert!(object.remove("z").is_none());
+        let array = object.get_mut("b").unwrap().as_array_mut().unwrap();
+        array.push(&node::json!("s"));
+        assert!(array.remove(0).is_some());
+        assert_eq!(
+            document.to_string(),
+            "// config\n{\n  \"a\" : null, // one\n  \"b\" : [ 2, \"s\" ],\n  \"d\" : {\"e\": [true], \"f\": {}}\n}\n"
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("[1 , 2 ]", 1, "[1 ]")]
+    #[case("[1 , 2 ]", 0, "[ 2 ]")]
+    #[case("[1]", 0, "[]")]
+    fn test_array_remove(#[case] input: &str, #[case] index: usize, #[case] expected: &str) {
+        let mut document = parse_lossless(input.as_bytes()).unwrap();
+        document.value.as_array_mut().unwrap().remove(index);
+        assert_eq!(document.to_string(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case("{}", r#"{"k": "a\"b"}"#)]
+    #[case(r#"{"x":1}"#, r#"{"x":1, "k":"a\"b"}"#)]
+    fn test_insert(#[case] input: &str, #[case] expected: &str) {
+        let mut document = parse_lossless(input.as_bytes()).unwrap();
+        let object = document.value.as_object_mut().unwrap();
+        object.insert("k", &node::json!("a\"b"));
+        assert_eq!(document.to_string(), expected);
+        assert_eq!(
+            document.value.pointer_mut("/k").unwrap().to_node(),
+            node::json!("a\"b")
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("", "値がありません")]
+    #[case(
+        "[1,]",
+        "bool型・null型・String型・Number型・Object・Arrayのいずれかでなければなりません"
+    )]
+    #[case("{\"a\" 1}", "Objectのキーの後は`:`でなければなりません")]
+    #[case("{1: 1}", "ObjectのキーはString型でなければなりません")]
+    #[case("[1 2]", "Arrayの要素の後は `,` か `]` でなければなりません")]
+    fn test_syntax_error(#[case] input: &str, #[case] expected: &str) {
+        match parse_lossless(input.as_bytes()) {
+            Err(Error::UnexpectedToken { message, .. }) => assert_eq!(message, expected),
+            result => panic!("{result:?}"),
+        }
+    }
+
+    #[rstest::rstest]
+    #[case("1 2")]
+    #[case("[1] x")]
+    #[case("// c\n1")]
+    fn test_invalid(#[case] input: &str) {
+        assert!(parse_lossless(input.as_bytes()).is_err());
+    }
+}
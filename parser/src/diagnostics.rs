@@ -0,0 +1,111 @@
+use crate::Error;
+
+impl Error {
+    /// エラーが発生した行と位置を返却する
+    /// 位置を持たないエラー（reader のエラーなど）は None を返却する
+    pub fn span(&self) -> Option<(std::ops::Range<usize>, std::ops::Range<usize>)> {
+        match self {
+            Error::SyntaxError(line, pos, _)
+            | Error::UnexpectedToken { line, pos, .. }
+            | Error::DuplicateKey(line, pos, _)
+            | Error::DepthLimitExceeded(line, pos, _)
+            | Error::TrailingCharacters(line, pos, _)
+            | Error::InvalidToken(line, pos, _)
+            | Error::NodeLimitExceeded(line, pos, _)
+            | Error::StringLimitExceeded(line, pos, _) => Some((line.clone(), pos.clone())),
+            _ => None,
+        }
+    }
+
+    /// 解析した入力の該当行を引用し、エラーの範囲に `^` を付けたメッセージを返却する
+    /// 位置は文字数で数えるため、全角文字を含む行では `^` が表示上ずれることがある
+    /// 位置を持たないエラーや source に該当行がない場合はメッセージのみを返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let source = "{\n  \"a\": tru\n}";
+    /// let error = parser::Parser::new(source.as_bytes()).parse().unwrap_err();
+    /// let rendered = error.render(source);
+    /// let lines = rendered.lines().collect::<Vec<_>>();
+    /// assert_eq!(lines[1], " --> 2:8");
+    /// assert_eq!(lines[3], "2 |   \"a\": tru");
+    /// assert_eq!(lines[4], "  |        ^^^^");
+    /// ```
+    pub fn render(&self, source: &str) -> String {
+        let message = format!("error: {self}");
+        let Some((line, pos)) = self.span() else {
+            return message;
+        };
+        let Some(text) = source.lines().nth(line.start.saturating_sub(1)) else {
+            return message;
+        };
+
+        let start = pos.start.max(1);
+        let end = if line.end > line.start {
+            text.chars().count().max(start)
+        } else {
+            pos.end.max(start)
+        };
+
+        // タブはそのまま残して `^` の位置を揃える
+        let indent = text
+            .chars()
+            .chain(std::iter::repeat(' '))
+            .take(start - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+        let number = line.start.to_string();
+        let gutter = " ".repeat(number.len());
+
+        format!(
+            "{message}\n{gutter}--> {}:{start}\n{gutter} |\n{number} | {text}\n{gutter} | {indent}{}",
+            line.start,
+            "^".repeat(end - start + 1)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Parser;
+    use crate::options::ParserOptions;
+    use pretty_assertions::assert_eq;
+
+    #[rstest::rstest]
+    #[case(
+        "[1,\n\t2 3]",
+        "error: 行: 2..2 位置: 4..4 で構文エラーが発生しました（Arrayの要素の後は `,` か `]` でなければなりません）\n --> 2:4\n  |\n2 | \t2 3]\n  | \t  ^"
+    )]
+    #[case(
+        "[1",
+        "error: 行: 1..1 位置: 2..2 で構文エラーが発生しました（Arrayの要素の後は `,` か `]` でなければなりません）\n --> 1:2\n  |\n1 | [1\n  |  ^"
+    )]
+    #[case(
+        "{\"a\": 1, \"a\": 2}",
+        "error: 行: 1..1 位置: 10..12 でObjectのキー `a` が重複しています\n --> 1:10\n  |\n1 | {\"a\": 1, \"a\": 2}\n  |          ^^^"
+    )]
+    #[case(
+        "[tru]",
+        "error: 行: 1..1 位置: 2..5 で`true` トークンの解釈に失敗しました\n --> 1:2\n  |\n1 | [tru]\n  |  ^^^^"
+    )]
+    fn test_render(#[case] source: &str, #[case] expected: &str) {
+        let options = ParserOptions {
+            duplicate_keys: crate::options::DuplicateKeys::Error,
+            ..Default::default()
+        };
+        let error = Parser::with_options(source.as_bytes(), options)
+            .parse()
+            .unwrap_err();
+        assert_eq!(error.render(source), expected);
+    }
+
+    #[test]
+    fn test_render_without_span() {
+        let error = crate::Error::SizeLimitExceeded(8);
+        assert_eq!(
+            error.render("[]"),
+            "error: 入力が上限（8バイト）を超えました"
+        );
+    }
+}
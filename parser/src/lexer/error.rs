@@ -7,8 +7,14 @@ pub enum Error {
     EOF(usize, usize),
     #[error("Line: {0:?} Position: {1:?} 文字列の終了の前に末尾に到達しました")]
     UnclosedStringLiteral(std::ops::Range<usize>, std::ops::Range<usize>),
+    #[error("Line: {0:?} Position: {1:?} 文字列のエスケープを解釈できません")]
+    InvalidEscape(std::ops::Range<usize>, std::ops::Range<usize>),
+    #[error("Line: {0:?} Position: {1:?} コメントの終了の前に末尾に到達しました")]
+    UnclosedComment(std::ops::Range<usize>, std::ops::Range<usize>),
     #[error("{0}")]
     ReaderError(String),
+    #[error("入力が上限（{0}バイト）を超えました")]
+    SizeLimitExceeded(usize),
     #[error("Line: {1:?} Position: {2:?} `{0}` トークンの解釈に失敗しました")]
     InvalidToken(String, std::ops::Range<usize>, std::ops::Range<usize>),
     #[error("Line: {1:?} Position: {2:?} `number` トークンとして解釈できませんでした（{0}） ")]
@@ -17,6 +23,11 @@ pub enum Error {
 
 impl From<char_reader::error::Error> for Error {
     fn from(value: char_reader::error::Error) -> Self {
-        Self::ReaderError(value.to_string())
+        match value {
+            char_reader::error::Error::SizeLimitExceeded(max_bytes) => {
+                Self::SizeLimitExceeded(max_bytes)
+            }
+            _ => Self::ReaderError(value.to_string()),
+        }
     }
 }
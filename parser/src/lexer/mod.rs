@@ -26,7 +26,7 @@ impl Token {
 #[derive(std::fmt::Debug, PartialEq)]
 pub enum Data {
     String(String),
-    Number(f64),
+    Number(node::Number),
     True,
     False,
     Null,
@@ -39,6 +39,76 @@ pub enum Data {
     EOF,
 }
 
+impl Data {
+    /// トークンの種別を返却する
+    pub fn kind(&self) -> Kind {
+        match self {
+            Data::String(_) => Kind::String,
+            Data::Number(_) => Kind::Number,
+            Data::True => Kind::True,
+            Data::False => Kind::False,
+            Data::Null => Kind::Null,
+            Data::Colon => Kind::Colon,
+            Data::Comma => Kind::Comma,
+            Data::LeftBracket => Kind::LeftBracket,
+            Data::RightBracket => Kind::RightBracket,
+            Data::LeftBrace => Kind::LeftBrace,
+            Data::RightBrace => Kind::RightBrace,
+            Data::EOF => Kind::EOF,
+        }
+    }
+}
+
+/// トークンの種別を値を持たずに表す
+/// 構文エラーで期待されたトークンと実際のトークンを表現するために利用する
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    String,
+    Number,
+    True,
+    False,
+    Null,
+    Colon,
+    Comma,
+    LeftBracket,
+    RightBracket,
+    LeftBrace,
+    RightBrace,
+    EOF,
+}
+
+impl Kind {
+    /// 値の開始となるトークンの種別
+    pub const VALUE: &[Kind] = &[
+        Kind::LeftBrace,
+        Kind::LeftBracket,
+        Kind::String,
+        Kind::Number,
+        Kind::True,
+        Kind::False,
+        Kind::Null,
+    ];
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kind::String => write!(f, "string"),
+            Kind::Number => write!(f, "number"),
+            Kind::True => write!(f, "true"),
+            Kind::False => write!(f, "false"),
+            Kind::Null => write!(f, "null"),
+            Kind::Colon => write!(f, "`:`"),
+            Kind::Comma => write!(f, "`,`"),
+            Kind::LeftBracket => write!(f, "`[`"),
+            Kind::RightBracket => write!(f, "`]`"),
+            Kind::LeftBrace => write!(f, "`{{`"),
+            Kind::RightBrace => write!(f, "`}}`"),
+            Kind::EOF => write!(f, "EOF"),
+        }
+    }
+}
+
 /// std::io::BufRead から UTF-8 を１文字ずつ読み取り、JSONトークンを返却する
 /// 文法の評価はしないが、データ型に違反している場合はエラーを返す（数値リテラルなのに数値として解釈できない: Error::InvalidNumber）
 ///
@@ -98,9 +168,19 @@ pub enum Data {
 /// ```
 ///
 #[allow(dead_code)]
-pub struct Lexer<T>(CharReader<T>)
+pub struct Lexer<T>
 where
-    T: std::io::BufRead + std::fmt::Debug;
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    reader: CharReader<T>,
+    allow_comments: bool,
+    /// true の場合は文字列トークンの内容を保持しない
+    discard: bool,
+    /// 数値トークンの解釈に使い回すバッファ
+    number: String,
+    /// 直前に生成した文字列トークンの入力中のバイト範囲（ダブルクォートを含む）
+    string_bytes: std::ops::Range<usize>,
+}
 
 #[allow(dead_code)]
 impl<T> Lexer<T>
@@ -109,7 +189,66 @@ where
 {
     /// トークナイザーを生成して返却する
     pub fn new(reader: T) -> Self {
-        Self(CharReader::new(reader))
+        Self {
+            reader: CharReader::new(reader),
+            allow_comments: false,
+            discard: false,
+            number: String::new(),
+            string_bytes: 0..0,
+        }
+    }
+
+    /// `// ...` と `/* ... */` のコメントを読み飛ばすトークナイザーを生成して返却する
+    pub fn with_comments(reader: T) -> Self {
+        Self {
+            reader: CharReader::new(reader),
+            allow_comments: true,
+            discard: false,
+            number: String::new(),
+            string_bytes: 0..0,
+        }
+    }
+
+    /// 内部の reader への可変参照を返却する
+    pub(crate) fn get_mut(&mut self) -> &mut T {
+        self.reader.get_mut()
+    }
+
+    /// reader を差し替え、先頭からトークンを生成し直す
+    /// 差し替える前の reader を返却する
+    pub fn reset(&mut self, reader: T) -> T {
+        self.discard = false;
+        self.number.clear();
+        self.string_bytes = 0..0;
+        self.reader.reset(reader)
+    }
+
+    /// reader から読み出せるバイト数の上限を設定する
+    /// 上限を超えて読み出すと Error::SizeLimitExceeded を返却する
+    pub fn set_max_bytes(&mut self, max_bytes: Option<usize>) {
+        self.reader.set_max_bytes(max_bytes);
+    }
+
+    /// true の場合、以降に読み出した文字（空白・コメントを含む）を元の表記のまま蓄える
+    pub fn set_recording(&mut self, recording: bool) {
+        self.reader.set_recording(recording);
+    }
+
+    /// 蓄えた文字を取り出して返却する
+    pub fn take_recorded(&mut self) -> String {
+        self.reader.take_recorded()
+    }
+
+    /// true の場合、以降の文字列トークンは内容を保持せず空の Data::String となる
+    /// 値を読み飛ばす場合にトークンごとのメモリ確保を避けるために利用する
+    pub fn set_discard(&mut self, discard: bool) {
+        self.discard = discard;
+    }
+
+    /// 直前に生成した文字列トークンの入力中のバイト範囲（ダブルクォートを含む）を返却する
+    /// set_discard で内容を保持しない場合でも、入力から文字列を切り出すために利用できる
+    pub fn string_bytes(&self) -> std::ops::Range<usize> {
+        self.string_bytes.clone()
     }
 
     fn discard_next(&mut self) -> (char, usize, usize) {
@@ -137,6 +276,10 @@ where
                     '}' => self.parse_delimiter::<'}'>(),
                     '[' => self.parse_delimiter::<'['>(),
                     ']' => self.parse_delimiter::<']'>(),
+                    '/' if self.allow_comments => {
+                        // コメントを読み飛ばして次のトークンの処理を呼び出す
+                        self.skip_comment().and_then(|_| self.read())
+                    }
                     // それ以外の文字は読み飛ばす
                     _ => {
                         // ピーク分を破棄する
@@ -148,38 +291,125 @@ where
 
                 match result {
                     Err(Error::EOF(line, pos)) => Ok(Token::new(line..line, pos..pos, Data::EOF)),
-                    Err(e) => return Err(e),
+                    Err(e) => Err(e),
                     Ok(token) => Ok(token),
                 }
             }
         }
     }
 
+    /// 空白（コメントが許可されている場合はコメントも）を読み飛ばし、最初に現れた文字を読み出さずに返却する
+    /// reader の末尾に到達した場合は None を返却する
+    pub fn skip_whitespace(&mut self) -> Result<Option<(char, usize, usize)>, Error> {
+        loop {
+            let peek = self.peek().cloned();
+
+            match peek {
+                Err(Error::EOF(_, _)) => return Ok(None),
+                Err(e) => return Err(e),
+                Ok((' ' | '\t' | '\n' | '\r', _, _)) => {
+                    self.discard_next();
+                }
+                Ok(('/', _, _)) if self.allow_comments => match self.skip_comment() {
+                    Err(Error::EOF(_, _)) => return Ok(None),
+                    result => result?,
+                },
+                Ok(found) => {
+                    self.peek_back()?;
+                    return Ok(Some(found));
+                }
+            }
+        }
+    }
+
+    /// トークン生成に失敗した後、失敗したトークンの残りを区切り文字（空白・`,`・`:`・括弧・`"`）の手前まで読み飛ばす
+    /// 以降の read は区切り文字からトークンの生成を再開する
+    pub fn recover(&mut self) -> Result<(), Error> {
+        // 失敗したトークンの解釈中に peek した文字を読み直す
+        while self.reader.peek_back().is_ok() {}
+
+        loop {
+            match self.peek() {
+                Err(Error::EOF(_, _)) => return Ok(()),
+                Err(e) => return Err(e),
+                Ok((' ' | '\t' | '\n' | '\r' | ',' | ':' | '{' | '}' | '[' | ']' | '"', _, _)) => {
+                    return self.peek_back();
+                }
+                Ok(_) => {
+                    self.discard_next();
+                }
+            }
+        }
+    }
+
     fn next(&mut self) -> Result<(char, usize, usize), Error> {
-        self.0.read().map_err(|e| match e {
+        self.reader.read().map_err(|e| match e {
             char_reader::error::Error::EOF(line, pos) => Error::EOF(line, pos),
             _ => Error::from(e),
         })
     }
 
     fn peek(&mut self) -> Result<&(char, usize, usize), Error> {
-        self.0.peek().map_err(|e| match e {
+        self.reader.peek().map_err(|e| match e {
             char_reader::error::Error::EOF(line, pos) => Error::EOF(line, pos),
             _ => Error::from(e),
         })
     }
 
     fn peek_back(&mut self) -> Result<(), Error> {
-        self.0.peek_back().map_err(Error::from)
+        self.reader.peek_back().map_err(Error::from)
+    }
+
+    fn skip_comment(&mut self) -> Result<(), Error> {
+        // コメント開始位置のスラッシュを読み捨て
+        let (_, initial_line, initial_pos) = self.discard_next();
+        let (c, line, pos) = self.next()?;
+
+        match c {
+            '/' => loop {
+                // 改行は次の read で読み飛ばされる
+                if self.peek()?.0 == '\n' {
+                    break self.peek_back();
+                }
+                self.discard_next();
+            },
+            '*' => {
+                let mut prev = ' ';
+
+                loop {
+                    let (c, _, _) = self.next().map_err(|e| match e {
+                        Error::EOF(line, pos) => {
+                            Error::UnclosedComment(initial_line..line, initial_pos..pos)
+                        }
+                        _ => e,
+                    })?;
+
+                    if prev == '*' && c == '/' {
+                        break Ok(());
+                    }
+                    prev = c;
+                }
+            }
+            _ => Err(Error::InvalidToken(
+                "comment".into(),
+                initial_line..line,
+                initial_pos..pos,
+            )),
+        }
     }
 
     fn parse_string(&mut self) -> Result<Token, Error> {
-        let mut buf = Vec::new();
+        // 文字を直接 String へ UTF-8 で書き込み、Vec<char> を経由しない
+        let mut buf = String::new();
+        let start = self.reader.offset();
 
         // トークン開始位置のダブルクォートを読み捨て
         let (_, initial_line, initial_pos) = self.discard_next();
         let final_line: usize;
         let final_pos: usize;
+        // 解釈できないエスケープの範囲
+        // 後続の解析を再開できるよう、閉じるダブルクォートまで読み進めてからエラーを返却する
+        let mut invalid = None;
 
         loop {
             let (c, _, _) = self.peek().map_err(|e| match e {
@@ -198,41 +428,66 @@ where
                     break;
                 }
                 '\\' => {
-                    // バッククォート読み捨て
-                    self.discard_next();
+                    // バックスラッシュ読み捨て
+                    let (_, escape_line, escape_pos) = self.discard_next();
+                    let unclosed = |e: Error| match e {
+                        Error::EOF(line, pos) => {
+                            Error::UnclosedStringLiteral(initial_line..line, initial_pos..pos)
+                        }
+                        _ => e,
+                    };
 
                     // match の評価をせずに１文字読み込む
-                    let result = self.next();
-
-                    if let Err(Error::EOF(line, pos)) = result {
-                        return Err(Error::UnclosedStringLiteral(
-                            initial_line..line,
-                            initial_pos..pos,
-                        ));
+                    let (c, mut last_line, mut last_pos) = self.next().map_err(unclosed)?;
+
+                    // `\u` の16進数は閉じるダブルクォートを読み進めないよう peek で確認してから読み出す
+                    let unescaped = unescape(c, || {
+                        let (c, line, pos) = *self.peek().map_err(unclosed)?;
+                        if c == '"' {
+                            self.peek_back()?;
+                        } else {
+                            self.discard_next();
+                            (last_line, last_pos) = (line, pos);
+                        }
+                        Ok::<_, Error>(c)
+                    })?;
+
+                    match unescaped {
+                        Some(c) if !self.discard => buf.push(c),
+                        Some(_) => {}
+                        None => {
+                            invalid.get_or_insert((escape_line..last_line, escape_pos..last_pos));
+                        }
                     }
-
-                    buf.push(result?.0);
                 }
                 _ => {
-                    buf.push(self.next().expect("peekと内容が異なる").0);
+                    let (c, _, _) = self.next().expect("peekと内容が異なる");
+                    if !self.discard {
+                        buf.push(c);
+                    }
                 }
             }
         }
 
+        if let Some((line, pos)) = invalid {
+            return Err(Error::InvalidEscape(line, pos));
+        }
+
+        self.string_bytes = start..self.reader.offset();
         Ok(Token::new(
             initial_line..final_line,
             initial_pos..final_pos,
-            Data::String(buf.into_iter().collect::<String>()),
+            Data::String(buf),
         ))
     }
 
     fn parse_number(&mut self) -> Result<Token, Error> {
-        let mut buf = Vec::new();
         let (c, initial_line, initial_position) = self.next().expect("peekと内容が異なる");
         let mut final_line = initial_line;
         let mut final_position = initial_position;
 
-        buf.push(c);
+        self.number.clear();
+        self.number.push(c);
 
         loop {
             let result = self.peek();
@@ -249,15 +504,14 @@ where
                     let (c, line, pos) = self.next().expect("peekと内容が異なる");
                     final_line = line;
                     final_position = pos;
-                    buf.push(c);
+                    self.number.push(c);
                 }
                 _ => break self.peek_back()?,
             }
         }
 
-        buf.into_iter()
-            .collect::<String>()
-            .parse::<f64>()
+        self.number
+            .parse::<node::Number>()
             .map_err(|e| {
                 Error::InvalidNumber(
                     e.to_string(),
@@ -265,11 +519,11 @@ where
                     initial_position..final_position,
                 )
             })
-            .map(|f| {
+            .map(|n| {
                 Token::new(
                     initial_line..final_line,
                     initial_position..final_position,
-                    Data::Number(f),
+                    Data::Number(n),
                 )
             })
     }
@@ -296,7 +550,7 @@ where
             }
         }
 
-        self.0
+        self.reader
             .consume(source.len())
             .map(|_| {
                 Token::new(
@@ -325,12 +579,75 @@ where
     }
 }
 
+/// トークンの先頭になり得る文字かを返却する
+/// Lexer::read はそれ以外の文字を読み飛ばすため、元の表記を保持する場合はエラーとするために利用する
+pub(crate) fn starts_token(c: char) -> bool {
+    matches!(
+        c,
+        '"' | '-' | '0'..='9' | 't' | 'f' | 'n' | ':' | ',' | '[' | ']' | '{' | '}'
+    )
+}
+
+/// `\` に続く文字 c からエスケープが表す文字を返却する
+/// `\u` の16進数や、サロゲートペアの下位の `\uXXXX` は next で１文字ずつ読み出す
+/// 解釈できないエスケープの場合は None を返却する
+pub(crate) fn unescape<E>(
+    c: char,
+    mut next: impl FnMut() -> Result<char, E>,
+) -> Result<Option<char>, E> {
+    let c = match c {
+        '"' | '\\' | '/' => c,
+        'b' => '\u{08}',
+        'f' => '\u{0C}',
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        'u' => {
+            let Some(high) = hex4(&mut next)? else {
+                return Ok(None);
+            };
+            if !(0xD800..=0xDBFF).contains(&high) {
+                // 対になっていない下位サロゲートは char::from_u32 が None を返却する
+                return Ok(char::from_u32(high));
+            }
+
+            if next()? != '\\' || next()? != 'u' {
+                return Ok(None);
+            }
+            return Ok(match hex4(&mut next)? {
+                Some(low @ 0xDC00..=0xDFFF) => {
+                    char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+                }
+                _ => None,
+            });
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(c))
+}
+
+/// 4桁の16進数を読み出す
+fn hex4<E>(next: &mut impl FnMut() -> Result<char, E>) -> Result<Option<u32>, E> {
+    let mut value = 0;
+    for _ in 0..4 {
+        match next()?.to_digit(16) {
+            Some(digit) => value = value * 16 + digit,
+            None => return Ok(None),
+        }
+    }
+
+    Ok(Some(value))
+}
+
 #[cfg(test)]
 mod tests {
     use std::io::Cursor;
 
     use super::*;
 
+    // 3.14159 は円周率の近似ではなく小数の例として使う
+    #[allow(clippy::approx_constant)]
     #[test]
     fn test_lexer() {
         let input = r#"
@@ -389,19 +706,19 @@ mod tests {
                 Data::Comma,
                 Data::String("number_integer".into()),
                 Data::Colon,
-                Data::Number(42.0_f64),
+                Data::Number(42.into()),
                 Data::Comma,
                 Data::String("number_negative".into()),
                 Data::Colon,
-                Data::Number(-123.0_f64),
+                Data::Number((-123).into()),
                 Data::Comma,
                 Data::String("number_float".into()),
                 Data::Colon,
-                Data::Number(3.14159_f64),
+                Data::Number(3.14159.into()),
                 Data::Comma,
                 Data::String("number_exponent".into()),
                 Data::Colon,
-                Data::Number(12300.0_f64),
+                Data::Number(12300.0.into()),
                 Data::Comma,
                 Data::String("boolean_true".into()),
                 Data::Colon,
@@ -420,7 +737,7 @@ mod tests {
                 Data::LeftBracket,
                 Data::String("text".into()),
                 Data::Comma,
-                Data::Number(123.0_f64),
+                Data::Number(123.into()),
                 Data::Comma,
                 Data::False,
                 Data::Comma,
@@ -442,7 +759,7 @@ mod tests {
                 Data::Comma,
                 Data::String("key2".into()),
                 Data::Colon,
-                Data::Number(2.0_f64),
+                Data::Number(2.into()),
                 Data::Comma,
                 Data::String("key3".into()),
                 Data::Colon,
@@ -456,6 +773,10 @@ mod tests {
     #[rstest::rstest]
     #[case("\"boon\"", Token::new(1..1, 1..6, Data::String("boon".into())))]
     #[case(r#""\"english\"""#, Token::new(1..1, 1..13, Data::String(r#""english""#.into())))]
+    #[case(r#""a\nb\t\/\\""#, Token::new(1..1, 1..12, Data::String("a\nb\t/\\".into())))]
+    #[case(r#""\b\f\r""#, Token::new(1..1, 1..8, Data::String("\u{08}\u{0C}\r".into())))]
+    #[case(r#""\u00e9\u0001""#, Token::new(1..1, 1..14, Data::String("é\u{1}".into())))]
+    #[case(r#""\ud83d\ude00""#, Token::new(1..1, 1..14, Data::String("😀".into())))] // サロゲートペア
     fn test_parse_string(#[case] input: &str, #[case] expected: Token) {
         let cursor = Cursor::new(input);
         let buf_reader = std::io::BufReader::new(cursor);
@@ -470,12 +791,13 @@ mod tests {
     }
 
     #[rstest::rstest]
-    #[case("123", Token::new(1..1, 1..3, Data::Number(123_f64)))] // 整数
-    #[case("-123", Token::new(1..1, 1..4, Data::Number(-123_f64)))] // 負の整数
-    #[case("3.14", Token::new(1..1, 1..4, Data::Number(3.14_f64)))] // 小数
-    #[case("-0.01", Token::new(1..1, 1..5, Data::Number(-0.01_f64)))] // 負の小数
-    #[case("1e6", Token::new(1..1, 1..3, Data::Number(1e6_f64)))] // 指数表記（10^6）
-    #[case("-2.5E-3", Token::new(1..1, 1..7, Data::Number(-2.5E-3_f64)))] // 指数付き小数
+    #[case("123", Token::new(1..1, 1..3, Data::Number(123.into())))] // 整数
+    #[case("-123", Token::new(1..1, 1..4, Data::Number((-123).into())))] // 負の整数
+    #[case("3.14", Token::new(1..1, 1..4, Data::Number(3.14.into())))] // 小数
+    #[case("-0.01", Token::new(1..1, 1..5, Data::Number((-0.01).into())))] // 負の小数
+    #[case("1e6", Token::new(1..1, 1..3, Data::Number(1e6.into())))] // 指数表記（10^6）
+    #[case("-2.5E-3", Token::new(1..1, 1..7, Data::Number((-2.5E-3).into())))] // 指数付き小数
+    #[allow(clippy::approx_constant)]
     fn test_parse_number(#[case] input: &str, #[case] expected: Token) {
         let cursor = Cursor::new(input);
         let buf_reader = std::io::BufReader::new(cursor);
@@ -491,15 +813,15 @@ mod tests {
     #[rstest::rstest]
     #[case(
         "3.14.14",
-        "Line: 1..1 Position: 1..7 `number` トークンとして解釈できませんでした（invalid float literal） "
+        "Line: 1..1 Position: 1..7 `number` トークンとして解釈できませんでした（invalid number） "
     )]
     #[case(
         "-3E14E1",
-        "Line: 1..1 Position: 1..7 `number` トークンとして解釈できませんでした（invalid float literal） "
+        "Line: 1..1 Position: 1..7 `number` トークンとして解釈できませんでした（invalid number） "
     )]
     #[case(
         "--11223",
-        "Line: 1..1 Position: 1..7 `number` トークンとして解釈できませんでした（invalid float literal） "
+        "Line: 1..1 Position: 1..7 `number` トークンとして解釈できませんでした（invalid number） "
     )]
     fn test_parse_invalid_number(#[case] input: &str, #[case] expected: &str) {
         let cursor = Cursor::new(input);
@@ -546,6 +868,21 @@ mod tests {
         assert_eq!(result.unwrap(), Token::new(1..1, 1..4, Data::Null));
     }
 
+    #[rstest::rstest]
+    #[case(r#""\x""#, Error::InvalidEscape(1..1, 2..3))]
+    #[case(r#""\u12""#, Error::InvalidEscape(1..1, 2..5))]
+    #[case(r#""\ud800""#, Error::InvalidEscape(1..1, 2..7))] // 対になっていない上位サロゲート
+    #[case(r#""\udc00a""#, Error::InvalidEscape(1..1, 2..7))] // 対になっていない下位サロゲート
+    #[case(r#""\ud800\u0041""#, Error::InvalidEscape(1..1, 2..13))] // 下位サロゲート以外が続く
+    #[case(r#""\u00"#, Error::UnclosedStringLiteral(1..1, 1..5))]
+    fn test_invalid_escape(#[case] input: &str, #[case] expected: Error) {
+        let mut lexer = Lexer::new(std::io::BufReader::new(Cursor::new(input)));
+
+        assert_eq!(lexer.read().unwrap_err(), expected);
+        // 閉じるダブルクォートまで読み進めている
+        assert_eq!(lexer.read().unwrap().data, Data::EOF);
+    }
+
     #[test]
     fn test_unclosed_string() {
         let cursor = Cursor::new("\"true");
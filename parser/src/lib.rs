@@ -1,24 +1,301 @@
+/// 解析した値をアリーナに確保する
+#[cfg(feature = "bumpalo")]
+pub mod arena;
+/// tokio::io::AsyncBufRead から読み取れる文字列を解析する
+#[cfg(feature = "tokio")]
+pub mod asynchronous;
+/// 入力の文字列を借用して解析する
+pub mod borrowed;
+/// 分割して届くバイト列の区切りを数える
+mod boundary;
 /// std::io::BufRead から UTF-8 を１文字ずつ取り出すReader
 pub mod char_reader;
+/// 空白・コメントを保持する JSON文書
+pub mod cst;
+/// エラーの該当箇所を引用して表示する
+mod diagnostics;
 /// char_reader::CharReader から　JSONトークンを生成する
 pub mod lexer;
+/// Parser の挙動の設定
+pub mod options;
+/// 受け取ったバイト列から JSONイベントを返却する
+pub mod push;
+/// 解析せずに入力の表記のまま保持する JSONの値
+pub mod raw;
+/// Node を構築せずに JSONの体裁を整える
+pub mod reformat;
+/// 入力中の範囲を持つ JSONデータ
+pub mod spanned;
+/// Node を構築せずに JSONイベントを返却する
+pub mod streaming;
+/// JSONイベントから Frame のスタックで木を構築する
+mod tree;
+/// JSONの構造を読み出した順に呼び出されるコールバック
+pub mod visitor;
 
-use node::Node;
+/// 解析結果の Node と変換のトレイトは node クレートのものを公開する
+/// node クレートに依存せずに解析結果を扱える
+///
+/// # Examples
+///
+/// ```
+/// let node: parser::Node = parser::from_str(r#"{"a": [1]}"#).unwrap();
+/// assert_eq!(<Vec<u8> as parser::FromNode>::from_node(&node["a"]).unwrap(), vec![1]);
+/// ```
+pub use node::{FromNode, Node, ToNode};
+
+use node::{MapKind, ObjectMap};
 
-use crate::lexer::{Data, Lexer, Token};
+use crate::lexer::Kind;
+use crate::options::{DuplicateKeys, ParserOptions};
+use crate::spanned::SpannedNode;
+use crate::streaming::{JsonEvent, StreamingParser};
+use crate::tree::{NodeBuilder, SpannedBuilder};
+use crate::visitor::JsonVisitor;
 
 /// 解析時のエラーを表現する
 #[derive(thiserror::Error, std::fmt::Debug)]
 pub enum Error {
     #[error("行: {0:?} 位置: {1:?} で構文エラーが発生しました（{2}）")]
     SyntaxError(std::ops::Range<usize>, std::ops::Range<usize>, String),
+    /// 期待されたトークンの種別と実際に現れたトークンの種別を持つ構文エラー
+    #[error("行: {line:?} 位置: {pos:?} で構文エラーが発生しました（{message}）")]
+    UnexpectedToken {
+        line: std::ops::Range<usize>,
+        pos: std::ops::Range<usize>,
+        expected: Vec<Kind>,
+        found: Kind,
+        message: String,
+    },
+    #[error("行: {0:?} 位置: {1:?} でObjectのキー `{2}` が重複しています")]
+    DuplicateKey(std::ops::Range<usize>, std::ops::Range<usize>, String),
+    #[error("行: {0:?} 位置: {1:?} でネストの深さが上限（{2}）を超えました")]
+    DepthLimitExceeded(std::ops::Range<usize>, std::ops::Range<usize>, usize),
+    #[error("行: {0:?} 位置: {1:?} で値の後に余分な文字 `{2}` があります")]
+    TrailingCharacters(std::ops::Range<usize>, std::ops::Range<usize>, char),
+    #[error("行: {0:?} 位置: {1:?} で{2}")]
+    InvalidToken(std::ops::Range<usize>, std::ops::Range<usize>, String),
     #[error("{0}")]
     LexerError(String),
+    #[error(transparent)]
+    InvalidPointer(#[from] node::PointerError),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error("入力が上限（{0}バイト）を超えました")]
+    SizeLimitExceeded(usize),
+    #[error("行: {0:?} 位置: {1:?} で Node の数が上限（{2}）を超えました")]
+    NodeLimitExceeded(std::ops::Range<usize>, std::ops::Range<usize>, usize),
+    #[error("行: {0:?} 位置: {1:?} で文字列の合計が上限（{2}バイト）を超えました")]
+    StringLimitExceeded(std::ops::Range<usize>, std::ops::Range<usize>, usize),
+    #[error(transparent)]
+    ConversionError(#[from] node::Error),
 }
 
 impl From<lexer::error::Error> for Error {
     fn from(value: lexer::error::Error) -> Self {
-        Self::LexerError(value.to_string())
+        match value {
+            lexer::error::Error::SizeLimitExceeded(max_bytes) => Self::SizeLimitExceeded(max_bytes),
+            lexer::error::Error::InvalidToken(token, line, pos) => {
+                Self::InvalidToken(line, pos, format!("`{token}` トークンの解釈に失敗しました"))
+            }
+            lexer::error::Error::InvalidNumber(message, line, pos) => Self::InvalidToken(
+                line,
+                pos,
+                format!("`number` トークンとして解釈できませんでした（{message}）"),
+            ),
+            lexer::error::Error::UnclosedStringLiteral(line, pos) => {
+                Self::InvalidToken(line, pos, "文字列の終了の前に末尾に到達しました".into())
+            }
+            lexer::error::Error::InvalidEscape(line, pos) => {
+                Self::InvalidToken(line, pos, "文字列のエスケープを解釈できません".into())
+            }
+            lexer::error::Error::UnclosedComment(line, pos) => {
+                Self::InvalidToken(line, pos, "コメントの終了の前に末尾に到達しました".into())
+            }
+            _ => Self::LexerError(value.to_string()),
+        }
+    }
+}
+
+/// 文字列全体を１つの JSON の値として解析し、FromNode を実装した型に変換して返却する
+/// 値の後に空白以外の文字が続く場合は Error::TrailingCharacters を返却する
+/// 変換に失敗した場合は Error::ConversionError を返却する
+/// node::Node は parser に依存しない node クレートの型であり std::str::FromStr を実装できないため、
+/// `text.parse()` で解析する場合は Json で包む
+///
+/// # Examples
+///
+/// ```
+/// let node: node::Node = parser::from_str(r#"{"key": [1, 2]}"#).unwrap();
+/// assert!(matches!(node, node::Node::Object(_)));
+///
+/// let pairs: Vec<(String, u8)> = parser::from_str(r#"[["a", 1], ["b", 2]]"#).unwrap();
+/// assert_eq!(pairs, [("a".to_string(), 1), ("b".to_string(), 2)]);
+/// assert!(matches!(
+///     parser::from_str::<Vec<u8>>("[1, -1]"),
+///     Err(parser::Error::ConversionError(_))
+/// ));
+/// ```
+pub fn from_str<T: FromNode>(input: &str) -> Result<T, Error> {
+    from_slice(input.as_bytes())
+}
+
+/// std::str::FromStr で文字列全体を１つの JSON の値として解析し、FromNode を実装した型に変換した値を保持する
+/// 孤児ルールにより parser クレートから node::Node に std::str::FromStr を実装できないため、代わりに `text.parse()` の対象とする
+/// 解析は from_str と同じく、値の後に空白以外の文字が続く場合は Error::TrailingCharacters を返却する
+///
+/// # Examples
+///
+/// ```
+/// use parser::Json;
+///
+/// let Json(node) = r#"{"key": [1, 2]}"#.parse::<Json>().unwrap();
+/// assert!(matches!(node, node::Node::Object(_)));
+///
+/// let ports: Vec<u16> = "[80, 443]".parse::<Json<_>>().unwrap().into_inner();
+/// assert_eq!(ports, [80, 443]);
+/// assert!(matches!(
+///     "[1] [2]".parse::<Json>(),
+///     Err(parser::Error::TrailingCharacters(..))
+/// ));
+/// ```
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub struct Json<T = Node>(pub T);
+
+impl<T> Json<T> {
+    /// 解析した値を返却する
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: FromNode> std::str::FromStr for Json<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        from_str(s).map(Json)
+    }
+}
+
+/// バイト列全体を UTF-8 の１つの JSON の値として解析し、FromNode を実装した型に変換して返却する
+/// 値の後に空白以外の文字が続く場合は Error::TrailingCharacters を返却する
+pub fn from_slice<T: FromNode>(input: &[u8]) -> Result<T, Error> {
+    Parser::new(input).parse_complete_into()
+}
+
+/// std::io::Read から末尾まで読み出し、１つの JSON の値として解析して FromNode を実装した型に変換して返却する
+/// 値の後に空白以外の文字が続く場合は Error::TrailingCharacters を返却する
+pub fn from_reader<T, R>(reader: R) -> Result<T, Error>
+where
+    T: FromNode,
+    R: std::io::Read + std::fmt::Debug,
+{
+    Parser::new(std::io::BufReader::new(reader)).parse_complete_into()
+}
+
+/// ファイルを開いて末尾まで読み出し、１つの JSON の値として解析して FromNode を実装した型に変換して返却する
+/// ファイルを開けない場合は Error::IoError を返却する
+pub fn from_file<T, P>(path: P) -> Result<T, Error>
+where
+    T: FromNode,
+    P: AsRef<std::path::Path>,
+{
+    from_reader(std::fs::File::open(path)?)
+}
+
+/// value を JSONの文字列に変換し、解析し直して FromNode で復元した値を返却する
+/// ToNode と FromNode の実装が対応していることをテストで確認する場合に利用する
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// let value = BTreeMap::from([("a".to_string(), vec![(1u8, Some('x'))])]);
+/// assert_eq!(parser::roundtrip(&value).unwrap(), value);
+/// ```
+pub fn roundtrip<T: ToNode + FromNode>(value: &T) -> Result<T, Error> {
+    from_str(&node::serializer::to_string_from(value))
+}
+
+/// std::io::BufRead から読み取れる文字列が１つの JSON の値として正しいかを検証する
+/// Node や文字列を構築しないため、巨大な JSON でもネストの深さ分のメモリで検証できる
+/// 構文エラーからは回復して検証を継続し、発生した全てのエラーを返却する
+///
+/// # Examples
+///
+/// ```
+/// let cursor = std::io::Cursor::new(r#"{"a": [1, 2,, 3], "b": 4 "c": 5}"#);
+/// let buf_reader = std::io::BufReader::new(cursor);
+/// let errors = parser::validate(buf_reader).unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// ```
+pub fn validate<T>(reader: T) -> Result<(), Vec<Error>>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    validate_with_options(reader, ParserOptions::default())
+}
+
+/// 挙動を設定して validate と同様に検証する
+/// 値の後に空白以外の文字が続く場合は ParserOptions::reject_trailing_characters によらず Error::TrailingCharacters を返却する
+/// キーを保持しないため ParserOptions::duplicate_keys は評価しない
+pub fn validate_with_options<T>(reader: T, options: ParserOptions) -> Result<(), Vec<Error>>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    let mut events = StreamingParser::with_options(reader, options);
+    events.set_discard(true);
+    events.begin_recovery();
+
+    let mut depth = 0_usize;
+    let mut fatal = None;
+    let mut empty = true;
+
+    loop {
+        match events.next_event() {
+            Ok(None) => break,
+            Ok(Some(event)) => {
+                empty = false;
+                match event {
+                    JsonEvent::StartObject | JsonEvent::StartArray => depth += 1,
+                    JsonEvent::EndObject | JsonEvent::EndArray => depth -= 1,
+                    JsonEvent::Key(_) => continue,
+                    JsonEvent::Value(_) => {}
+                }
+
+                if depth == 0 {
+                    break;
+                }
+            }
+            Err(e) => {
+                fatal = Some(e);
+                break;
+            }
+        }
+    }
+
+    let mut errors = events.end_recovery();
+    errors.extend(fatal);
+
+    if errors.is_empty() {
+        if empty {
+            errors.push(events.syntax_error(Kind::VALUE, "値がありません"));
+        } else {
+            match events.skip_whitespace() {
+                Ok(None) => {}
+                Ok(Some((c, line, pos))) => {
+                    errors.push(Error::TrailingCharacters(line..line, pos..pos, c))
+                }
+                Err(e) => errors.push(e),
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
     }
 }
 
@@ -37,7 +314,7 @@ impl From<lexer::error::Error> for Error {
 /// assert_eq!(
 ///     result,
 ///     node::Node::Object(
-///         std::collections::BTreeMap::from([
+///         node::Map::from([
 ///             (
 ///                 "key".to_string(),
 ///                 node::Node::String("Hello, 世界".to_string())
@@ -50,11 +327,79 @@ pub struct Parser<T>
 where
     T: std::io::BufRead + std::fmt::Debug,
 {
-    lexer: Lexer<T>,
+    events: StreamingParser<T>,
+    duplicate_keys: DuplicateKeys,
+    reject_trailing_characters: bool,
+    max_nodes: Option<usize>,
+    max_string_bytes: Option<usize>,
+    #[cfg(feature = "preserve_order")]
+    preserve_order: bool,
+}
+
+/// Objectのキーとその位置を表現する
+struct Key {
+    name: String,
     line: std::ops::Range<usize>,
     pos: std::ops::Range<usize>,
 }
 
+/// 値を１つ解析する間に構築した Node の数と文字列のバイト数を数える
+struct Budget {
+    max_nodes: Option<usize>,
+    max_string_bytes: Option<usize>,
+    nodes: usize,
+    string_bytes: usize,
+}
+
+impl Budget {
+    /// イベントから構築する分を加算し、上限を超えた場合はエラーを返却する
+    fn charge(
+        &mut self,
+        event: &JsonEvent,
+        span: (std::ops::Range<usize>, std::ops::Range<usize>),
+    ) -> Result<(), Error> {
+        match event {
+            JsonEvent::EndObject | JsonEvent::EndArray => return Ok(()),
+            JsonEvent::Key(name) => self.string_bytes += name.len(),
+            JsonEvent::Value(Node::String(value)) => {
+                self.nodes += 1;
+                self.string_bytes += value.len();
+            }
+            JsonEvent::StartObject | JsonEvent::StartArray | JsonEvent::Value(_) => self.nodes += 1,
+        }
+
+        self.check(span)
+    }
+
+    /// イベントが内容を持たない文字列のバイト数を加算し、上限を超えた場合はエラーを返却する
+    fn charge_string(
+        &mut self,
+        bytes: usize,
+        span: (std::ops::Range<usize>, std::ops::Range<usize>),
+    ) -> Result<(), Error> {
+        self.string_bytes += bytes;
+        self.check(span)
+    }
+
+    fn check(
+        &self,
+        (line, pos): (std::ops::Range<usize>, std::ops::Range<usize>),
+    ) -> Result<(), Error> {
+        if let Some(max_nodes) = self.max_nodes
+            && self.nodes > max_nodes
+        {
+            return Err(Error::NodeLimitExceeded(line, pos, max_nodes));
+        }
+        if let Some(max_string_bytes) = self.max_string_bytes
+            && self.string_bytes > max_string_bytes
+        {
+            return Err(Error::StringLimitExceeded(line, pos, max_string_bytes));
+        }
+
+        Ok(())
+    }
+}
+
 #[allow(dead_code)]
 impl<T> Parser<T>
 where
@@ -62,196 +407,599 @@ where
 {
     /// パーサーを生成して返却する
     pub fn new(reader: T) -> Self {
+        Self::with_options(reader, ParserOptions::default())
+    }
+
+    /// 挙動を設定したパーサーを生成して返却する
+    pub fn with_options(reader: T, options: ParserOptions) -> Self {
         Self {
-            lexer: Lexer::new(reader),
-            line: 1..1,
-            pos: 1..1,
+            duplicate_keys: options.duplicate_keys,
+            reject_trailing_characters: options.reject_trailing_characters,
+            max_nodes: options.max_nodes,
+            max_string_bytes: options.max_string_bytes,
+            #[cfg(feature = "preserve_order")]
+            preserve_order: options.preserve_order,
+            events: StreamingParser::with_options(reader, options),
         }
     }
 
+    /// reader を差し替え、生成した直後の状態から解析し直す
+    /// 小さな入力を繰り返し解析する場合に、設定やバッファを作り直さずに再利用できる
+    /// 差し替える前の reader を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut parser = parser::Parser::new(std::io::Cursor::new(b"[1, 2".to_vec()));
+    /// assert!(parser.parse().is_err());
+    ///
+    /// let previous = parser.reset(std::io::Cursor::new(b"{\"a\": null}".to_vec()));
+    /// assert_eq!(previous.into_inner(), b"[1, 2");
+    /// assert_eq!(
+    ///     parser.parse().unwrap(),
+    ///     node::Node::Object(node::Map::from([(
+    ///         "a".to_string(),
+    ///         node::Node::Null
+    ///     )]))
+    /// );
+    /// ```
+    pub fn reset(&mut self, reader: T) -> T {
+        self.events.reset(reader)
+    }
+
+    /// 内部の reader への可変参照を返却する
+    #[cfg(feature = "tokio")]
+    pub(crate) fn get_mut(&mut self) -> &mut T {
+        self.events.get_mut()
+    }
+
     /// std::io::BufRead から１文字ずつ読み出し、トークンを生成し、文法からノードを構築して返却する
-    /// std::io::BufRead の末尾に到達した場合は Node::EOF を返却する
-    /// 構文エラーの場合は Error::SyntaxError を返却する
-    /// トークン生成や reader 自体のエラーは　Error::LexerError を返却する
+    /// 値を読み出す前に std::io::BufRead の末尾に到達した場合は Error::UnexpectedToken を返却する
+    /// 構文エラーの場合は Error::UnexpectedToken を返却する
+    /// ネストが ParserOptions::max_depth を超える場合は Error::DepthLimitExceeded を返却する
+    /// トークン生成のエラーは Error::InvalidToken、reader 自体のエラーは Error::LexerError を返却する
     pub fn parse(&mut self) -> Result<Node, Error> {
-        match self.read_token()? {
-            Token {
-                line: _,
-                pos: _,
-                data: Data::LeftBrace,
-            } => self.parse_object(),
-            Token {
-                line: _,
-                pos: _,
-                data: Data::LeftBracket,
-            } => self.parse_array(),
-            Token {
-                line: _,
-                pos: _,
-                data: Data::String(value),
-            } => Ok(Node::String(value.clone())),
-            Token {
-                line: _,
-                pos: _,
-                data: Data::Number(value),
-            } => Ok(Node::Number(value.clone())),
-            Token {
-                line: _,
-                pos: _,
-                data: Data::True,
-            } => Ok(Node::True),
-            Token {
-                line: _,
-                pos: _,
-                data: Data::False,
-            } => Ok(Node::False),
-            Token {
-                line: _,
-                pos: _,
-                data: Data::Null,
-            } => Ok(Node::Null),
-            Token {
-                line: _,
-                pos: _,
-                data: Data::EOF,
-            } => Ok(Node::EOF),
-            _ => return Err(self.syntax_error(
-                "bool型・null型・String型・Number型・Object・Arrayのいずれかでなければなりません",
-            )),
-        }
-    }
-
-    fn read_token(&mut self) -> Result<Token, Error> {
-        self.lexer
-            .read()
-            .map(|mut token| {
-                // token の line/pos を以降で読み出さない
-                self.line = std::mem::take(&mut token.line);
-                self.pos = std::mem::take(&mut token.pos);
-                token
-            })
-            .map_err(Error::from)
+        match self.parse_next()? {
+            Some(node) => Ok(node),
+            None => Err(self.missing_value()),
+        }
     }
 
-    fn parse_object(&mut self) -> Result<Node, Error> {
-        let mut object = std::collections::BTreeMap::new();
+    /// Parser::parse と同様に値を１つ解析して返却する
+    /// reader の末尾に到達した場合はエラーではなく None を返却する
+    /// 連結された複数の値を順に読み出す場合に利用する
+    /// エラーを返却した後は、Parser::reset するまで None を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let cursor = std::io::Cursor::new("1 2");
+    /// let buf_reader = std::io::BufReader::new(cursor);
+    /// let mut parser = parser::Parser::new(buf_reader);
+    /// let mut nodes = vec![];
+    /// while let Some(node) = parser.parse_next().unwrap() {
+    ///     nodes.push(node);
+    /// }
+    /// assert_eq!(nodes, vec![node::Node::Number(1.into()), node::Node::Number(2.into())]);
+    /// ```
+    pub fn parse_next(&mut self) -> Result<Option<Node>, Error> {
+        let node = self.parse_value(None)?;
+        if self.reject_trailing_characters {
+            self.ensure_end()?;
+        }
 
-        loop {
-            let key_token = self.read_token()?;
-
-            match key_token {
-                Token {
-                    line: _,
-                    pos: _,
-                    data: Data::String(key),
-                } => {
-                    let colon_token = self.read_token()?;
-
-                    match colon_token {
-                        Token {
-                            line: _,
-                            pos: _,
-                            data: Data::Colon,
-                        } => {
-                            let value_node = self.parse()?;
-
-                            match value_node {
-                                Node::String(_)
-                                | Node::Number(_)
-                                | Node::True
-                                | Node::False
-                                | Node::Null
-                                | Node::Object(_)
-                                | Node::Array(_) => {
-                                    match object.entry(key) {
-                                        std::collections::btree_map::Entry::Occupied(mut e) => {
-                                            *e.get_mut() = value_node;
-                                        }
-                                        std::collections::btree_map::Entry::Vacant(e) => {
-                                            e.insert(value_node);
-                                        }
-                                    };
-
-                                    match self.read_token()? {
-                                        Token {
-                                            line: _,
-                                            pos: _,
-                                            data: Data::Comma,
-                                        } => continue,
-                                        Token {
-                                            line: _,
-                                            pos: _,
-                                            data: Data::RightBrace,
-                                        } => break,
-                                        _ => return Err(self.syntax_error("Objectの解析の継続（`,`）、終了（`}`）のいずれもでありません")),
-                                    }
-                                },
-                                _ => return Err(self.syntax_error("Objectの値はbool型・null型・String型・Number型・Object・Arrayのいずれかでなければなりません")),
+        Ok(node)
+    }
+
+    /// Parser::parse と同様に値を１つ解析し、Object を K が表す種類の Map で構築して返却する
+    /// ParserOptions::preserve_order が無効な場合、並び順を持つ Map はキーの昇順に並べ替える
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let cursor = std::io::Cursor::new(r#"{"b": 1, "a": 2}"#);
+    /// let buf_reader = std::io::BufReader::new(cursor);
+    /// let mut parser = parser::Parser::new(buf_reader);
+    /// let node = parser.parse_with_map::<node::map::Hashed>().unwrap();
+    /// assert_eq!(
+    ///     node,
+    ///     node::Node::Object(std::collections::HashMap::from([
+    ///         ("a".to_string(), node::Node::Number(2.into())),
+    ///         ("b".to_string(), node::Node::Number(1.into())),
+    ///     ]))
+    /// );
+    /// ```
+    pub fn parse_with_map<K: MapKind>(&mut self) -> Result<Node<K>, Error> {
+        let node = self
+            .parse_value(None)?
+            .ok_or_else(|| self.missing_value())?;
+        if self.reject_trailing_characters {
+            self.ensure_end()?;
+        }
+
+        Ok(node)
+    }
+
+    /// Parser::parse と同様に値を１つ解析し、全ての値が入力中の範囲を持つツリーを返却する
+    /// 意味的に不正な値の位置を利用者に示す場合に利用する
+    /// reader の末尾に到達した場合は None を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let input = "{\n  \"port\": -1\n}";
+    /// let cursor = std::io::Cursor::new(input);
+    /// let buf_reader = std::io::BufReader::new(cursor);
+    /// let mut parser = parser::Parser::new(buf_reader);
+    /// let node = parser.parse_spanned().unwrap().unwrap();
+    /// let parser::spanned::SpannedValue::Object(object) = node.value else {
+    ///     unreachable!()
+    /// };
+    /// let port = &object["port"].value;
+    /// assert_eq!(port.span.line, 2..2);
+    /// assert_eq!(port.span.pos, 11..12);
+    /// ```
+    pub fn parse_spanned(&mut self) -> Result<Option<SpannedNode>, Error> {
+        let Some(node) = self.build(&mut SpannedBuilder)? else {
+            return Ok(None);
+        };
+        if self.reject_trailing_characters {
+            self.ensure_end()?;
+        }
+
+        Ok(Some(node))
+    }
+
+    /// Parser::parse と同様にノードを構築した後、reader の末尾まで空白しか残っていないことを確認する
+    /// 空白以外の文字が残っている場合は Error::TrailingCharacters を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let cursor = std::io::Cursor::new(r#"{"a": 1} xyz"#);
+    /// let buf_reader = std::io::BufReader::new(cursor);
+    /// let mut parser = parser::Parser::new(buf_reader);
+    /// let result = parser.parse_complete();
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(parser::Error::TrailingCharacters(_, _, 'x'))
+    /// ));
+    /// ```
+    pub fn parse_complete(&mut self) -> Result<Node, Error> {
+        let node = self
+            .parse_value(None)?
+            .ok_or_else(|| self.missing_value())?;
+        self.ensure_end()?;
+
+        Ok(node)
+    }
+
+    /// 値を１つ解析し、FromNode を実装した型に変換して返却する
+    /// FromNode::FIELDS が Some の場合、最上位のObjectのうち一覧にないキーの値は Node を構築せずに読み飛ばす
+    /// 読み飛ばしたキーは ParserOptions::duplicate_keys を評価しない
+    /// 変換に失敗した場合は Error::ConversionError を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[derive(macro_deserialize::Deserialize, Debug, PartialEq)]
+    /// struct User {
+    ///     id: u32,
+    ///     name: String,
+    /// }
+    ///
+    /// let input = r#"{"id": 1, "name": "Alice", "history": [{"a": 1}, {"b": 2}]}"#;
+    /// let cursor = std::io::Cursor::new(input);
+    /// let buf_reader = std::io::BufReader::new(cursor);
+    /// let mut parser = parser::Parser::new(buf_reader);
+    /// let user = parser.parse_into::<User>().unwrap();
+    /// assert_eq!(user, User { id: 1, name: "Alice".into() });
+    /// ```
+    pub fn parse_into<U>(&mut self) -> Result<U, Error>
+    where
+        U: node::FromNode,
+    {
+        let node: Node = self
+            .parse_value(U::FIELDS)?
+            .ok_or_else(|| self.missing_value())?;
+        if self.reject_trailing_characters {
+            self.ensure_end()?;
+        }
+
+        Ok(U::from_node(&node)?)
+    }
+
+    /// Parser::parse_into と同様に値を１つ解析して変換した後、reader の末尾まで空白しか残っていないことを確認する
+    /// 空白以外の文字が残っている場合は Error::TrailingCharacters を返却する
+    pub fn parse_complete_into<U>(&mut self) -> Result<U, Error>
+    where
+        U: node::FromNode,
+    {
+        let node: Node = self
+            .parse_value(U::FIELDS)?
+            .ok_or_else(|| self.missing_value())?;
+        self.ensure_end()?;
+
+        Ok(U::from_node(&node)?)
+    }
+
+    /// 値を１つ読み出し、RFC 6901 の JSON Pointer（`/results/items/3/id` など）が指す値のみ Node を構築して返却する
+    /// 指す値以外は Node を構築せずに読み飛ばし、値の末尾まで読み出してから返却する
+    /// 指す値が存在しない場合（reader の末尾に到達していた場合を含む）は None を返却する
+    /// JSON Pointer として解釈できない場合は Error::InvalidPointer を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let input = r#"{"results": {"items": [{"id": 1}, {"id": 2}]}, "total": 2}"#;
+    /// let cursor = std::io::Cursor::new(input);
+    /// let buf_reader = std::io::BufReader::new(cursor);
+    /// let mut parser = parser::Parser::new(buf_reader);
+    /// assert_eq!(
+    ///     parser.parse_at("/results/items/1/id").unwrap(),
+    ///     Some(node::Node::Number(2.into()))
+    /// );
+    /// ```
+    pub fn parse_at(&mut self, pointer: &str) -> Result<Option<Node>, Error> {
+        let pointer = pointer.parse::<node::Pointer>()?;
+        // 読み飛ばさずに中へ進んだ Object・Array の数
+        let mut depth = 0_usize;
+
+        for segment in pointer.tokens() {
+            let found = match self.events.next_event()? {
+                None => return Ok(None),
+                Some(JsonEvent::StartObject) => {
+                    depth += 1;
+
+                    loop {
+                        match self.events.next_event()? {
+                            Some(JsonEvent::Key(key)) if key == *segment => break true,
+                            Some(JsonEvent::Key(_)) => {
+                                self.events.skip_value()?;
+                            }
+                            _ => {
+                                depth -= 1;
+                                break false;
                             }
                         }
-                        _ => {
-                            return Err(
-                                self.syntax_error("Objectのキーの後は`:`でなければなりません")
-                            );
+                    }
+                }
+                Some(JsonEvent::StartArray) => {
+                    depth += 1;
+
+                    match node::Pointer::parse_index(segment) {
+                        None => false,
+                        Some(index) => {
+                            let mut found = true;
+                            for _ in 0..index {
+                                if !self.events.skip_value()? {
+                                    found = false;
+                                    break;
+                                }
+                            }
+
+                            found
+                                && !matches!(
+                                    self.events.peek_event()?,
+                                    None | Some(JsonEvent::EndArray)
+                                )
                         }
                     }
                 }
+                Some(_) => false,
+            };
 
-                _ => return Err(self.syntax_error("ObjectのキーはString型でなければなりません")),
+            if !found {
+                self.skip_rest(depth)?;
+                return Ok(None);
             }
         }
 
-        Ok(Node::Object(object))
+        let node = self.parse_value(None)?;
+        self.skip_rest(depth)?;
+
+        Ok(node)
+    }
+
+    /// 中へ進んだ Object・Array の残りを読み飛ばす
+    fn skip_rest(&mut self, depth: usize) -> Result<(), Error> {
+        for _ in 0..depth {
+            while self.events.skip_value()? {}
+            // 閉じるイベントを読み捨てる
+            self.events.next_event()?;
+        }
+
+        Ok(())
     }
 
-    fn parse_array(&mut self) -> Result<Node, Error> {
-        let mut array: Vec<Node> = Vec::new();
+    /// Node を構築せずに、値を１つ読み出しながら visitor のコールバックを呼び出す
+    /// 値を読み終えた場合は true を、reader の末尾に到達していた場合は false を返却する
+    /// エラーは Parser::parse と同様に返却する
+    pub fn parse_with_visitor<V>(&mut self, visitor: &mut V) -> Result<bool, Error>
+    where
+        V: JsonVisitor,
+    {
+        let mut depth = 0_usize;
 
         loop {
-            let node = self.parse()?;
-
-            match node {
-                Node::String(_)
-                | Node::Number(_)
-                | Node::True
-                | Node::False
-                | Node::Null
-                | Node::Object(_)
-                | Node::Array(_) => array.push(node),
-                _ => return Err(self.syntax_error("Arrayの要素はbool型・null型・String型・Number型・Object・Arrayのいずれかでなければなりません")),
-            }
-
-            match self.read_token()? {
-                Token {
-                    line: _,
-                    pos: _,
-                    data: Data::Comma,
-                } => continue,
-                Token {
-                    line: _,
-                    pos: _,
-                    data: Data::RightBracket,
-                } => break,
-                _ => {
-                    return Err(
-                        self.syntax_error("Arrayの要素の後は `,` か `]` でなければなりません")
-                    );
+            let Some(event) = self.events.next_event()? else {
+                return Ok(false);
+            };
+            let (line, pos) = self.events.span();
+
+            match event {
+                JsonEvent::StartObject => {
+                    depth += 1;
+                    visitor.on_object_start(line, pos);
+                }
+                JsonEvent::StartArray => {
+                    depth += 1;
+                    visitor.on_array_start(line, pos);
+                }
+                JsonEvent::EndObject => {
+                    depth -= 1;
+                    visitor.on_object_end(line, pos);
+                }
+                JsonEvent::EndArray => {
+                    depth -= 1;
+                    visitor.on_array_end(line, pos);
+                }
+                JsonEvent::Key(key) => visitor.on_key(&key, line, pos),
+                JsonEvent::Value(value) => visitor.on_value(&value, line, pos),
+            }
+
+            if depth == 0 {
+                break;
+            }
+        }
+
+        if self.reject_trailing_characters {
+            self.ensure_end()?;
+        }
+
+        Ok(true)
+    }
+
+    /// reader の末尾まで空白しか残っていないことを確認する
+    fn ensure_end(&mut self) -> Result<(), Error> {
+        match self.events.skip_whitespace()? {
+            None => Ok(()),
+            Some((c, line, pos)) => Err(Error::TrailingCharacters(line..line, pos..pos, c)),
+        }
+    }
+
+    /// 最上位の Array の要素を１つずつ解析して返却するイテレーターを返却する
+    /// Array 全体の Vec<Node> を構築しないため、巨大な Array でも要素１つ分のメモリで処理できる
+    /// 最上位の値が Array でない場合は Error::UnexpectedToken を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let input = r#"[{"id": 1}, {"id": 2}, {"id": 3}]"#;
+    /// let cursor = std::io::Cursor::new(input);
+    /// let buf_reader = std::io::BufReader::new(cursor);
+    /// let mut parser = parser::Parser::new(buf_reader);
+    /// let mut count = 0;
+    /// for element in parser.array_elements() {
+    ///     assert!(matches!(element, Ok(node::Node::Object(_))));
+    ///     count += 1;
+    /// }
+    /// assert_eq!(count, 3);
+    /// ```
+    pub fn array_elements(&mut self) -> ArrayElements<'_, T> {
+        ArrayElements {
+            parser: self,
+            started: false,
+            finished: false,
+        }
+    }
+
+    /// reader の末尾まで値を１つずつ解析して返却するイテレーターに変換する
+    /// 改行区切りの JSON（NDJSON）や、区切りなく連結された複数の値を読み出す場合に利用する
+    /// ParserOptions::reject_trailing_characters は評価しない
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let input = "{\"id\": 1}\n{\"id\": 2}{\"id\": 3}\n";
+    /// let cursor = std::io::Cursor::new(input);
+    /// let buf_reader = std::io::BufReader::new(cursor);
+    /// let documents = parser::Parser::new(buf_reader)
+    ///     .into_documents()
+    ///     .collect::<Result<Vec<_>, _>>();
+    /// assert_eq!(documents.unwrap().len(), 3);
+    /// ```
+    pub fn into_documents(self) -> Documents<T> {
+        Documents {
+            parser: self,
+            finished: false,
+        }
+    }
+
+    /// 構文エラーが発生しても `,`・`}`・`]` まで読み飛ばして解析を継続し、部分的に構築したノードと発生した全てのエラーを返却する
+    /// 値として解釈できないトークンは Node::Null として扱う
+    /// 値を１つも構築できなかった場合（reader の末尾に到達していた場合を含む）は None を返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let cursor = std::io::Cursor::new(r#"{"a": 1 "b": 2, "c": [3,, 4], "d": truthy}"#);
+    /// let buf_reader = std::io::BufReader::new(cursor);
+    /// let mut parser = parser::Parser::new(buf_reader);
+    /// let (node, errors) = parser.parse_with_recovery();
+    /// assert_eq!(errors.len(), 3);
+    /// assert_eq!(
+    ///     node,
+    ///     Some(node::Node::Object(node::Map::from([
+    ///         ("a".to_string(), node::Node::Number(1.into())),
+    ///         (
+    ///             "c".to_string(),
+    ///             node::Node::Array(vec![node::Node::Number(3.into()), node::Node::Number(4.into())])
+    ///         ),
+    ///         ("d".to_string(), node::Node::Null),
+    ///     ])))
+    /// );
+    /// ```
+    pub fn parse_with_recovery(&mut self) -> (Option<Node>, Vec<Error>) {
+        self.events.begin_recovery();
+        let result = self.parse_value(None);
+        let mut errors = self.events.end_recovery();
+
+        let node = match result {
+            Ok(node) => node,
+            Err(e) => {
+                errors.push(e);
+                None
+            }
+        };
+
+        (node, errors)
+    }
+
+    /// 値を読み出す前に末尾に到達した場合のエラー
+    fn missing_value(&self) -> Error {
+        self.events.syntax_error(Kind::VALUE, "値がありません")
+    }
+
+    /// 値を１つ解析する
+    /// 値を読み出す前に末尾に到達した場合は None を返却する
+    /// fields が Some の場合、最上位のObjectのうち一覧にないキーの値は読み飛ばす
+    fn parse_value<K: MapKind>(
+        &mut self,
+        fields: Option<&[&str]>,
+    ) -> Result<Option<Node<K>>, Error> {
+        self.build(&mut NodeBuilder::new(fields))
+    }
+
+    /// ParserOptions::preserve_order が無効な場合、並び順を持つ Map をキーの昇順に並べ替える
+    #[cfg_attr(not(feature = "preserve_order"), allow(unused_variables))]
+    fn sort_keys<V, M: ObjectMap<V>>(&self, object: &mut M) {
+        #[cfg(feature = "preserve_order")]
+        if !self.preserve_order {
+            object.sort_keys();
+        }
+    }
+
+    /// ParserOptions::duplicate_keys に従って、Object に既にあるキーの値で上書きするかを返却する
+    fn accept_duplicate(&mut self, key: &Key) -> Result<bool, Error> {
+        match self.duplicate_keys {
+            DuplicateKeys::Overwrite => Ok(true),
+            DuplicateKeys::KeepFirst => Ok(false),
+            DuplicateKeys::Error => {
+                // 解析を継続する場合は最初に現れた値を保持する
+                self.events.record(Error::DuplicateKey(
+                    key.line.clone(),
+                    key.pos.clone(),
+                    key.name.clone(),
+                ))?;
+                Ok(false)
+            }
+        }
+    }
+}
+
+/// 最上位の Array の要素を１つずつ解析して返却するイテレーター
+/// エラーを返却した後は None を返却する
+pub struct ArrayElements<'a, T>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    parser: &'a mut Parser<T>,
+    /// `[` を読み出したか
+    started: bool,
+    finished: bool,
+}
+
+impl<T> ArrayElements<'_, T>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    fn next_element(&mut self) -> Result<Option<Node>, Error> {
+        let events = &mut self.parser.events;
+
+        if !self.started {
+            self.started = true;
+
+            match events.next_event()? {
+                None => return Ok(None),
+                Some(JsonEvent::StartArray) => {}
+                Some(_) => {
+                    return Err(events.syntax_error(
+                        &[Kind::LeftBracket],
+                        "最上位の値はArrayでなければなりません",
+                    ));
                 }
             }
         }
 
-        Ok(Node::Array(array))
+        if let Some(JsonEvent::EndArray) = events.peek_event()? {
+            events.next_event()?;
+            if self.parser.reject_trailing_characters {
+                self.parser.ensure_end()?;
+            }
+
+            return Ok(None);
+        }
+
+        self.parser.parse_value(None)
+    }
+}
+
+impl<T> Iterator for ArrayElements<'_, T>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    type Item = Result<Node, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let result = self.next_element();
+        if !matches!(result, Ok(Some(_))) {
+            self.finished = true;
+        }
+
+        result.transpose()
     }
+}
+
+/// reader の末尾まで値を１つずつ解析して返却するイテレーター
+/// エラーを返却した後は None を返却する
+pub struct Documents<T>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    parser: Parser<T>,
+    finished: bool,
+}
+
+impl<T> Iterator for Documents<T>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    type Item = Result<Node, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
 
-    fn syntax_error(&self, message: &str) -> Error {
-        Error::SyntaxError(self.line.clone(), self.pos.clone(), message.to_string())
+        let result = self.parser.parse_value(None).transpose();
+        self.finished = !matches!(result, Some(Ok(_)));
+        result
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::options::TopLevel;
+    use crate::spanned::{Span, SpannedEntry, SpannedValue};
 
+    // 3.14159 は円周率の近似ではなく小数の例として使う
+    #[allow(clippy::approx_constant)]
     #[test]
     fn test_parser() {
         let input = r#"
@@ -291,33 +1039,33 @@ mod tests {
 
         assert_eq!(
             result,
-            Node::Object(std::collections::BTreeMap::from([
+            Node::Object(node::Map::from([
                 (
                     "array".to_string(),
                     Node::Array(vec![
                         Node::String("text".into()),
-                        Node::Number(123.0),
-                        Node::False,
+                        Node::Number(123.into()),
+                        Node::Bool(false),
                         Node::Null,
-                        Node::Object(std::collections::BTreeMap::from([(
+                        Node::Object(node::Map::from([(
                             "nested_key".to_string(),
                             Node::String("nested_value".to_string())
                         )]))
                     ])
                 ),
-                ("boolean_false".to_string(), Node::False),
-                ("boolean_true".to_string(), Node::True),
+                ("boolean_false".to_string(), Node::Bool(false)),
+                ("boolean_true".to_string(), Node::Bool(true)),
                 ("null_value".to_string(), Node::Null),
-                ("number_exponent".to_string(), Node::Number(12300.0)),
-                ("number_float".to_string(), Node::Number(3.14159)),
-                ("number_integer".to_string(), Node::Number(42.0)),
-                ("number_negative".to_string(), Node::Number(-123.0)),
+                ("number_exponent".to_string(), Node::Number(12300.0.into())),
+                ("number_float".to_string(), Node::Number(3.14159.into())),
+                ("number_integer".to_string(), Node::Number(42.into())),
+                ("number_negative".to_string(), Node::Number((-123).into())),
                 (
                     "object".to_string(),
-                    Node::Object(std::collections::BTreeMap::from([
+                    Node::Object(node::Map::from([
                         ("key1".to_string(), Node::String("value1".into())),
-                        ("key2".to_string(), Node::Number(2.0)),
-                        ("key3".to_string(), Node::True),
+                        ("key2".to_string(), Node::Number(2.into())),
+                        ("key3".to_string(), Node::Bool(true)),
                     ]))
                 ),
                 ("string".to_string(), Node::String("Hello, 世界".into())),
@@ -325,8 +1073,14 @@ mod tests {
         );
 
         let result = parser.parse();
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), Node::EOF);
+        assert!(matches!(
+            result,
+            Err(Error::UnexpectedToken {
+                found: Kind::EOF,
+                ..
+            })
+        ));
+        assert_eq!(parser.parse_next().unwrap(), None);
     }
 
     #[rstest::rstest]
@@ -353,6 +1107,14 @@ mod tests {
         "構文エラーが発生しました（ObjectのキーはString型でなければなりません）"
     )]
     #[case(r#"{"hello": truthy}"#, "`true` トークンの解釈に失敗しました")]
+    #[case(
+        "[1e400,-1e400]",
+        "`number` トークンとして解釈できませんでした（number out of range）"
+    )]
+    #[case(
+        "-1e400",
+        "`number` トークンとして解釈できませんでした（number out of range）"
+    )]
     fn test_syntax_error(#[case] input: &str, #[case] message: &str) {
         let cursor = std::io::Cursor::new(input);
         let buf_reader = std::io::BufReader::new(cursor);
@@ -364,4 +1126,1492 @@ mod tests {
         let err = result.unwrap_err();
         assert!(err.to_string().contains(message));
     }
+
+    #[rstest::rstest]
+    #[case("{}", Node::Object(node::Map::new()))]
+    #[case("[]", Node::Array(vec![]))]
+    #[case(
+        r#"{"a": [], "b": {}}"#,
+        Node::Object(node::Map::from([
+            ("a".to_string(), Node::Array(vec![])),
+            ("b".to_string(), Node::Object(node::Map::new())),
+        ]))
+    )]
+    fn test_empty_container(#[case] input: &str, #[case] expected: Node) {
+        let cursor = std::io::Cursor::new(input);
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = Parser::new(buf_reader);
+
+        let result = parser.parse();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(
+        "[1, 2,]",
+        "bool型・null型・String型・Number型・Object・Arrayのいずれかでなければなりません"
+    )]
+    #[case(r#"{"a": 1,}"#, "ObjectのキーはString型でなければなりません")]
+    #[case(
+        "[,]",
+        "bool型・null型・String型・Number型・Object・Arrayのいずれかでなければなりません"
+    )]
+    fn test_trailing_commas(#[case] input: &str, #[case] message: &str) {
+        let cursor = std::io::Cursor::new(input);
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = Parser::new(buf_reader);
+
+        let result = parser.parse();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains(message));
+
+        let cursor = std::io::Cursor::new(input);
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = Parser::with_options(
+            buf_reader,
+            ParserOptions {
+                allow_trailing_commas: true,
+                ..Default::default()
+            },
+        );
+
+        let result = parser.parse();
+        if input == "[,]" {
+            // 要素のないカンマは末尾カンマではない
+            assert!(result.is_err());
+        } else {
+            assert!(result.is_ok());
+        }
+    }
+
+    #[test]
+    fn test_comments() {
+        let input = r#"
+        // 設定ファイル
+        {
+            "a": 1, /* 複数行の
+                       コメント */
+            "b": [true] // 行末のコメント
+        }
+        "#;
+
+        let cursor = std::io::Cursor::new(input);
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = Parser::with_options(
+            buf_reader,
+            ParserOptions {
+                allow_comments: true,
+                ..Default::default()
+            },
+        );
+
+        let result = parser.parse();
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            Node::Object(node::Map::from([
+                ("a".to_string(), Node::Number(1.into())),
+                ("b".to_string(), Node::Array(vec![Node::Bool(true)])),
+            ]))
+        );
+
+        let cursor = std::io::Cursor::new("[1 /* 閉じられない");
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = Parser::with_options(
+            buf_reader,
+            ParserOptions {
+                allow_comments: true,
+                ..Default::default()
+            },
+        );
+
+        let result = parser.parse();
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("コメントの終了の前に末尾に到達しました")
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(DuplicateKeys::Overwrite, Some(Node::Number(2.into())))]
+    #[case(DuplicateKeys::KeepFirst, Some(Node::Number(1.into())))]
+    #[case(DuplicateKeys::Error, None)]
+    fn test_duplicate_keys(#[case] policy: DuplicateKeys, #[case] expected: Option<Node>) {
+        let cursor = std::io::Cursor::new(r#"{"a": 1, "a": 2}"#);
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = Parser::with_options(
+            buf_reader,
+            ParserOptions {
+                duplicate_keys: policy,
+                ..Default::default()
+            },
+        );
+
+        let result = parser.parse();
+
+        match expected {
+            Some(value) => {
+                assert!(result.is_ok());
+                assert_eq!(
+                    result.unwrap(),
+                    Node::Object(node::Map::from([("a".to_string(), value)]))
+                );
+            }
+            None => {
+                assert!(result.is_err());
+                assert_eq!(
+                    result.unwrap_err().to_string(),
+                    "行: 1..1 位置: 10..12 でObjectのキー `a` が重複しています"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_depth_limit() {
+        let input = "[".repeat(100_000);
+        let cursor = std::io::Cursor::new(input);
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = Parser::new(buf_reader);
+
+        let result = parser.parse();
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "行: 1..1 位置: 129..129 でネストの深さが上限（128）を超えました"
+        );
+
+        let input = format!("{}{}", "[".repeat(3), "]".repeat(3));
+        let options = ParserOptions {
+            max_depth: 3,
+            ..Default::default()
+        };
+
+        let cursor = std::io::Cursor::new(input.clone());
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = Parser::with_options(buf_reader, options.clone());
+        assert!(parser.parse().is_ok());
+
+        let cursor = std::io::Cursor::new(format!("[{input}]"));
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = Parser::with_options(buf_reader, options);
+        assert!(matches!(
+            parser.parse(),
+            Err(Error::DepthLimitExceeded(_, _, 3))
+        ));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut parser = Parser::new(std::io::Cursor::new("{\"a\": [\n1, \"x".to_string()));
+        assert!(parser.parse().is_err());
+
+        // 途中の状態や位置を引き継がない
+        parser.reset(std::io::Cursor::new("[true,\n]".to_string()));
+        assert_eq!(
+            parser.parse().unwrap_err().to_string(),
+            "行: 2..2 位置: 1..1 で構文エラーが発生しました（bool型・null型・String型・Number型・Object・Arrayのいずれかでなければなりません）"
+        );
+
+        for input in ["1", "\"a\"", "[null]"] {
+            parser.reset(std::io::Cursor::new(input.to_string()));
+            assert_eq!(parser.parse().unwrap(), from_str(input).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_deep_nesting_without_recursion() {
+        let depth = 100_000;
+        let input = format!("{}{}", r#"{"a":["#.repeat(depth), "]}".repeat(depth));
+        let cursor = std::io::Cursor::new(input);
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = Parser::with_options(
+            buf_reader,
+            ParserOptions {
+                max_depth: usize::MAX,
+                ..Default::default()
+            },
+        );
+
+        let mut node = parser.parse().unwrap();
+        let mut count = 0;
+
+        // Node の再帰的な Drop でスタックを使い切らないよう、一段ずつ分解しながら数える
+        loop {
+            node = match node {
+                Node::Object(mut object) => object.remove("a").unwrap(),
+                Node::Array(mut array) if !array.is_empty() => array.pop().unwrap(),
+                Node::Array(_) => break,
+                _ => unreachable!(),
+            };
+            count += 1;
+        }
+
+        assert_eq!(count, depth * 2 - 1);
+    }
+
+    #[rstest::rstest]
+    #[case("{\"a\": 1}  \n\t", None)]
+    #[case(
+        "",
+        Some("行: 1..1 位置: 0..0 で構文エラーが発生しました（値がありません）")
+    )]
+    #[case(
+        "[1] xyz",
+        Some("行: 1..1 位置: 5..5 で値の後に余分な文字 `x` があります")
+    )]
+    #[case(
+        "1\n 2",
+        Some("行: 2..2 位置: 2..2 で値の後に余分な文字 `2` があります")
+    )]
+    fn test_parse_complete(#[case] input: &str, #[case] message: Option<&str>) {
+        let cursor = std::io::Cursor::new(input);
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = Parser::new(buf_reader);
+
+        let result = parser.parse_complete();
+
+        match message {
+            None => assert!(result.is_ok()),
+            Some(message) => {
+                assert!(result.is_err());
+                assert_eq!(result.unwrap_err().to_string(), message);
+            }
+        }
+
+        let cursor = std::io::Cursor::new(input);
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = Parser::with_options(
+            buf_reader,
+            ParserOptions {
+                reject_trailing_characters: true,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(parser.parse().is_ok(), message.is_none());
+    }
+
+    #[rstest::rstest]
+    #[case("]", 1..1, vec![Kind::LeftBrace, Kind::LeftBracket, Kind::String, Kind::Number, Kind::True, Kind::False, Kind::Null], Kind::RightBracket)]
+    #[case("[1 2]", 4..4, vec![Kind::Comma, Kind::RightBracket], Kind::Number)]
+    #[case(r#"{"a" 1}"#, 6..6, vec![Kind::Colon], Kind::Number)]
+    #[case("{\n1}", 1..1, vec![Kind::String, Kind::RightBrace], Kind::Number)]
+    #[case(r#"{"a": 1,: 2}"#, 9..9, vec![Kind::String], Kind::Colon)]
+    fn test_unexpected_token(
+        #[case] input: &str,
+        #[case] expected_pos: std::ops::Range<usize>,
+        #[case] expected_kinds: Vec<Kind>,
+        #[case] expected_found: Kind,
+    ) {
+        let cursor = std::io::Cursor::new(input);
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = Parser::new(buf_reader);
+
+        match parser.parse() {
+            Err(Error::UnexpectedToken {
+                line: _,
+                pos,
+                expected,
+                found,
+                message: _,
+            }) => {
+                assert_eq!(pos, expected_pos);
+                assert_eq!(expected, expected_kinds);
+                assert_eq!(found, expected_found);
+            }
+            result => panic!("{:?}", result),
+        }
+    }
+
+    #[rstest::rstest]
+    #[case("[1, 2", Some(Node::Array(vec![Node::Number(1.into()), Node::Number(2.into())])), 1)]
+    #[case("]", None, 1)]
+    #[case("[1 {\"x\": [}] 3]", Some(Node::Array(vec![Node::Number(1.into())])), 1)]
+    #[case(
+        r#"{"a": "unterminated"#,
+        Some(Node::Object(node::Map::from([("a".to_string(), Node::Null)]))),
+        2
+    )]
+    #[case(
+        r#"[{"a": 1, "a": 2}, nul, 3]"#,
+        Some(Node::Array(vec![
+            Node::Object(node::Map::from([("a".to_string(), Node::Number(1.into()))])),
+            Node::Null,
+            Node::Number(3.into()),
+        ])),
+        2
+    )]
+    #[case(r#"{"a": 1}"#, Some(Node::Object(node::Map::from([("a".to_string(), Node::Number(1.into()))]))), 0)]
+    fn test_parse_with_recovery(
+        #[case] input: &str,
+        #[case] expected: Option<Node>,
+        #[case] error_count: usize,
+    ) {
+        let cursor = std::io::Cursor::new(input);
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = Parser::with_options(
+            buf_reader,
+            ParserOptions {
+                duplicate_keys: DuplicateKeys::Error,
+                ..Default::default()
+            },
+        );
+
+        let (node, errors) = parser.parse_with_recovery();
+        assert_eq!(node, expected);
+        assert_eq!(errors.len(), error_count, "{:?}", errors);
+    }
+
+    #[test]
+    fn test_parse_with_visitor() {
+        #[derive(Default)]
+        struct Recorder(Vec<String>);
+
+        impl JsonVisitor for Recorder {
+            fn on_object_start(
+                &mut self,
+                line: std::ops::Range<usize>,
+                pos: std::ops::Range<usize>,
+            ) {
+                self.0.push(format!("{{ {line:?} {pos:?}"));
+            }
+
+            fn on_object_end(&mut self, line: std::ops::Range<usize>, pos: std::ops::Range<usize>) {
+                self.0.push(format!("}} {line:?} {pos:?}"));
+            }
+
+            fn on_array_start(
+                &mut self,
+                line: std::ops::Range<usize>,
+                pos: std::ops::Range<usize>,
+            ) {
+                self.0.push(format!("[ {line:?} {pos:?}"));
+            }
+
+            fn on_array_end(&mut self, line: std::ops::Range<usize>, pos: std::ops::Range<usize>) {
+                self.0.push(format!("] {line:?} {pos:?}"));
+            }
+
+            fn on_key(
+                &mut self,
+                key: &str,
+                line: std::ops::Range<usize>,
+                pos: std::ops::Range<usize>,
+            ) {
+                self.0.push(format!("{key} {line:?} {pos:?}"));
+            }
+
+            fn on_value(
+                &mut self,
+                value: &Node,
+                line: std::ops::Range<usize>,
+                pos: std::ops::Range<usize>,
+            ) {
+                self.0.push(format!("{value:?} {line:?} {pos:?}"));
+            }
+        }
+
+        let cursor = std::io::Cursor::new("{\"a\": [1, true]}\n\"b\"");
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = Parser::new(buf_reader);
+        let mut recorder = Recorder::default();
+
+        assert!(parser.parse_with_visitor(&mut recorder).unwrap());
+        assert!(parser.parse_with_visitor(&mut recorder).unwrap());
+        assert!(!parser.parse_with_visitor(&mut recorder).unwrap());
+        assert_eq!(
+            recorder.0,
+            vec![
+                "{ 1..1 1..1",
+                "a 1..1 2..4",
+                "[ 1..1 7..7",
+                "Number(Number(1)) 1..1 8..8",
+                "Bool(true) 1..1 11..14",
+                "] 1..1 15..15",
+                "} 1..1 16..16",
+                "String(\"b\") 2..2 1..3",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_documents() {
+        let input = "{\"a\": 1}\n[2]\n\n\"3\" 4 true{}[";
+        let cursor = std::io::Cursor::new(input);
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut documents = Parser::new(buf_reader).into_documents();
+
+        assert_eq!(
+            documents.next().unwrap().unwrap(),
+            Node::Object(node::Map::from([("a".to_string(), Node::Number(1.into()))]))
+        );
+        assert_eq!(
+            documents.next().unwrap().unwrap(),
+            Node::Array(vec![Node::Number(2.into())])
+        );
+        assert_eq!(documents.next().unwrap().unwrap(), Node::String("3".into()));
+        assert_eq!(documents.next().unwrap().unwrap(), Node::Number(4.into()));
+        assert_eq!(documents.next().unwrap().unwrap(), Node::Bool(true));
+        assert_eq!(
+            documents.next().unwrap().unwrap(),
+            Node::Object(node::Map::new())
+        );
+        assert!(documents.next().unwrap().is_err());
+        assert!(documents.next().is_none());
+    }
+
+    #[rstest::rstest]
+    #[case(r#"[{"a": 1, "a": 2}, {"b": 3}] [4]"#, None)]
+    #[case(r#"[[1, 2, 3], {"b": 3}] [4]"#, Some(3))]
+    fn test_build_after_error(#[case] input: &str, #[case] max_nodes: Option<usize>) {
+        let options = ParserOptions {
+            duplicate_keys: DuplicateKeys::Error,
+            max_nodes,
+            ..Default::default()
+        };
+        let mut parser = Parser::with_options(std::io::Cursor::new(input), options);
+
+        assert!(parser.parse_spanned().is_err());
+        // 閉じていない Array・Object の途中から解析を再開しない
+        assert!(parser.parse_spanned().unwrap().is_none());
+        assert!(parser.parse_with_map::<node::map::Sorted>().is_err());
+    }
+
+    #[test]
+    fn test_documents_after_error() {
+        let input = r#"[1] {"a": 1, "a": 2, "b": 3} [4]"#;
+        let options = ParserOptions {
+            duplicate_keys: DuplicateKeys::Error,
+            ..Default::default()
+        };
+        let mut documents =
+            Parser::with_options(std::io::Cursor::new(input), options).into_documents();
+
+        assert_eq!(
+            documents.next().unwrap().unwrap(),
+            Node::Array(vec![Node::Number(1.into())])
+        );
+        assert!(matches!(
+            documents.next(),
+            Some(Err(Error::DuplicateKey(_, _, key))) if key == "a"
+        ));
+        // 拒否した Object の途中から解析を再開しない
+        assert!(documents.next().is_none());
+        assert!(documents.next().is_none());
+    }
+
+    #[test]
+    fn test_parse_into() {
+        #[derive(macro_deserialize::Deserialize, std::fmt::Debug, PartialEq)]
+        struct Item {
+            id: u32,
+            tags: Vec<String>,
+        }
+
+        // 読み飛ばす値は重複していてもエラーとならない
+        let input = r#"{"skip": {"a": [1, {"b": 2}]}, "id": 7, "skip": 0, "tags": ["x"]}"#;
+        let options = ParserOptions {
+            duplicate_keys: DuplicateKeys::Error,
+            ..Default::default()
+        };
+        let mut parser = Parser::with_options(std::io::Cursor::new(input), options);
+        assert_eq!(
+            parser.parse_into::<Item>().unwrap(),
+            Item {
+                id: 7,
+                tags: vec!["x".into()]
+            }
+        );
+
+        let mut parser = Parser::new(std::io::Cursor::new(r#"{"id": 1}"#));
+        match parser.parse_into::<Item>() {
+            Err(Error::ConversionError(e)) => {
+                assert_eq!(e.path().to_string(), "/tags");
+                assert!(matches!(e.inner(), node::Error::Missing { field } if field == "tags"));
+            }
+            result => panic!("{result:?}"),
+        }
+    }
+
+    #[rstest::rstest]
+    #[case(r#"{"bytes": [1, 2]}"#, Ok(Some(vec![1, 2])))]
+    #[case(r#"{"bytes": []}"#, Ok(Some(vec![])))]
+    #[case(r#"{}"#, Ok(None))]
+    #[case(r#"{"bytes": [1, 300]}"#, Err("/bytes/1"))]
+    fn test_parse_into_optional_vec(
+        #[case] input: &str,
+        #[case] expected: Result<Option<Vec<u8>>, &str>,
+    ) {
+        #[derive(macro_deserialize::Deserialize, std::fmt::Debug)]
+        struct Item {
+            bytes: Option<Vec<u8>>,
+        }
+
+        let mut parser = Parser::new(std::io::Cursor::new(input));
+        let result = parser.parse_into::<Item>().map(|item| item.bytes);
+        match (result, expected) {
+            (Ok(bytes), Ok(expected)) => assert_eq!(bytes, expected),
+            (Err(Error::ConversionError(e)), Err(path)) => assert_eq!(e.path().to_string(), path),
+            (result, expected) => panic!("{result:?} {expected:?}"),
+        }
+    }
+
+    #[test]
+    fn test_serialize_generic() {
+        #[derive(macro_deserialize::Serialize)]
+        struct Wrapper<'a, T, U: Copy> {
+            x: T,
+            y: &'a [U],
+        }
+
+        let wrapper = Wrapper {
+            x: Some("a"),
+            y: &[1u8, 2],
+        };
+        let mut bytes = Vec::new();
+        node::serializer::write_json(&wrapper, &mut bytes).unwrap();
+        assert_eq!(bytes, br#"{"x":"a","y":[1,2]}"#);
+        assert_eq!(
+            node::ToNode::to_node(&wrapper),
+            node::json!({ "x": "a", "y": [1, 2] })
+        );
+    }
+
+    #[test]
+    fn test_serialize_round_trip() {
+        #[derive(
+            macro_deserialize::Deserialize, macro_deserialize::Serialize, std::fmt::Debug, PartialEq,
+        )]
+        struct Item {
+            id: u32,
+            score: f64,
+            name: Option<String>,
+            tags: Vec<String>,
+            pair: (i8, bool),
+            nested: Nested,
+            counts: std::collections::BTreeMap<String, u32>,
+        }
+
+        #[derive(
+            macro_deserialize::Deserialize, macro_deserialize::Serialize, std::fmt::Debug, PartialEq,
+        )]
+        struct Nested {
+            value: Option<u64>,
+            limit: Option<std::num::NonZeroUsize>,
+        }
+
+        let item = Item {
+            id: 1,
+            score: 0.5,
+            name: None,
+            tags: vec!["a".into()],
+            pair: (-1, true),
+            nested: Nested {
+                value: Some(2),
+                limit: std::num::NonZeroUsize::new(5),
+            },
+            counts: std::collections::BTreeMap::from([("x".into(), 3)]),
+        };
+        let node = node::ToNode::to_node(&item);
+        assert_eq!(
+            node,
+            node::json!({
+                "id": 1,
+                "score": 0.5,
+                "name": null,
+                "tags": ["a"],
+                "pair": [-1, true],
+                "nested": { "value": 2, "limit": 5 },
+                "counts": { "x": 3 }
+            })
+        );
+        let json = node::serializer::to_string(&node);
+        let mut parser = Parser::new(std::io::Cursor::new(json));
+        assert_eq!(parser.parse_into::<Item>().unwrap(), item);
+
+        let mut json = Vec::new();
+        node::serializer::write_json(&item, &mut json).unwrap();
+        assert_eq!(
+            json,
+            br#"{"id":1,"score":0.5,"name":null,"tags":["a"],"pair":[-1,true],"nested":{"value":2,"limit":5},"counts":{"x":3}}"#
+        );
+        let mut parser = Parser::new(std::io::Cursor::new(json));
+        assert_eq!(parser.parse_into::<Item>().unwrap(), item);
+
+        let json = node::serializer::to_string(&node).replace(r#""limit":5"#, r#""limit":0"#);
+        let Err(Error::ConversionError(error)) = from_str::<Item>(&json) else {
+            panic!("{json}");
+        };
+        assert_eq!(error.path().to_string(), "/nested/limit");
+    }
+
+    #[test]
+    fn test_parse_into_path() {
+        #[derive(macro_deserialize::Deserialize, std::fmt::Debug)]
+        #[allow(dead_code)]
+        struct Outer {
+            deep_nested_i16: Vec<Inner>,
+        }
+
+        #[derive(macro_deserialize::Deserialize, std::fmt::Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            v: (i16, Option<Vec<i16>>),
+        }
+
+        let input = r#"{"deep_nested_i16": [{"v": [1, null]}, {"v": [2, [40000]]}]}"#;
+        let error = Parser::new(std::io::Cursor::new(input))
+            .parse_into::<Outer>()
+            .unwrap_err();
+        let Error::ConversionError(e) = &error else {
+            panic!("{error:?}")
+        };
+        assert_eq!(e.path().to_string(), "/deep_nested_i16/1/v/1/0");
+        assert_eq!(
+            error.to_string(),
+            "`/deep_nested_i16/1/v/1/0` で値を i16 に変換できません（40000 は範囲外か整数ではありません）"
+        );
+
+        let input = r#"{"deep_nested_i16": [{"w": []}]}"#;
+        let error = Parser::new(std::io::Cursor::new(input))
+            .parse_into::<Outer>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "`/deep_nested_i16/0/v` でJSONオブジェクトから `v` が読み取れません"
+        );
+    }
+
+    #[test]
+    fn test_serialize_recursive() {
+        #[derive(
+            macro_deserialize::Deserialize, macro_deserialize::Serialize, std::fmt::Debug, PartialEq,
+        )]
+        struct Tree {
+            value: u8,
+            next: Option<Box<Tree>>,
+            shared: Option<std::sync::Arc<String>>,
+        }
+
+        let input = r#"{"value":1,"next":{"value":2,"next":null,"shared":"s"},"shared":null}"#;
+        let tree = Parser::new(std::io::Cursor::new(input))
+            .parse_into::<Tree>()
+            .unwrap();
+        assert_eq!(
+            tree,
+            Tree {
+                value: 1,
+                next: Some(Box::new(Tree {
+                    value: 2,
+                    next: None,
+                    shared: Some(std::sync::Arc::new("s".into())),
+                })),
+                shared: None,
+            }
+        );
+        let mut json = Vec::new();
+        node::serializer::write_json(&tree, &mut json).unwrap();
+        assert_eq!(json, input.as_bytes());
+    }
+
+    #[rstest::rstest]
+    #[case(
+        r#"{"id": "1", "tags": []}"#,
+        "/id",
+        "`id` の string の値は u32 に変換できません"
+    )]
+    #[case(
+        r#"{"id": -1, "tags": []}"#,
+        "/id",
+        "`id` の値を u32 に変換できません（-1 は範囲外か整数ではありません）"
+    )]
+    #[case(
+        r#"{"id": 1, "tags": {}}"#,
+        "/tags",
+        "`tags` の object の値は Vec<String> に変換できません"
+    )]
+    #[case(
+        r#"{"id": 1, "tags": ["a", null]}"#,
+        "/tags/1",
+        "`tags` の null の値は String に変換できません"
+    )]
+    #[case("[]", "", "array の値は Item に変換できません")]
+    fn test_parse_into_mismatch(#[case] input: &str, #[case] path: &str, #[case] expected: &str) {
+        #[derive(macro_deserialize::Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Item {
+            id: u32,
+            tags: Vec<String>,
+        }
+
+        let mut parser = Parser::new(std::io::Cursor::new(input));
+        match parser.parse_into::<Item>() {
+            Err(Error::ConversionError(e)) => {
+                assert_eq!(e.path().to_string(), path);
+                assert_eq!(e.inner().to_string(), expected);
+            }
+            result => panic!("{result:?}"),
+        }
+    }
+
+    #[rstest::rstest]
+    #[case(r#"{"name": 5}"#, Some(("/name", "`name` の number の値は String に変換できません")))]
+    #[case(r#"{"count": "1"}"#, Some(("/count", "`count` の string の値は u8 に変換できません")))]
+    #[case(r#"{"ratio": true}"#, Some(("/ratio", "`ratio` の boolean の値は f64 に変換できません")))]
+    #[case(r#"{"flag": 0}"#, Some(("/flag", "`flag` の number の値は bool に変換できません")))]
+    #[case(r#"{"tags": "a"}"#, Some(("/tags", "`tags` の string の値は Vec<String> に変換できません")))]
+    #[case(r#"{"pair": {}}"#, Some(("/pair", "`pair` の object の値は (u8,u8) に変換できません")))]
+    #[case(
+        r#"{"name": null, "count": null, "ratio": null, "flag": null, "tags": null}"#,
+        None
+    )]
+    #[case(r#"{"pair": null}"#, None)]
+    fn test_parse_into_optional_mismatch(
+        #[case] input: &str,
+        #[case] expected: Option<(&str, &str)>,
+    ) {
+        #[derive(macro_deserialize::Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Item {
+            name: Option<String>,
+            count: Option<u8>,
+            ratio: Option<f64>,
+            flag: Option<bool>,
+            tags: Option<Vec<String>>,
+            pair: Option<(u8, u8)>,
+        }
+
+        let mut parser = Parser::new(std::io::Cursor::new(input));
+        match (parser.parse_into::<Item>(), expected) {
+            (Err(Error::ConversionError(e)), Some((path, message))) => {
+                assert_eq!(e.path().to_string(), path);
+                assert_eq!(e.inner().to_string(), message);
+            }
+            (Ok(item), None) => {
+                assert!(item.name.is_none() && item.count.is_none() && item.tags.is_none());
+            }
+            (result, _) => panic!("{result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_into_error_kind() {
+        #[derive(macro_deserialize::Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Item {
+            id: u8,
+        }
+
+        let parse = |input: &str| {
+            let mut parser = Parser::new(std::io::Cursor::new(input.to_string()));
+            match parser.parse_into::<Item>() {
+                Err(Error::ConversionError(e)) => e,
+                result => panic!("{result:?}"),
+            }
+        };
+        assert!(matches!(parse("{}").inner(), node::Error::Missing { field } if field == "id"));
+        assert!(matches!(
+            parse(r#"{"id": true}"#).inner(),
+            node::Error::TypeMismatch { field: Some(field), expected, found: node::Kind::Bool }
+                if field == "id" && expected == "u8"
+        ));
+        assert!(matches!(
+            parse(r#"{"id": 256}"#).inner(),
+            node::Error::InvalidValue { field: Some(field), expected, .. }
+                if field == "id" && expected == "u8"
+        ));
+    }
+
+    #[rstest::rstest]
+    #[case(r#"{"big": -1, "huge": 1}"#, Some((-1, 1)))]
+    #[case(
+        r#"{"big": -9223372036854775809, "huge": 18446744073709551616}"#,
+        cfg!(feature = "arbitrary_precision")
+            .then_some((i64::MIN as i128 - 1, u64::MAX as u128 + 1))
+    )]
+    #[case(
+        r#"{"big": -170141183460469231731687303715884105729, "huge": 0}"#,
+        None
+    )]
+    #[case(r#"{"big": 0, "huge": -1}"#, None)]
+    #[case(r#"{"big": 1.5, "huge": 0}"#, None)]
+    #[case(r#"{"big": 1.0, "huge": 1e2}"#, Some((1, 100)))]
+    fn test_parse_into_wide_integer(#[case] input: &str, #[case] expected: Option<(i128, u128)>) {
+        #[derive(
+            macro_deserialize::Deserialize, macro_deserialize::Serialize, Debug, PartialEq,
+        )]
+        struct Wide {
+            big: i128,
+            huge: u128,
+        }
+
+        let result = from_str::<Wide>(input);
+        assert_eq!(
+            result.as_ref().ok().map(|wide| (wide.big, wide.huge)),
+            expected
+        );
+        if let Ok(wide) = result {
+            assert_eq!(
+                from_str::<(i128, u128)>(&format!("[{}, {}]", wide.big, wide.huge)).unwrap(),
+                (wide.big, wide.huge)
+            );
+            assert_eq!(roundtrip(&wide).unwrap(), wide);
+        }
+    }
+
+    #[test]
+    fn test_parse_into_number_policy() {
+        #[derive(macro_deserialize::Deserialize, Debug, PartialEq)]
+        struct Strict {
+            count: u8,
+            #[json(number = "lossy")]
+            ratio: i32,
+            #[json(number = "lossy")]
+            samples: Vec<u8>,
+            #[json(number = "lossy")]
+            limit: Option<i8>,
+        }
+
+        #[derive(macro_deserialize::Deserialize, Debug, PartialEq)]
+        #[json(number = "lossy")]
+        struct Lossy {
+            count: u8,
+            #[json(number = "strict")]
+            exact: u8,
+        }
+
+        let input = r#"{"count": 1, "ratio": 3.9, "samples": [-1, 2.5, 300], "limit": -1e9}"#;
+        let value: Strict = from_str(input).unwrap();
+        assert_eq!(
+            value,
+            Strict {
+                count: 1,
+                ratio: 3,
+                samples: vec![0, 2, 255],
+                limit: Some(-128),
+            }
+        );
+        assert!(from_str::<Strict>(r#"{"count": 1.5, "ratio": 0, "samples": []}"#).is_err());
+        assert!(from_str::<Strict>(r#"{"count": 3.5, "ratio": 0, "samples": []}"#).is_err());
+        let value: Strict = from_str(r#"{"count": 3.0, "ratio": 0, "samples": []}"#).unwrap();
+        assert_eq!(value.count, 3);
+        let value: Strict = from_str(r#"{"count": 1e2, "ratio": 0, "samples": []}"#).unwrap();
+        assert_eq!(value.count, 100);
+        let error = from_str::<Strict>(r#"{"count": 1, "ratio": "3", "samples": []}"#).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "`/ratio` で`ratio` の string の値は i32 に変換できません"
+        );
+
+        let value: Lossy = from_str(r#"{"count": 256, "exact": 2}"#).unwrap();
+        assert_eq!(
+            value,
+            Lossy {
+                count: 255,
+                exact: 2
+            }
+        );
+        assert!(from_str::<Lossy>(r#"{"count": 1, "exact": 2.5}"#).is_err());
+    }
+
+    #[test]
+    fn test_rename() {
+        #[derive(
+            macro_deserialize::Deserialize, macro_deserialize::Serialize, Debug, PartialEq,
+        )]
+        struct Event {
+            #[json(rename = "createdAt")]
+            created_at: u64,
+            #[json(rename = "user-tags")]
+            tags: Vec<String>,
+            name: String,
+        }
+
+        let input = r#"{"createdAt": 1700000000, "user-tags": ["a"], "name": "x", "created_at": "ignored"}"#;
+        let event: Event = from_str(input).unwrap();
+        assert_eq!(
+            event,
+            Event {
+                created_at: 1_700_000_000,
+                tags: vec!["a".into()],
+                name: "x".into(),
+            }
+        );
+        assert_eq!(
+            node::ToNode::to_node(&event),
+            node::json!({ "createdAt": 1700000000, "user-tags": ["a"], "name": "x" })
+        );
+        let mut json = Vec::new();
+        node::serializer::write_json(&event, &mut json).unwrap();
+        assert_eq!(
+            json,
+            br#"{"createdAt":1700000000,"user-tags":["a"],"name":"x"}"#
+        );
+        assert_eq!(roundtrip(&event).unwrap(), event);
+
+        let error = from_str::<Event>(r#"{"created_at": 1, "name": "x"}"#).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "`/createdAt` でJSONオブジェクトから `createdAt` が読み取れません"
+        );
+        let Error::ConversionError(error) =
+            from_str::<Event>(r#"{"createdAt": 1, "user-tags": [1], "name": "x"}"#).unwrap_err()
+        else {
+            unreachable!()
+        };
+        assert_eq!(error.path().to_string(), "/user-tags/0");
+    }
+
+    #[test]
+    fn test_parse_into_str_fields() {
+        use std::borrow::Cow;
+
+        #[derive(
+            macro_deserialize::Deserialize, macro_deserialize::Serialize, Debug, PartialEq,
+        )]
+        struct Label {
+            name: Box<str>,
+            note: Cow<'static, str>,
+            alias: Option<Box<str>>,
+            tags: Vec<std::sync::Arc<str>>,
+        }
+
+        let input = r#"{"name": "a", "note": "b", "alias": null, "tags": ["x", "y"]}"#;
+        let label: Label = from_str(input).unwrap();
+        assert_eq!(
+            label,
+            Label {
+                name: "a".into(),
+                note: Cow::Borrowed("b"),
+                alias: None,
+                tags: vec!["x".into(), "y".into()],
+            }
+        );
+        assert_eq!(roundtrip(&label).unwrap(), label);
+        assert!(from_str::<Label>(r#"{"name": 1, "note": "", "tags": []}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_with_map() {
+        #[derive(macro_deserialize::Deserialize, Debug, PartialEq)]
+        struct Item {
+            id: u32,
+            tags: Vec<String>,
+        }
+
+        let input = r#"{"id": 1, "tags": ["a"], "nested": {"b": null, "a": true}}"#;
+        let node = Parser::new(std::io::Cursor::new(input))
+            .parse_with_map::<node::map::Sorted>()
+            .unwrap();
+        assert_eq!(
+            node,
+            Parser::new(std::io::Cursor::new(input))
+                .parse()
+                .unwrap()
+                .into_map_kind()
+        );
+        let Node::Object(object) = &node else {
+            unreachable!()
+        };
+        let Node::Object(nested) = &object["nested"] else {
+            unreachable!()
+        };
+        assert_eq!(nested.keys().collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!(
+            <Item as node::FromNode>::from_node(&node).unwrap(),
+            Item {
+                id: 1,
+                tags: vec!["a".into()]
+            }
+        );
+
+        let node = Parser::new(std::io::Cursor::new(input))
+            .parse_with_map::<node::map::Hashed>()
+            .unwrap();
+        assert_eq!(
+            <Item as node::FromNode>::from_node(&node).unwrap(),
+            Item {
+                id: 1,
+                tags: vec!["a".into()]
+            }
+        );
+    }
+
+    #[cfg(feature = "preserve_order")]
+    #[rstest::rstest]
+    #[case(true, vec!["b", "a", "c"])]
+    #[case(false, vec!["a", "b", "c"])]
+    fn test_preserve_order(#[case] preserve_order: bool, #[case] expected: Vec<&str>) {
+        let input = r#"{"b": 1, "a": {"z": 1, "y": 2}, "c": 3, "b": 4}"#;
+        let options = ParserOptions {
+            preserve_order,
+            ..Default::default()
+        };
+        let node = Parser::with_options(std::io::Cursor::new(input), options)
+            .parse()
+            .unwrap();
+
+        let Node::Object(object) = node else {
+            unreachable!()
+        };
+        // 重複したキーは最初に現れた位置のまま上書きされる
+        assert_eq!(object.keys().collect::<Vec<_>>(), expected);
+        assert_eq!(object["b"], Node::Number(4.into()));
+        let Node::Object(nested) = &object["a"] else {
+            unreachable!()
+        };
+        assert_eq!(
+            nested.keys().collect::<Vec<_>>(),
+            if preserve_order {
+                vec!["z", "y"]
+            } else {
+                vec!["y", "z"]
+            }
+        );
+    }
+
+    #[cfg(feature = "preserve_order")]
+    #[rstest::rstest]
+    #[case(true, vec!["b", "a"], vec!["z", "y"])]
+    #[case(false, vec!["a", "b"], vec!["y", "z"])]
+    fn test_parse_spanned_preserve_order(
+        #[case] preserve_order: bool,
+        #[case] expected: Vec<&str>,
+        #[case] nested: Vec<&str>,
+    ) {
+        let input = r#"{"b": 1, "a": {"z": 1, "y": 2}}"#;
+        let options = ParserOptions {
+            preserve_order,
+            ..Default::default()
+        };
+        let node = Parser::with_options(std::io::Cursor::new(input), options)
+            .parse_spanned()
+            .unwrap()
+            .unwrap();
+
+        let SpannedValue::Object(object) = node.value else {
+            unreachable!()
+        };
+        assert_eq!(object.keys().collect::<Vec<_>>(), expected);
+        let SpannedValue::Object(a) = &object["a"].value.value else {
+            unreachable!()
+        };
+        assert_eq!(a.keys().collect::<Vec<_>>(), nested);
+    }
+
+    #[rstest::rstest]
+    #[case("9007199254740993", Ok(9007199254740993))]
+    #[case("18446744073709551615", Ok(u64::MAX))]
+    #[case("-1", Err(()))]
+    #[case("1.5", Err(()))]
+    #[case("18446744073709551616", Err(()))]
+    fn test_parse_integer(#[case] input: &str, #[case] expected: Result<u64, ()>) {
+        #[derive(macro_deserialize::Deserialize, std::fmt::Debug)]
+        struct Item {
+            id: u64,
+        }
+
+        let node: Node = from_str(input).unwrap();
+        if let Ok(expected) = expected {
+            assert_eq!(node, Node::Number(expected.into()));
+        }
+
+        let input = format!("{{\"id\": {input}}}");
+        let result = Parser::new(std::io::Cursor::new(input)).parse_into::<Item>();
+        assert_eq!(result.map(|item| item.id).map_err(|_| ()), expected);
+    }
+
+    #[rstest::rstest]
+    #[case("/a~1b/~0c/1", Some(Node::Number(3.into())))]
+    #[case("/a~1b/~0c/2", None)]
+    #[case("/a~1b/~0c/01", None)]
+    #[case("/a~1b/~0c/-", None)]
+    #[case("/a~1b/x", None)]
+    #[case("/a~1b/~0c/0/y", None)]
+    #[case("/d/0", Some(Node::Array(vec![Node::Number(1.into())])))]
+    #[case("/e", Some(Node::String("f".into())))]
+    #[case("", Some(Node::Object(node::Map::from([
+        ("a/b".into(), Node::Object(node::Map::from([(
+            "~c".into(),
+            Node::Array(vec![Node::Number(2.into()), Node::Number(3.into())])
+        )]))),
+        ("d".into(), Node::Array(vec![Node::Array(vec![Node::Number(1.into())])])),
+        ("e".into(), Node::String("f".into())),
+    ]))))]
+    fn test_parse_at(#[case] pointer: &str, #[case] expected: Option<Node>) {
+        let input = r#"{"a/b": {"~c": [2, 3]}, "d": [[1]], "e": "f"} [null]"#;
+        let mut parser = Parser::new(std::io::Cursor::new(input));
+        assert_eq!(parser.parse_at(pointer).unwrap(), expected);
+
+        // 見つかった場合も見つからなかった場合も値の末尾まで読み出している
+        assert_eq!(parser.parse().unwrap(), Node::Array(vec![Node::Null]));
+    }
+
+    #[test]
+    fn test_parse_at_invalid_pointer() {
+        let mut parser = Parser::new(std::io::Cursor::new("{}"));
+        assert!(matches!(
+            parser.parse_at("a"),
+            Err(Error::InvalidPointer(_))
+        ));
+    }
+
+    #[rstest::rstest]
+    #[case("[]", vec![])]
+    #[case("", vec![])]
+    #[case(
+        r#"[1, {"a": [2]}, "b"]"#,
+        vec![
+            Ok(Node::Number(1.into())),
+            Ok(Node::Object(node::Map::from([(
+                "a".into(),
+                Node::Array(vec![Node::Number(2.into())])
+            )]))),
+            Ok(Node::String("b".into())),
+        ]
+    )]
+    #[case("[1, 2 3]", vec![Ok(Node::Number(1.into())), Ok(Node::Number(2.into())), Err(Kind::Number)])]
+    #[case(r#"{"a": 1}"#, vec![Err(Kind::LeftBrace)])]
+    fn test_array_elements(#[case] input: &str, #[case] expected: Vec<Result<Node, Kind>>) {
+        let mut parser = Parser::new(std::io::Cursor::new(input));
+        let elements = parser
+            .array_elements()
+            .map(|result| {
+                result.map_err(|e| match e {
+                    Error::UnexpectedToken { found, .. } => found,
+                    _ => unreachable!("{e}"),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(elements, expected);
+    }
+
+    #[rstest::rstest]
+    #[case(r#"{"a": [1, "b", {"c": null}], "d": true}"#, vec![])]
+    #[case("[1, 2 3, {\"a\" 4}]", vec![Kind::Number, Kind::Number])]
+    #[case("", vec![Kind::EOF])]
+    #[case("[1, 2", vec![Kind::EOF])]
+    fn test_validate(#[case] input: &str, #[case] expected: Vec<Kind>) {
+        let result = validate(std::io::Cursor::new(input)).map_err(|errors| {
+            errors
+                .into_iter()
+                .map(|e| match e {
+                    Error::UnexpectedToken { found, .. } => found,
+                    _ => unreachable!("{e}"),
+                })
+                .collect::<Vec<_>>()
+        });
+
+        if expected.is_empty() {
+            assert!(result.is_ok());
+        } else {
+            assert_eq!(result.unwrap_err(), expected);
+        }
+    }
+
+    #[test]
+    fn test_validate_invalid_escape() {
+        // 不正なエスケープを含む文字列を読み飛ばして検証を継続する
+        let errors = validate(std::io::Cursor::new(r#"["\x", "\ud800", 1 2]"#)).unwrap_err();
+        assert!(matches!(
+            errors.as_slice(),
+            [
+                Error::InvalidToken(_, pos1, _),
+                Error::InvalidToken(_, pos2, _),
+                Error::UnexpectedToken { .. },
+            ] if *pos1 == (3..4) && *pos2 == (9..14)
+        ));
+    }
+
+    #[test]
+    fn test_validate_trailing_characters() {
+        let result = validate(std::io::Cursor::new("[1] x"));
+        assert!(matches!(
+            result.unwrap_err().as_slice(),
+            [Error::TrailingCharacters(_, _, 'x')]
+        ));
+    }
+
+    #[test]
+    fn test_from_functions() {
+        let expected: Node = Node::Array(vec![Node::String("日本語".into()), Node::Null]);
+
+        assert_eq!(from_str::<Node>(r#"["日本語", null]"#).unwrap(), expected);
+        assert_eq!(
+            from_slice::<Node>(r#" ["日本語",null] "#.as_bytes()).unwrap(),
+            expected
+        );
+        assert_eq!(
+            from_reader::<Node, _>(std::io::Cursor::new(r#"["日本語", null]"#)).unwrap(),
+            expected
+        );
+        assert!(matches!(
+            from_str::<Node>("[1] [2]"),
+            Err(Error::TrailingCharacters(_, _, '['))
+        ));
+
+        let path =
+            std::env::temp_dir().join(format!("parser_from_file_{}.json", std::process::id()));
+        std::fs::write(&path, r#"["日本語", null]"#).unwrap();
+        let result = from_file::<Node, _>(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(result.unwrap(), expected);
+
+        assert!(matches!(
+            from_file::<Node, _>(&path),
+            Err(Error::IoError(_))
+        ));
+
+        assert_eq!(
+            from_str::<(u8, Option<String>)>("[1, null]").unwrap(),
+            (1, None)
+        );
+        assert_eq!(
+            from_reader::<Vec<bool>, _>(std::io::Cursor::new("[true] ")).unwrap(),
+            [true]
+        );
+        assert!(matches!(
+            from_str::<Vec<u8>>("[1] x"),
+            Err(Error::TrailingCharacters(_, _, 'x'))
+        ));
+        assert!(matches!(
+            from_str::<u8>(""),
+            Err(Error::UnexpectedToken { .. })
+        ));
+        let error = from_slice::<Vec<u8>>(b"[1, 256]").unwrap_err();
+        let Error::ConversionError(error) = error else {
+            panic!("{error:?}");
+        };
+        assert_eq!(error.path().to_string(), "/1");
+    }
+
+    #[rstest::rstest]
+    #[case(" [1, \"a\"] ", Some(node::json!([1, "a"])))]
+    #[case("null", Some(Node::Null))]
+    #[case("[1] x", None)]
+    #[case("", None)]
+    fn test_parse_json(#[case] input: &str, #[case] expected: Option<Node>) {
+        assert_eq!(input.parse::<Json>().ok().map(Json::into_inner), expected);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        #[derive(macro_deserialize::Deserialize, std::fmt::Debug, PartialEq)]
+        struct Entry {
+            name: String,
+            ports: Vec<u16>,
+        }
+
+        let value = vec![(String::from("a\"b"), Some(1.5)), (String::new(), None)];
+        assert_eq!(roundtrip(&value).unwrap(), value);
+        assert_eq!(roundtrip(&'あ').unwrap(), 'あ');
+
+        let node: Node = node::json!({ "name": "x", "ports": [80, 443] });
+        assert_eq!(
+            from_str::<Entry>(&node::serializer::to_string_from(&node)).unwrap(),
+            Entry {
+                name: "x".into(),
+                ports: vec![80, 443],
+            }
+        );
+        // f64 の NaN は null になるため復元できない
+        assert!(matches!(
+            roundtrip(&f64::NAN),
+            Err(Error::ConversionError(_))
+        ));
+    }
+
+    #[rstest::rstest]
+    #[case("line1\nline2")]
+    #[case("\u{0}\u{1}\u{8}\u{c}\r\t\u{1f}")] // 制御文字
+    #[case("a\\b/c\"d")]
+    #[case("é日本語😀")]
+    fn test_roundtrip_escape(#[case] value: &str) {
+        assert_eq!(roundtrip(&value.to_string()).unwrap(), value);
+
+        // ASCII 以外の文字を \uXXXX やサロゲートペアとして出力しても復元できる
+        let node: Node = Node::String(value.into());
+        let options = node::serializer::SerializerOptions {
+            ensure_ascii: true,
+            escape_slash: true,
+            ..Default::default()
+        };
+        let mut serializer = node::serializer::Serializer::new(Vec::new()).options(options);
+        serializer.serialize(&node).unwrap();
+        let json = String::from_utf8(serializer.into_inner()).unwrap();
+        assert!(json.is_ascii());
+        assert_eq!(from_str::<String>(&json).unwrap(), value);
+    }
+
+    #[rstest::rstest]
+    #[case("", vec![])]
+    #[case("  \n ", vec![])]
+    #[case("null {}", vec![Node::Null, Node::Object(node::Map::new())])]
+    fn test_parse_next(#[case] input: &str, #[case] expected: Vec<Node>) {
+        let mut parser = Parser::new(std::io::Cursor::new(input));
+        let mut nodes = vec![];
+        while let Some(node) = parser.parse_next().unwrap() {
+            nodes.push(node);
+        }
+
+        assert_eq!(nodes, expected);
+        // 末尾に到達した後も None を返却する
+        assert!(parser.parse_next().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_parse_next_after_error() {
+        let input = r#"[1] {"a": 1, "a": 2, "b": 3} [4]"#;
+        let options = ParserOptions {
+            duplicate_keys: DuplicateKeys::Error,
+            ..Default::default()
+        };
+        let mut parser = Parser::with_options(std::io::Cursor::new(input), options);
+
+        assert_eq!(
+            parser.parse_next().unwrap(),
+            Some(Node::Array(vec![Node::Number(1.into())]))
+        );
+        assert!(matches!(parser.parse_next(), Err(Error::DuplicateKey(..))));
+        // 拒否した Object の途中の値を返却しない
+        assert!(parser.parse_next().unwrap().is_none());
+
+        parser.reset(std::io::Cursor::new("[4]"));
+        assert_eq!(
+            parser.parse_next().unwrap(),
+            Some(Node::Array(vec![Node::Number(4.into())]))
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("[1, 2, 3]", 9, true)]
+    #[case("[1, 2, 3]", 8, false)]
+    #[case("\"日本\"", 8, true)]
+    #[case("\"日本\"", 7, false)]
+    fn test_max_bytes(#[case] input: &str, #[case] max_bytes: usize, #[case] ok: bool) {
+        let options = ParserOptions {
+            max_bytes: Some(max_bytes),
+            ..Default::default()
+        };
+        let result = Parser::with_options(std::io::Cursor::new(input), options).parse();
+
+        if ok {
+            assert!(result.is_ok());
+        } else {
+            assert!(matches!(result, Err(Error::SizeLimitExceeded(m)) if m == max_bytes));
+        }
+    }
+
+    #[rstest::rstest]
+    #[case(Some(4), None, None)]
+    #[case(
+        Some(3),
+        None,
+        Some("行: 1..1 位置: 16..16 で Node の数が上限（3）を超えました")
+    )]
+    #[case(None, Some(5), None)]
+    #[case(
+        None,
+        Some(4),
+        Some("行: 1..1 位置: 9..13 で文字列の合計が上限（4バイト）を超えました")
+    )]
+    fn test_allocation_budget(
+        #[case] max_nodes: Option<usize>,
+        #[case] max_string_bytes: Option<usize>,
+        #[case] message: Option<&str>,
+    ) {
+        let options = ParserOptions {
+            max_nodes,
+            max_string_bytes,
+            ..Default::default()
+        };
+        let input = r#"{"ab": ["cde", 1]}"#;
+        let result = Parser::with_options(std::io::Cursor::new(input), options).parse();
+
+        match message {
+            None => assert!(result.is_ok()),
+            Some(message) => assert_eq!(result.unwrap_err().to_string(), message),
+        }
+    }
+
+    #[rstest::rstest]
+    #[case(TopLevel::AnyValue, "42", true)]
+    #[case(TopLevel::AnyValue, "\"hi\"", true)]
+    #[case(TopLevel::ObjectOrArray, "42", false)]
+    #[case(TopLevel::ObjectOrArray, "\"hi\"", false)]
+    #[case(TopLevel::ObjectOrArray, "[42]", true)]
+    #[case(TopLevel::ObjectOrArray, "{\"a\": \"hi\"}", true)]
+    #[case(TopLevel::ObjectOrArray, "", true)]
+    fn test_top_level(#[case] top_level: TopLevel, #[case] input: &str, #[case] ok: bool) {
+        let options = ParserOptions {
+            top_level,
+            ..Default::default()
+        };
+        let result = Parser::with_options(std::io::Cursor::new(input), options).parse_next();
+
+        if ok {
+            assert!(result.is_ok());
+        } else {
+            assert!(matches!(
+                result,
+                Err(Error::UnexpectedToken { expected, .. })
+                    if expected == vec![Kind::LeftBrace, Kind::LeftBracket]
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parse_spanned() {
+        let input = "[\n  {\"a\": true},\n  \"b\"\n]";
+        let mut parser = Parser::new(std::io::Cursor::new(input));
+        let node = parser.parse_spanned().unwrap().unwrap();
+
+        assert_eq!(
+            node,
+            SpannedNode {
+                span: Span {
+                    line: 1..4,
+                    pos: 1..1
+                },
+                value: SpannedValue::Array(vec![
+                    SpannedNode {
+                        span: Span {
+                            line: 2..2,
+                            pos: 3..13
+                        },
+                        value: SpannedValue::Object(node::Map::from([(
+                            "a".to_string(),
+                            SpannedEntry {
+                                key: Span {
+                                    line: 2..2,
+                                    pos: 4..6
+                                },
+                                value: SpannedNode {
+                                    span: Span {
+                                        line: 2..2,
+                                        pos: 9..12
+                                    },
+                                    value: SpannedValue::Bool(true),
+                                },
+                            }
+                        )])),
+                    },
+                    SpannedNode {
+                        span: Span {
+                            line: 3..3,
+                            pos: 3..5
+                        },
+                        value: SpannedValue::String("b".into()),
+                    },
+                ]),
+            }
+        );
+        assert!(parser.parse_spanned().unwrap().is_none());
+    }
 }
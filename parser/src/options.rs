@@ -0,0 +1,93 @@
+/// Parser の挙動を設定する
+/// 既定値は厳密な JSON の解析（Objectのキー重複時は後勝ち、ネストの深さは128まで）となる
+///
+/// # Examples
+///
+/// ```
+/// let options = parser::options::ParserOptions {
+///     allow_comments: true,
+///     allow_trailing_commas: true,
+///     ..Default::default()
+/// };
+/// let input = "[1, 2, /* three */ 3,]";
+/// let cursor = std::io::Cursor::new(input);
+/// let buf_reader = std::io::BufReader::new(cursor);
+/// let mut parser = parser::Parser::with_options(buf_reader, options);
+/// assert_eq!(
+///     parser.parse().unwrap(),
+///     node::Node::Array(vec![
+///         node::Node::Number(1.into()),
+///         node::Node::Number(2.into()),
+///         node::Node::Number(3.into()),
+///     ])
+/// );
+/// ```
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub struct ParserOptions {
+    /// Object・Array のネストの深さの上限
+    pub max_depth: usize,
+    /// Objectのキーが重複した場合の扱い
+    pub duplicate_keys: DuplicateKeys,
+    /// `// ...` と `/* ... */` のコメントを読み飛ばすか
+    pub allow_comments: bool,
+    /// Object・Array の末尾のカンマ（`[1, 2,]`）を許容するか
+    pub allow_trailing_commas: bool,
+    /// 値の後に空白以外の文字が続く場合に Error::TrailingCharacters を返却するか
+    /// 有効にすると Parser::parse は Parser::parse_complete と同じ振る舞いになる
+    pub reject_trailing_characters: bool,
+    /// reader から読み出せるバイト数の上限
+    /// 上限を超えて読み出すと Error::SizeLimitExceeded を返却する（None の場合は上限なし）
+    pub max_bytes: Option<usize>,
+    /// 値を１つ解析する間に構築できる Node の数の上限
+    /// 上限を超えると Error::NodeLimitExceeded を返却する（None の場合は上限なし）
+    pub max_nodes: Option<usize>,
+    /// 値を１つ解析する間に構築できる文字列（キーと Node::String）のバイト数の合計の上限
+    /// 上限を超えると Error::StringLimitExceeded を返却する（None の場合は上限なし）
+    pub max_string_bytes: Option<usize>,
+    /// 最上位に許容する値の種類
+    pub top_level: TopLevel,
+    /// Objectのキーを入力に現れた順に保持するか
+    /// false の場合は `preserve_order` フィーチャーが無効な場合と同じくキーの昇順に並べる
+    #[cfg(feature = "preserve_order")]
+    pub preserve_order: bool,
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 128,
+            duplicate_keys: DuplicateKeys::default(),
+            allow_comments: false,
+            allow_trailing_commas: false,
+            reject_trailing_characters: false,
+            max_bytes: None,
+            max_nodes: None,
+            max_string_bytes: None,
+            top_level: TopLevel::default(),
+            #[cfg(feature = "preserve_order")]
+            preserve_order: false,
+        }
+    }
+}
+
+/// Objectのキーが重複した場合の扱いを表す
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateKeys {
+    /// 後から現れた値で上書きする
+    #[default]
+    Overwrite,
+    /// 最初に現れた値を保持する
+    KeepFirst,
+    /// Error::DuplicateKey を返却する
+    Error,
+}
+
+/// 最上位に許容する値の種類を表す
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TopLevel {
+    /// 全ての値を許容する（RFC 8259）
+    #[default]
+    AnyValue,
+    /// Object・Array のみ許容する（RFC 4627）
+    ObjectOrArray,
+}
@@ -0,0 +1,155 @@
+use crate::Error;
+use crate::boundary::Boundary;
+use crate::options::ParserOptions;
+use crate::streaming::{JsonEvent, StreamingParser};
+
+/// 任意の位置で分割されたバイト列を受け取りながら、JSONイベントを返却する
+/// reader を持たないため、イベントループやプロトコルのデコーダーに組み込める
+/// 受け取ったバイト列で読み出し終えたトークンの分だけイベントを返却し、残りは次の feed か finish で返却する
+///
+/// # Examples
+///
+/// ```
+/// use parser::push::PushParser;
+/// use parser::streaming::JsonEvent;
+///
+/// let mut parser = PushParser::new();
+/// let mut events = vec![];
+/// for chunk in [&b"{\"ke"[..], b"y\": [1", b"0, tr", b"ue]}"] {
+///     events.extend(parser.feed(chunk).unwrap());
+/// }
+/// events.extend(parser.finish().unwrap());
+/// assert_eq!(
+///     events,
+///     vec![
+///         JsonEvent::StartObject,
+///         JsonEvent::Key("key".into()),
+///         JsonEvent::StartArray,
+///         JsonEvent::Value(node::Node::Number(10.into())),
+///         JsonEvent::Value(node::Node::Bool(true)),
+///         JsonEvent::EndArray,
+///         JsonEvent::EndObject,
+///     ]
+/// );
+/// ```
+pub struct PushParser {
+    events: StreamingParser<std::collections::VecDeque<u8>>,
+    boundary: Boundary,
+    /// 受け取ったバイト数
+    bytes: usize,
+    max_bytes: Option<usize>,
+}
+
+impl Default for PushParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PushParser {
+    /// パーサーを生成して返却する
+    pub fn new() -> Self {
+        Self::with_options(ParserOptions::default())
+    }
+
+    /// 挙動を設定したパーサーを生成して返却する
+    /// ParserOptions::duplicate_keys はキーを保持しないため評価しない
+    pub fn with_options(options: ParserOptions) -> Self {
+        Self {
+            boundary: Boundary::new(options.allow_comments),
+            bytes: 0,
+            max_bytes: options.max_bytes,
+            events: StreamingParser::with_options(std::collections::VecDeque::new(), options),
+        }
+    }
+
+    /// バイト列を受け取り、読み出し終えたトークンまでのイベントを返却する
+    /// エラーは StreamingParser::next_event と同様に返却し、以降は空のイベントを返却する
+    pub fn feed(&mut self, bytes: &[u8]) -> Result<Vec<JsonEvent>, Error> {
+        // 解析を待つバイト列も上限を超えないようにする
+        self.bytes += bytes.len();
+        if let Some(max_bytes) = self.max_bytes
+            && self.bytes > max_bytes
+        {
+            return Err(Error::SizeLimitExceeded(max_bytes));
+        }
+
+        self.boundary.scan(bytes);
+        self.events.get_mut().extend(bytes);
+
+        let mut events = vec![];
+        while let Some(event) = self.events.next_event_within(Some(self.boundary.tokens))? {
+            events.push(event);
+        }
+
+        Ok(events)
+    }
+
+    /// 入力の終わりとして、残りの全てのイベントを返却する
+    /// 値の途中で終了している場合は構文エラーを返却する
+    pub fn finish(mut self) -> Result<Vec<JsonEvent>, Error> {
+        let mut events = vec![];
+        while let Some(event) = self.events.next_event()? {
+            events.push(event);
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use node::Node;
+    use pretty_assertions::assert_eq;
+
+    #[rstest::rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(7)]
+    fn test_push_parser(#[case] size: usize) {
+        let input = "{\"a\": [\"\\\"x\", -1.5e2, null], \"b\": {}} \"日本語\" 3";
+        let mut parser = PushParser::new();
+        let mut events = vec![];
+
+        for chunk in input.as_bytes().chunks(size) {
+            events.extend(parser.feed(chunk).unwrap());
+        }
+        events.extend(parser.finish().unwrap());
+
+        assert_eq!(
+            events,
+            vec![
+                JsonEvent::StartObject,
+                JsonEvent::Key("a".into()),
+                JsonEvent::StartArray,
+                JsonEvent::Value(Node::String("\"x".into())),
+                JsonEvent::Value(Node::Number((-150.0).into())),
+                JsonEvent::Value(Node::Null),
+                JsonEvent::EndArray,
+                JsonEvent::Key("b".into()),
+                JsonEvent::StartObject,
+                JsonEvent::EndObject,
+                JsonEvent::EndObject,
+                JsonEvent::Value(Node::String("日本語".into())),
+                JsonEvent::Value(Node::Number(3.into())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_push_parser_incomplete() {
+        let mut parser = PushParser::new();
+
+        // 数値は区切り文字を受け取るまで返却しない
+        assert_eq!(parser.feed(b"[12").unwrap(), vec![JsonEvent::StartArray]);
+        assert_eq!(
+            parser.feed(b"3,").unwrap(),
+            vec![JsonEvent::Value(Node::Number(123.into()))]
+        );
+        assert!(matches!(
+            parser.finish(),
+            Err(Error::UnexpectedToken { .. })
+        ));
+    }
+}
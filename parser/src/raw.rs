@@ -0,0 +1,121 @@
+use node::serializer::{Formatter, Serializer, WriteJson};
+use node::{Node, ToNode};
+
+use crate::Error;
+
+/// 解析せずに入力の表記のまま保持する JSONの値を表現する
+/// 空白や数値の表記も含めて保持し、serializer で出力する場合はそのまま書き込むため、
+/// 中継する値を解釈せずにバイト列のまま受け渡せる
+///
+/// # Examples
+///
+/// ```
+/// let input = r#"{"type": "event", "payload": {"b": 1,  "a": [1.50]}}"#;
+/// let document = parser::cst::parse_lossless(input.as_bytes()).unwrap();
+/// let object = document.value.as_object().unwrap();
+/// assert_eq!(object.get("type").unwrap().to_node(), node::Node::String("event".into()));
+///
+/// let payload = object.get("payload").unwrap().to_raw_value();
+/// assert_eq!(payload.get(), r#"{"b": 1,  "a": [1.50]}"#);
+///
+/// let mut output = Vec::new();
+/// node::serializer::write_json(&("forwarded", &payload), &mut output).unwrap();
+/// assert_eq!(output, br#"["forwarded",{"b": 1,  "a": [1.50]}]"#);
+/// ```
+#[derive(std::fmt::Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RawValue(Box<str>);
+
+impl RawValue {
+    /// １つの JSONの値として正しいかを Node を構築せずに検証して保持する
+    /// 前後の空白は取り除く
+    pub fn from_string(text: String) -> Result<Self, Error> {
+        if let Err(mut errors) = crate::validate(text.as_bytes()) {
+            return Err(errors.remove(0));
+        }
+
+        let trimmed = text.trim_matches([' ', '\t', '\n', '\r']);
+        if trimmed.len() == text.len() {
+            Ok(Self(text.into_boxed_str()))
+        } else {
+            Ok(Self(trimmed.into()))
+        }
+    }
+
+    /// 検証済みの表記から生成する
+    pub(crate) fn from_validated(text: String) -> Self {
+        Self(text.into_boxed_str())
+    }
+
+    /// 保持している表記を返却する
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+
+    /// 保持している表記を String として返却する
+    pub fn into_string(self) -> String {
+        self.0.into_string()
+    }
+}
+
+impl std::str::FromStr for RawValue {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Self::from_string(s.to_string())
+    }
+}
+
+/// 保持している表記をそのまま出力する
+impl std::fmt::Display for RawValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// 保持している表記を解析した Node に変換する
+impl ToNode for RawValue {
+    fn to_node(&self) -> Node {
+        crate::from_str(&self.0).expect("RawValue は検証済みの表記のみを保持する")
+    }
+}
+
+/// 保持している表記を Formatter::write_raw_fragment でそのまま書き込む
+impl WriteJson for RawValue {
+    fn write_json<W: std::io::Write, F: Formatter>(
+        &self,
+        serializer: &mut Serializer<W, F>,
+    ) -> Result<(), node::serializer::Error> {
+        serializer.serialize_raw(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest::rstest]
+    #[case(" [1, 2.50] \n", Some("[1, 2.50]"))]
+    #[case("\"a\"", Some("\"a\""))]
+    #[case("", None)]
+    #[case("[1,]", None)]
+    #[case("1 2", None)]
+    fn test_from_string(#[case] input: &str, #[case] expected: Option<&str>) {
+        let raw = input.parse::<RawValue>();
+        assert_eq!(raw.as_ref().ok().map(RawValue::get), expected);
+    }
+
+    #[test]
+    fn test_to_node() {
+        let raw: RawValue = r#"{"a": [true]}"#.parse().unwrap();
+        assert_eq!(raw.to_node(), node::json!({ "a": [true] }));
+        assert_eq!(raw.to_string(), r#"{"a": [true]}"#);
+    }
+
+    #[test]
+    fn test_write_json_pretty() {
+        let raw: RawValue = "[1,  2]".parse().unwrap();
+        let mut serializer = Serializer::pretty(Vec::new());
+        vec![raw].write_json(&mut serializer).unwrap();
+        assert_eq!(serializer.into_inner(), b"[\n  [1,  2]\n]");
+    }
+}
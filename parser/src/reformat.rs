@@ -0,0 +1,294 @@
+use node::Node;
+use node::serializer::{CompactFormatter, Formatter, PrettyFormatter};
+
+use crate::Error;
+use crate::options::ParserOptions;
+use crate::streaming::{JsonEvent, StreamingParser};
+
+/// reformat が出力する JSONの体裁を表す
+#[derive(std::fmt::Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+    /// 空白を含まない JSONに縮める
+    #[default]
+    Compact,
+    /// 要素ごとに改行し、指定した数の空白で字下げする
+    Pretty(usize),
+}
+
+/// reader から読み取れる JSONを Node を構築せずに style の体裁で writer に書き込む
+/// StreamingParser のイベントを順に Formatter に渡し、文字列・数値はトークンの元の表記のまま書き込む
+/// 連結された複数の値は改行で区切って出力する
+/// 途中までの出力を残さないよう、全て解析できた場合のみ writer に書き込む
+///
+/// # Examples
+///
+/// ```
+/// use parser::reformat::{Style, reformat};
+///
+/// let input = r#"{ "a" : [1, 2.5, "x"], "b": {} }"#;
+/// let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+/// let mut output = Vec::new();
+/// reformat(reader, &mut output, Style::Compact).unwrap();
+/// assert_eq!(output, br#"{"a":[1,2.5,"x"],"b":{}}"#);
+///
+/// let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+/// let mut output = Vec::new();
+/// reformat(reader, &mut output, Style::Pretty(2)).unwrap();
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "{\n  \"a\": [\n    1,\n    2.5,\n    \"x\"\n  ],\n  \"b\": {}\n}"
+/// );
+/// ```
+pub fn reformat<R, W>(reader: R, writer: &mut W, style: Style) -> Result<(), Error>
+where
+    R: std::io::BufRead + std::fmt::Debug,
+    W: std::io::Write,
+{
+    reformat_with_options(reader, writer, style, ParserOptions::default())
+}
+
+/// 挙動を設定して reformat と同様に整形する
+/// コメントは出力せず、末尾の `,` は取り除く
+/// ParserOptions::duplicate_keys はキーを保持しないため評価しない
+///
+/// # Examples
+///
+/// ```
+/// use parser::options::ParserOptions;
+/// use parser::reformat::{Style, reformat_with_options};
+///
+/// let input = "[1E2, /* c */ \"a\\nb\", 18446744073709551616,]";
+/// let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+/// let options = ParserOptions {
+///     allow_comments: true,
+///     allow_trailing_commas: true,
+///     ..Default::default()
+/// };
+/// let mut output = Vec::new();
+/// reformat_with_options(reader, &mut output, Style::Compact, options).unwrap();
+/// assert_eq!(output, br#"[1E2,"a\nb",18446744073709551616]"#);
+/// ```
+pub fn reformat_with_options<R, W>(
+    reader: R,
+    writer: &mut W,
+    style: Style,
+    options: ParserOptions,
+) -> Result<(), Error>
+where
+    R: std::io::BufRead + std::fmt::Debug,
+    W: std::io::Write,
+{
+    let mut parser = StreamingParser::with_options(reader, options);
+    parser.set_recording();
+
+    let mut output = Vec::new();
+    match style {
+        Style::Compact => Reformatter::new(&mut output, CompactFormatter).run(parser)?,
+        Style::Pretty(width) => {
+            let indent = " ".repeat(width);
+            Reformatter::new(&mut output, PrettyFormatter::with_indent(&indent)).run(parser)?
+        }
+    }
+
+    writer.write_all(&output)?;
+    Ok(())
+}
+
+/// 書き込み途中の Object・Array を表現する
+struct Level {
+    array: bool,
+    /// まだ要素を書き込んでいないか
+    first: bool,
+}
+
+/// JSONイベントを Formatter の呼び出しに置き換える
+struct Reformatter<'a, W, F> {
+    writer: &'a mut W,
+    formatter: F,
+    stack: Vec<Level>,
+    /// 最上位の値を書き込んだか
+    written: bool,
+}
+
+impl<'a, W: std::io::Write, F: Formatter> Reformatter<'a, W, F> {
+    fn new(writer: &'a mut W, formatter: F) -> Self {
+        Self {
+            writer,
+            formatter,
+            stack: Vec::new(),
+            written: false,
+        }
+    }
+
+    fn run<R>(mut self, mut parser: StreamingParser<R>) -> Result<(), Error>
+    where
+        R: std::io::BufRead + std::fmt::Debug,
+    {
+        while let Some(event) = parser.next_event()? {
+            self.event(event, parser.raw())?;
+        }
+        Ok(())
+    }
+
+    /// raw は JsonEvent::Key・JsonEvent::Value のトークンの元の表記
+    fn event(&mut self, event: JsonEvent, raw: &str) -> std::io::Result<()> {
+        match event {
+            JsonEvent::StartObject => {
+                self.begin_value()?;
+                self.stack.push(Level {
+                    array: false,
+                    first: true,
+                });
+                self.formatter.begin_object(self.writer)
+            }
+            JsonEvent::StartArray => {
+                self.begin_value()?;
+                self.stack.push(Level {
+                    array: true,
+                    first: true,
+                });
+                self.formatter.begin_array(self.writer)
+            }
+            JsonEvent::EndObject => {
+                self.stack.pop();
+                self.formatter.end_object(self.writer)?;
+                self.end_value()
+            }
+            JsonEvent::EndArray => {
+                self.stack.pop();
+                self.formatter.end_array(self.writer)?;
+                self.end_value()
+            }
+            JsonEvent::Key(_) => {
+                let first = self.take_first();
+                self.formatter.begin_object_key(self.writer, first)?;
+                self.formatter.write_raw_fragment(self.writer, raw)?;
+                self.formatter.begin_object_value(self.writer)
+            }
+            JsonEvent::Value(value) => {
+                self.begin_value()?;
+                match value {
+                    Node::Bool(b) => self.formatter.write_bool(self.writer, b),
+                    Node::Null => self.formatter.write_null(self.writer),
+                    // 文字列のエスケープや数値の表記・精度を変えないよう、元の表記のまま書き込む
+                    _ => self.formatter.write_raw_fragment(self.writer, raw),
+                }?;
+                self.end_value()
+            }
+        }
+    }
+
+    /// 書き込み途中の Object・Array の最初の要素かを返却し、以降は要素がある状態にする
+    fn take_first(&mut self) -> bool {
+        self.stack
+            .last_mut()
+            .is_some_and(|level| std::mem::replace(&mut level.first, false))
+    }
+
+    /// Array の要素であれば区切りを、２つ目以降の最上位の値であれば改行を書き込む
+    /// Objectの値の前の区切りは JsonEvent::Key で書き込んでいる
+    fn begin_value(&mut self) -> std::io::Result<()> {
+        match self.stack.last() {
+            None if std::mem::replace(&mut self.written, true) => self.writer.write_all(b"\n"),
+            Some(level) if level.array => {
+                let first = self.take_first();
+                self.formatter.begin_array_value(self.writer, first)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn end_value(&mut self) -> std::io::Result<()> {
+        match self.stack.last() {
+            None => Ok(()),
+            Some(level) if level.array => self.formatter.end_array_value(self.writer),
+            Some(_) => self.formatter.end_object_value(self.writer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reformat_str(input: &str, style: Style) -> Result<String, Error> {
+        let reader = std::io::BufReader::new(std::io::Cursor::new(input.to_string()));
+        let mut output = Vec::new();
+        reformat(reader, &mut output, style)?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[rstest::rstest]
+    #[case(
+        r#"[ "a\nb", "\u00e9\ud83d\ude00", "\/" ]"#,
+        r#"["a\nb","\u00e9\ud83d\ude00","\/"]"#
+    )]
+    #[case(r#"{ "k\t" : "\"" }"#, r#"{"k\t":"\""}"#)]
+    #[case(
+        "[18446744073709551616, -9223372036854775809]",
+        "[18446744073709551616,-9223372036854775809]"
+    )]
+    #[case("[1E2, 1e-7, -0.0, 1.50]", "[1E2,1e-7,-0.0,1.50]")]
+    fn test_reformat_raw(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(reformat_str(input, Style::Compact).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_reformat_with_options() {
+        let input = "// head\n{\"a\": [1, /* x */ 2,], \"b\": {\"c\": [[]]}}";
+        let options = ParserOptions {
+            allow_comments: true,
+            allow_trailing_commas: true,
+            ..Default::default()
+        };
+        let mut output = Vec::new();
+        reformat_with_options(input.as_bytes(), &mut output, Style::Compact, options).unwrap();
+        assert_eq!(output, br#"{"a":[1,2],"b":{"c":[[]]}}"#);
+
+        let options = ParserOptions {
+            max_depth: 2,
+            ..Default::default()
+        };
+        let mut output = Vec::new();
+        assert!(matches!(
+            reformat_with_options(
+                r#"{"b": {"c": [[]]}}"#.as_bytes(),
+                &mut output,
+                Style::Compact,
+                options
+            ),
+            Err(Error::DepthLimitExceeded(..))
+        ));
+        assert!(output.is_empty());
+    }
+
+    #[rstest::rstest]
+    #[case(" 1 ", Style::Compact, "1")]
+    #[case("[ ]", Style::Pretty(2), "[]")]
+    #[case(r#"[ {}, [ ], "a\"b" ]"#, Style::Compact, r#"[{},[],"a\"b"]"#)]
+    #[case(
+        "[[1], {\"k\": [true]}]",
+        Style::Pretty(1),
+        "[\n [\n  1\n ],\n {\n  \"k\": [\n   true\n  ]\n }\n]"
+    )]
+    #[case("1 [2] {}", Style::Compact, "1\n[2]\n{}")]
+    #[case(
+        "{\"a\": null, \"b\": false}",
+        Style::Pretty(4),
+        "{\n    \"a\": null,\n    \"b\": false\n}"
+    )]
+    fn test_reformat(#[case] input: &str, #[case] style: Style, #[case] expected: &str) {
+        assert_eq!(reformat_str(input, style).unwrap(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case("[1,]")]
+    #[case("[1, 2] [3")]
+    #[case("@1")] // Lexer が読み飛ばす文字
+    fn test_reformat_error(#[case] input: &str) {
+        let mut output = Vec::new();
+        assert!(reformat(input.as_bytes(), &mut output, Style::Compact).is_err());
+        // 途中までの出力を残さない
+        assert!(output.is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+use node::Node;
+
+/// 入力中の行と位置の範囲を表現する
+/// 位置は行頭からの UTF-8 の文字数を表す
+#[derive(std::fmt::Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub line: std::ops::Range<usize>,
+    pub pos: std::ops::Range<usize>,
+}
+
+/// 入力中の範囲を持つ JSONデータを表現する
+/// Object・Array の範囲は開き括弧から閉じ括弧までとなる
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub struct SpannedNode {
+    pub span: Span,
+    pub value: SpannedValue,
+}
+
+/// SpannedNode の値を表現する
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub enum SpannedValue {
+    String(String),
+    Number(node::Number),
+    Bool(bool),
+    Null,
+    Array(Vec<SpannedNode>),
+    /// キーの並び順は Parser::parse と同様に ParserOptions::preserve_order に従う
+    Object(node::Map<SpannedEntry>),
+}
+
+/// Objectの要素をキーの範囲とともに表現する
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub struct SpannedEntry {
+    pub key: Span,
+    pub value: SpannedNode,
+}
+
+impl SpannedNode {
+    /// 範囲を取り除いた Node に変換する
+    pub fn into_node(self) -> Node {
+        match self.value {
+            SpannedValue::String(value) => Node::String(value),
+            SpannedValue::Number(value) => Node::Number(value),
+            SpannedValue::Bool(value) => Node::Bool(value),
+            SpannedValue::Null => Node::Null,
+            SpannedValue::Array(array) => {
+                Node::Array(array.into_iter().map(SpannedNode::into_node).collect())
+            }
+            SpannedValue::Object(object) => Node::Object(
+                object
+                    .into_iter()
+                    .map(|(key, entry)| (key, entry.value.into_node()))
+                    .collect(),
+            ),
+        }
+    }
+}
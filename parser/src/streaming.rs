@@ -0,0 +1,712 @@
+use node::Node;
+
+use crate::Error;
+use crate::lexer::{self, Data, Kind, Lexer, Token};
+use crate::options::{ParserOptions, TopLevel};
+
+/// JSONの構造を表すイベント
+#[derive(std::fmt::Debug, Clone, PartialEq)]
+pub enum JsonEvent {
+    StartObject,
+    EndObject,
+    StartArray,
+    EndArray,
+    /// Objectのキー（直後のイベントがその値となる）
+    Key(String),
+    /// Node::String・Node::Number・Node::Bool・Node::Null のいずれか
+    Value(Node),
+}
+
+/// 解析途中の Object・Array の種別を表現する
+enum Container {
+    Array,
+    Object,
+}
+
+/// 解析の次の手順を表現する
+enum State {
+    /// 値を待っている
+    /// 読み出し済みのトークンがあればそれを評価し、２番目が true の場合は `]` で Array を閉じられる
+    Value(Option<Token>, bool),
+    /// Objectのキーを待っている
+    /// true の場合は `}` で Object を閉じられる
+    Key(bool),
+    /// Object・Array の要素の後の `,` か閉じ括弧を待っている
+    Separator,
+    /// reader の末尾に到達したか、エラーにより解析を終了した
+    Finished,
+}
+
+/// std::io::BufRead から読み取れる文字列の文法を検証しながら、JSONイベントを１つずつ返却する
+/// Node のツリーを構築しないため、巨大な JSON でもネストの深さ分のメモリで処理できる
+/// 連結された複数の値は続けてイベントを返却し、reader の末尾に到達すると None を返却する
+/// エラーを返却した後は None を返却する
+///
+/// # Examples
+///
+/// ```
+/// use parser::streaming::{JsonEvent, StreamingParser};
+///
+/// let input = r#"{"key": [1, "value"]}"#;
+/// let cursor = std::io::Cursor::new(input);
+/// let buf_reader = std::io::BufReader::new(cursor);
+/// let events = StreamingParser::new(buf_reader).collect::<Result<Vec<_>, _>>();
+/// assert_eq!(
+///     events.unwrap(),
+///     vec![
+///         JsonEvent::StartObject,
+///         JsonEvent::Key("key".into()),
+///         JsonEvent::StartArray,
+///         JsonEvent::Value(node::Node::Number(1.into())),
+///         JsonEvent::Value(node::Node::String("value".into())),
+///         JsonEvent::EndArray,
+///         JsonEvent::EndObject,
+///     ]
+/// );
+/// ```
+pub struct StreamingParser<T>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    lexer: Lexer<T>,
+    options: ParserOptions,
+    stack: Vec<Container>,
+    state: State,
+    /// 直前に読み出したトークンの位置と種別
+    line: std::ops::Range<usize>,
+    pos: std::ops::Range<usize>,
+    kind: Kind,
+    /// 直前に返却したイベントの位置
+    span: (std::ops::Range<usize>, std::ops::Range<usize>),
+    /// エラーからの回復時に一度に閉じた Object・Array のイベント
+    queue: std::collections::VecDeque<JsonEvent>,
+    /// エラーから回復する場合のみ Some となり、発生したエラーを蓄える
+    errors: Option<Vec<Error>>,
+    /// Lexer から読み出したトークンの数
+    tokens: usize,
+    /// true の場合、トークンの元の表記を raw に保持する
+    recording: bool,
+    /// 直前に返却したイベントのトークンの表記
+    raw: String,
+}
+
+impl<T> StreamingParser<T>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    /// パーサーを生成して返却する
+    pub fn new(reader: T) -> Self {
+        Self::with_options(reader, ParserOptions::default())
+    }
+
+    /// 挙動を設定したパーサーを生成して返却する
+    /// ParserOptions::duplicate_keys はキーを保持しないため評価しない
+    pub fn with_options(reader: T, options: ParserOptions) -> Self {
+        let mut lexer = if options.allow_comments {
+            Lexer::with_comments(reader)
+        } else {
+            Lexer::new(reader)
+        };
+        lexer.set_max_bytes(options.max_bytes);
+
+        Self {
+            lexer,
+            options,
+            stack: Vec::new(),
+            state: State::Value(None, false),
+            line: 1..1,
+            pos: 1..1,
+            kind: Kind::EOF,
+            span: (1..1, 1..1),
+            queue: std::collections::VecDeque::new(),
+            errors: None,
+            tokens: 0,
+            recording: false,
+            raw: String::new(),
+        }
+    }
+
+    /// 内部の reader への可変参照を返却する
+    pub(crate) fn get_mut(&mut self) -> &mut T {
+        self.lexer.get_mut()
+    }
+
+    /// reader を差し替え、生成した直後の状態から解析し直す
+    /// 設定やバッファは再利用し、差し替える前の reader を返却する
+    pub fn reset(&mut self, reader: T) -> T {
+        self.stack.clear();
+        self.state = State::Value(None, false);
+        self.line = 1..1;
+        self.pos = 1..1;
+        self.kind = Kind::EOF;
+        self.span = (1..1, 1..1);
+        self.queue.clear();
+        self.errors = None;
+        self.tokens = 0;
+        self.raw.clear();
+        self.lexer.reset(reader)
+    }
+
+    /// 以降のイベントを読み出さず、reader の末尾に到達したものとして扱う
+    pub(crate) fn finish(&mut self) {
+        self.stack.clear();
+        self.state = State::Finished;
+        self.queue.clear();
+    }
+
+    /// 直前に返却したイベントの行と位置を返却する
+    pub fn span(&self) -> (std::ops::Range<usize>, std::ops::Range<usize>) {
+        self.span.clone()
+    }
+
+    /// 次のイベントを返却する
+    /// reader の末尾に到達した場合は None を返却する
+    /// 構文エラーの場合は Error::UnexpectedToken を返却する
+    /// ネストが ParserOptions::max_depth を超える場合は Error::DepthLimitExceeded を返却する
+    /// トークン生成のエラーは Error::InvalidToken、reader 自体のエラーは Error::LexerError を返却する
+    pub fn next_event(&mut self) -> Result<Option<JsonEvent>, Error> {
+        self.next_event_within(None)
+    }
+
+    /// StreamingParser::next_event と同様に次のイベントを返却する
+    /// limit が Some の場合は、読み出したトークンの数が limit を超えるまでにイベントが発生しなければ None を返却する
+    pub(crate) fn next_event_within(
+        &mut self,
+        limit: Option<usize>,
+    ) -> Result<Option<JsonEvent>, Error> {
+        loop {
+            if let Some(event) = self.queue.pop_front() {
+                return Ok(Some(event));
+            }
+
+            let needed = match self.state {
+                State::Value(None, _) | State::Separator => 1,
+                // キーと `:` を続けて読み出す
+                State::Key(_) => 2,
+                State::Value(Some(_), _) => 0,
+                State::Finished => return Ok(None),
+            };
+            if limit.is_some_and(|limit| self.tokens + needed > limit) {
+                return Ok(None);
+            }
+
+            match self.step() {
+                Ok(Some(event)) => return Ok(Some(event)),
+                Ok(None) => {}
+                Err(e) => self.recover(e)?,
+            }
+        }
+    }
+
+    /// Node や文字列を構築せずに値を１つ読み飛ばす
+    /// 次のイベントが Objectのキーの場合は、キーとその値を読み飛ばす
+    /// 値を読み飛ばした場合は true を、Object・Array の末尾か reader の末尾に到達していた場合は false を返却する
+    /// エラーは StreamingParser::next_event と同様に返却する
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parser::streaming::{JsonEvent, StreamingParser};
+    ///
+    /// let input = r#"[{"large": [1, 2, 3]}, "next"]"#;
+    /// let cursor = std::io::Cursor::new(input);
+    /// let buf_reader = std::io::BufReader::new(cursor);
+    /// let mut parser = StreamingParser::new(buf_reader);
+    /// assert_eq!(parser.next_event().unwrap(), Some(JsonEvent::StartArray));
+    /// assert_eq!(parser.skip_value().unwrap(), true);
+    /// assert_eq!(
+    ///     parser.next_event().unwrap(),
+    ///     Some(JsonEvent::Value(node::Node::String("next".into())))
+    /// );
+    /// assert_eq!(parser.skip_value().unwrap(), false);
+    /// assert_eq!(parser.next_event().unwrap(), Some(JsonEvent::EndArray));
+    /// ```
+    pub fn skip_value(&mut self) -> Result<bool, Error> {
+        self.set_discard(true);
+        let result = self.skip_events();
+        self.set_discard(false);
+
+        result
+    }
+
+    fn skip_events(&mut self) -> Result<bool, Error> {
+        let mut depth = 0_usize;
+
+        while let Some(event) = self.next_event()? {
+            match event {
+                JsonEvent::StartObject | JsonEvent::StartArray => depth += 1,
+                JsonEvent::EndObject | JsonEvent::EndArray if depth == 0 => {
+                    // 読み飛ばす値がないため、閉じるイベントは次の next_event で返却する
+                    self.queue.push_front(event);
+                    return Ok(false);
+                }
+                JsonEvent::EndObject | JsonEvent::EndArray => depth -= 1,
+                JsonEvent::Key(_) => continue,
+                JsonEvent::Value(_) => {}
+            }
+
+            if depth == 0 {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// true の場合、以降のイベントの文字列（キーと Node::String）は空となる
+    pub(crate) fn set_discard(&mut self, discard: bool) {
+        self.lexer.set_discard(discard);
+    }
+
+    /// 以降のトークンの元の表記（空白・コメントを除く）を保持する
+    /// Lexer が読み飛ばす文字は表記に含められないため、トークンの先頭になり得ない文字は Error::InvalidToken とする
+    pub(crate) fn set_recording(&mut self) {
+        self.recording = true;
+        self.lexer.set_recording(true);
+    }
+
+    /// 直前に返却した JsonEvent::Key・JsonEvent::Value のトークンの元の表記を返却する
+    /// set_recording を呼び出していない場合は空文字列を返却する
+    pub(crate) fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// 直前に読み出した文字列（キーと Node::String）の入力中のバイト範囲（ダブルクォートを含む）を返却する
+    pub(crate) fn string_bytes(&self) -> std::ops::Range<usize> {
+        self.lexer.string_bytes()
+    }
+
+    /// 次のイベントを読み出さずに返却する
+    pub(crate) fn peek_event(&mut self) -> Result<Option<&JsonEvent>, Error> {
+        if self.queue.is_empty()
+            && let Some(event) = self.next_event()?
+        {
+            self.queue.push_front(event);
+        }
+
+        Ok(self.queue.front())
+    }
+
+    /// エラーから回復しながらの解析を開始する
+    pub(crate) fn begin_recovery(&mut self) {
+        self.errors = Some(Vec::new());
+    }
+
+    /// エラーから回復しながらの解析を終了し、発生したエラーを返却する
+    pub(crate) fn end_recovery(&mut self) -> Vec<Error> {
+        self.errors.take().unwrap_or_default()
+    }
+
+    /// エラーから回復しながらの解析中であればエラーを蓄え、そうでなければエラーをそのまま返却する
+    pub(crate) fn record(&mut self, error: Error) -> Result<(), Error> {
+        match self.errors.as_mut() {
+            Some(errors) => {
+                errors.push(error);
+                Ok(())
+            }
+            None => Err(error),
+        }
+    }
+
+    /// 空白を読み飛ばし、最初に現れた文字を読み出さずに返却する
+    pub(crate) fn skip_whitespace(&mut self) -> Result<Option<(char, usize, usize)>, Error> {
+        self.lexer.skip_whitespace().map_err(Error::from)
+    }
+
+    /// トークンを１つ読み出し状態を進める
+    /// イベントが発生しない遷移（`,` の読み出し）では None を返却する
+    fn step(&mut self) -> Result<Option<JsonEvent>, Error> {
+        match std::mem::replace(&mut self.state, State::Finished) {
+            State::Value(pending, closable) => {
+                let token = match pending {
+                    Some(token) => token,
+                    None => self.read_token()?,
+                };
+
+                if self.stack.is_empty()
+                    && self.options.top_level == TopLevel::ObjectOrArray
+                    && !matches!(token.data, Data::LeftBrace | Data::LeftBracket | Data::EOF)
+                {
+                    return Err(self.syntax_error(
+                        &[Kind::LeftBrace, Kind::LeftBracket],
+                        "最上位の値はObjectかArrayでなければなりません",
+                    ));
+                }
+
+                match token.data {
+                    Data::RightBracket if closable => Ok(self.close()),
+                    Data::LeftBrace => {
+                        self.enter()?;
+                        self.stack.push(Container::Object);
+                        self.state = State::Key(true);
+                        Ok(Some(self.emit(JsonEvent::StartObject)))
+                    }
+                    Data::LeftBracket => {
+                        self.enter()?;
+                        self.stack.push(Container::Array);
+                        self.state = State::Value(None, true);
+                        Ok(Some(self.emit(JsonEvent::StartArray)))
+                    }
+                    Data::String(value) => Ok(Some(self.value(Node::String(value)))),
+                    Data::Number(value) => Ok(Some(self.value(Node::Number(value)))),
+                    Data::True => Ok(Some(self.value(Node::Bool(true)))),
+                    Data::False => Ok(Some(self.value(Node::Bool(false)))),
+                    Data::Null => Ok(Some(self.value(Node::Null))),
+                    Data::EOF => match self.stack.last() {
+                        None => Ok(None),
+                        Some(Container::Array) => Err(self.syntax_error(
+                            Kind::VALUE,
+                            "Arrayの要素はbool型・null型・String型・Number型・Object・Arrayのいずれかでなければなりません",
+                        )),
+                        Some(Container::Object) => Err(self.syntax_error(
+                            Kind::VALUE,
+                            "Objectの値はbool型・null型・String型・Number型・Object・Arrayのいずれかでなければなりません",
+                        )),
+                    },
+                    _ => Err(self.syntax_error(
+                        Kind::VALUE,
+                        "bool型・null型・String型・Number型・Object・Arrayのいずれかでなければなりません",
+                    )),
+                }
+            }
+            State::Key(closable) => match self.read_token()?.data {
+                Data::RightBrace if closable => Ok(self.close()),
+                Data::String(name) => {
+                    let line = self.line.clone();
+                    let pos = self.pos.clone();
+                    let raw = std::mem::take(&mut self.raw);
+
+                    match self.read_token()?.data {
+                        Data::Colon => {
+                            self.state = State::Value(None, false);
+                            self.span = (line, pos);
+                            self.raw = raw;
+                            Ok(Some(JsonEvent::Key(name)))
+                        }
+                        _ => Err(self.syntax_error(
+                            &[Kind::Colon],
+                            "Objectのキーの後は`:`でなければなりません",
+                        )),
+                    }
+                }
+                _ if closable => Err(self.syntax_error(
+                    &[Kind::String, Kind::RightBrace],
+                    "ObjectのキーはString型でなければなりません",
+                )),
+                _ => Err(self.syntax_error(
+                    &[Kind::String],
+                    "ObjectのキーはString型でなければなりません",
+                )),
+            },
+            State::Separator => match (self.read_token()?.data, self.stack.last()) {
+                (Data::Comma, Some(Container::Array)) => {
+                    self.state = State::Value(None, self.options.allow_trailing_commas);
+                    Ok(None)
+                }
+                (Data::Comma, Some(Container::Object)) => {
+                    self.state = State::Key(self.options.allow_trailing_commas);
+                    Ok(None)
+                }
+                (Data::RightBracket, Some(Container::Array))
+                | (Data::RightBrace, Some(Container::Object)) => Ok(self.close()),
+                (_, Some(Container::Array)) => Err(self.syntax_error(
+                    &[Kind::Comma, Kind::RightBracket],
+                    "Arrayの要素の後は `,` か `]` でなければなりません",
+                )),
+                (_, _) => Err(self.syntax_error(
+                    &[Kind::Comma, Kind::RightBrace],
+                    "Objectの解析の継続（`,`）、終了（`}`）のいずれもでありません",
+                )),
+            },
+            State::Finished => Ok(None),
+        }
+    }
+
+    /// 直前に読み出したトークンの位置をイベントの位置として記録する
+    fn emit(&mut self, event: JsonEvent) -> JsonEvent {
+        self.span = (self.line.clone(), self.pos.clone());
+        event
+    }
+
+    /// 値が完成した後の状態へ遷移する
+    /// 最上位の値が完成した場合は、続く値の解析を待つ
+    fn complete(&mut self) {
+        self.state = if self.stack.is_empty() {
+            State::Value(None, false)
+        } else {
+            State::Separator
+        };
+    }
+
+    fn value(&mut self, node: Node) -> JsonEvent {
+        self.complete();
+        self.emit(JsonEvent::Value(node))
+    }
+
+    /// 直近の Object・Array を閉じる
+    fn close(&mut self) -> Option<JsonEvent> {
+        let event = match self.stack.pop()? {
+            Container::Array => JsonEvent::EndArray,
+            Container::Object => JsonEvent::EndObject,
+        };
+        self.complete();
+
+        Some(self.emit(event))
+    }
+
+    /// 解析途中の Object・Array を全て閉じて解析を終了する
+    fn close_all(&mut self) {
+        while let Some(event) = self.close() {
+            self.queue.push_back(event);
+        }
+        self.state = State::Finished;
+    }
+
+    /// エラーから回復しながらの解析中であればエラーを蓄え、解析を再開できる位置まで読み飛ばす
+    /// そうでなければ解析を終了し、エラーをそのまま返却する
+    fn recover(&mut self, error: Error) -> Result<(), Error> {
+        let fatal = matches!(
+            error,
+            Error::InvalidToken(..) | Error::LexerError(_) | Error::SizeLimitExceeded(_)
+        );
+
+        if let Err(e) = self.record(error) {
+            self.stack.clear();
+            self.state = State::Finished;
+            return Err(e);
+        }
+
+        // reader 自体のエラーからは再開できない
+        if fatal {
+            self.close_all();
+            return Ok(());
+        }
+
+        let mut nesting = 0_usize;
+
+        loop {
+            match self.kind {
+                Kind::EOF => {
+                    self.close_all();
+                    return Ok(());
+                }
+                Kind::Comma if nesting == 0 => {
+                    self.state = match self.stack.last() {
+                        Some(Container::Array) => {
+                            State::Value(None, self.options.allow_trailing_commas)
+                        }
+                        Some(Container::Object) => State::Key(self.options.allow_trailing_commas),
+                        None => State::Value(None, false),
+                    };
+                    return Ok(());
+                }
+                Kind::RightBrace | Kind::RightBracket if nesting == 0 => {
+                    match self.close() {
+                        Some(event) => self.queue.push_back(event),
+                        None => self.complete(),
+                    }
+                    return Ok(());
+                }
+                Kind::RightBrace | Kind::RightBracket => nesting -= 1,
+                Kind::LeftBrace | Kind::LeftBracket => nesting += 1,
+                _ => {}
+            }
+
+            if let Err(e) = self.read_token() {
+                self.record(e)?;
+                self.close_all();
+                return Ok(());
+            }
+        }
+    }
+
+    fn enter(&self) -> Result<(), Error> {
+        if self.stack.len() >= self.options.max_depth {
+            Err(Error::DepthLimitExceeded(
+                self.line.clone(),
+                self.pos.clone(),
+                self.options.max_depth,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn read_token(&mut self) -> Result<Token, Error> {
+        self.tokens += 1;
+        if self.recording {
+            self.skip_unrecorded()?;
+        }
+
+        let result = match self.lexer.read() {
+            // 解析を継続する場合は、トークンの残りを読み飛ばし null として扱う
+            Err(e)
+                if self.errors.is_some()
+                    && !matches!(
+                        e,
+                        lexer::error::Error::ReaderError(_)
+                            | lexer::error::Error::SizeLimitExceeded(_)
+                    ) =>
+            {
+                self.record(Error::from(e))?;
+                self.lexer.recover().map(|_| Token {
+                    line: self.line.clone(),
+                    pos: self.pos.clone(),
+                    data: Data::Null,
+                })
+            }
+            result => result,
+        };
+
+        result
+            .map(|mut token| {
+                // token の line/pos を以降で読み出さない
+                self.line = std::mem::take(&mut token.line);
+                self.pos = std::mem::take(&mut token.pos);
+                self.kind = token.data.kind();
+                if self.recording {
+                    self.raw = self.lexer.take_recorded();
+                }
+                token
+            })
+            .map_err(Error::from)
+    }
+
+    /// 空白・コメントを読み飛ばし、蓄えた表記から取り除く
+    fn skip_unrecorded(&mut self) -> Result<(), Error> {
+        let next = self.lexer.skip_whitespace()?;
+        self.lexer.take_recorded();
+
+        match next {
+            Some((c, line, pos)) if !lexer::starts_token(c) => {
+                Err(lexer::error::Error::InvalidToken(c.to_string(), line..line, pos..pos).into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn syntax_error(&self, expected: &[Kind], message: &str) -> Error {
+        Error::UnexpectedToken {
+            line: self.line.clone(),
+            pos: self.pos.clone(),
+            expected: expected.to_vec(),
+            found: self.kind,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl<T> Iterator for StreamingParser<T>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    type Item = Result<JsonEvent, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_event().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events(input: &str) -> Vec<Result<JsonEvent, Error>> {
+        let cursor = std::io::Cursor::new(input.to_string());
+        let buf_reader = std::io::BufReader::new(cursor);
+        StreamingParser::new(buf_reader).collect()
+    }
+
+    #[test]
+    fn test_streaming_parser() {
+        let result = events(r#"{"a": [true, false, null, {}], "b": {"c": -1.5}} "d""#)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>();
+
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            vec![
+                JsonEvent::StartObject,
+                JsonEvent::Key("a".into()),
+                JsonEvent::StartArray,
+                JsonEvent::Value(Node::Bool(true)),
+                JsonEvent::Value(Node::Bool(false)),
+                JsonEvent::Value(Node::Null),
+                JsonEvent::StartObject,
+                JsonEvent::EndObject,
+                JsonEvent::EndArray,
+                JsonEvent::Key("b".into()),
+                JsonEvent::StartObject,
+                JsonEvent::Key("c".into()),
+                JsonEvent::Value(Node::Number((-1.5).into())),
+                JsonEvent::EndObject,
+                JsonEvent::EndObject,
+                JsonEvent::Value(Node::String("d".into())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_streaming_parser_error() {
+        let result = events("[1, 2 3]");
+
+        assert_eq!(result.len(), 4);
+        assert!(matches!(
+            result.last(),
+            Some(Err(Error::UnexpectedToken {
+                found: Kind::Number,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_span() {
+        let cursor = std::io::Cursor::new("{\n  \"key\": \"value\"\n}");
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = StreamingParser::new(buf_reader);
+
+        let mut spans = vec![];
+        while parser.next_event().unwrap().is_some() {
+            spans.push(parser.span());
+        }
+
+        assert_eq!(
+            spans,
+            vec![(1..1, 1..1), (2..2, 3..7), (2..2, 10..16), (3..3, 1..1)]
+        );
+    }
+
+    #[test]
+    fn test_skip_value() {
+        let cursor = std::io::Cursor::new(r#"{"a": {"b": ["c", 1e3]}, "d": "e", "f": [1 2]}"#);
+        let buf_reader = std::io::BufReader::new(cursor);
+        let mut parser = StreamingParser::new(buf_reader);
+
+        assert_eq!(parser.next_event().unwrap(), Some(JsonEvent::StartObject));
+        // キーとその値を読み飛ばす
+        assert!(parser.skip_value().unwrap());
+        assert_eq!(
+            parser.next_event().unwrap(),
+            Some(JsonEvent::Key("d".into()))
+        );
+        // 読み飛ばした後は文字列を保持する
+        assert_eq!(
+            parser.next_event().unwrap(),
+            Some(JsonEvent::Value(Node::String("e".into())))
+        );
+        assert_eq!(
+            parser.next_event().unwrap(),
+            Some(JsonEvent::Key("f".into()))
+        );
+        // 読み飛ばす値の構文エラーは返却する
+        assert!(matches!(
+            parser.skip_value(),
+            Err(Error::UnexpectedToken {
+                found: Kind::Number,
+                ..
+            })
+        ));
+    }
+}
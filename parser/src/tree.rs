@@ -0,0 +1,366 @@
+use std::marker::PhantomData;
+
+use node::{MapKind, Node, ObjectMap};
+
+use crate::spanned::{Span, SpannedEntry, SpannedNode, SpannedValue};
+use crate::streaming::JsonEvent;
+use crate::{Budget, Error, Key, Parser};
+
+/// イベントから木を構築する方法を表現する
+/// Frame のスタックは Parser::build が管理し、値の種類ごとの構築のみを実装する
+/// 各メソッドは直前に読み出したイベントの位置を parser.events.span() で参照できる
+pub(crate) trait TreeBuilder<T>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    /// 構築する値
+    type Node;
+    /// 構築途中の Array
+    type Array;
+    /// 構築途中の Object
+    type Object;
+    /// 値を待っている Object のキー
+    type Key;
+
+    /// `[` を読み出した際に空の Array を返却する
+    fn start_array(&mut self, parser: &mut Parser<T>) -> Self::Array;
+
+    /// `{` を読み出した際に空の Object を返却する
+    fn start_object(&mut self, parser: &mut Parser<T>) -> Self::Object;
+
+    /// Objectのキーを返却する
+    /// None を返却した場合は、そのキーの値を読み飛ばす
+    /// depth は読み出したキーを持つ Object の深さで、最上位の Object は 1 となる
+    fn key(
+        &mut self,
+        parser: &mut Parser<T>,
+        budget: &mut Budget,
+        name: String,
+        depth: usize,
+    ) -> Result<Option<Self::Key>, Error>;
+
+    /// Array・Object 以外の値を返却する
+    fn leaf(
+        &mut self,
+        parser: &mut Parser<T>,
+        budget: &mut Budget,
+        node: Node,
+    ) -> Result<Self::Node, Error>;
+
+    /// `]` を読み出した際に完成した Array を値として返却する
+    fn end_array(&mut self, parser: &mut Parser<T>, array: Self::Array) -> Self::Node;
+
+    /// `}` を読み出した際に完成した Object を値として返却する
+    fn end_object(
+        &mut self,
+        parser: &mut Parser<T>,
+        object: Self::Object,
+    ) -> Result<Self::Node, Error>;
+
+    /// Array に要素を加える
+    fn push(&mut self, array: &mut Self::Array, node: Self::Node);
+
+    /// Object にキーと値を加える
+    fn insert(
+        &mut self,
+        parser: &mut Parser<T>,
+        object: &mut Self::Object,
+        key: Self::Key,
+        node: Self::Node,
+    ) -> Result<(), Error>;
+}
+
+/// 解析途中の Object・Array を表現する
+/// 再帰の代わりにスタックへ積むことで、ネストの深さがスレッドのスタックではなくヒープで制限される
+enum Frame<A, O, K> {
+    Array(A),
+    /// 構築中の Object と、値を待っているキー
+    Object(O, Option<K>),
+}
+
+impl<T> Parser<T>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    /// builder で値を１つ構築する
+    /// 値を読み出す前に末尾に到達した場合は None を返却する
+    /// エラーを返却した後は値の途中から解析を再開しないよう、以降は末尾に到達したものとして扱う
+    pub(crate) fn build<B: TreeBuilder<T>>(
+        &mut self,
+        builder: &mut B,
+    ) -> Result<Option<B::Node>, Error> {
+        let result = self.build_value(builder);
+        if result.is_err() {
+            self.events.finish();
+        }
+
+        result
+    }
+
+    fn build_value<B: TreeBuilder<T>>(
+        &mut self,
+        builder: &mut B,
+    ) -> Result<Option<B::Node>, Error> {
+        let mut stack: Vec<Frame<B::Array, B::Object, B::Key>> = Vec::new();
+        let mut budget = Budget {
+            max_nodes: self.max_nodes,
+            max_string_bytes: self.max_string_bytes,
+            nodes: 0,
+            string_bytes: 0,
+        };
+
+        loop {
+            let event = self.events.next_event()?;
+            if let Some(event) = event.as_ref() {
+                budget.charge(event, self.events.span())?;
+            }
+
+            let node = match event {
+                // 最上位の値の途中で終了する場合は、エラーか閉じるイベントが先に返却される
+                None => return Ok(None),
+                Some(JsonEvent::StartObject) => {
+                    stack.push(Frame::Object(builder.start_object(self), None));
+                    continue;
+                }
+                Some(JsonEvent::StartArray) => {
+                    stack.push(Frame::Array(builder.start_array(self)));
+                    continue;
+                }
+                Some(JsonEvent::Key(name)) => {
+                    match builder.key(self, &mut budget, name, stack.len())? {
+                        Some(name) => {
+                            if let Some(Frame::Object(_, key)) = stack.last_mut() {
+                                *key = Some(name);
+                            }
+                        }
+                        None => {
+                            self.events.skip_value()?;
+                        }
+                    }
+                    continue;
+                }
+                Some(JsonEvent::Value(node)) => builder.leaf(self, &mut budget, node)?,
+                Some(JsonEvent::EndObject | JsonEvent::EndArray) => {
+                    match stack
+                        .pop()
+                        .expect("開始のイベントの後にのみ閉じるイベントが返却される")
+                    {
+                        Frame::Array(array) => builder.end_array(self, array),
+                        Frame::Object(object, _) => builder.end_object(self, object)?,
+                    }
+                }
+            };
+
+            // 完成した値を親の Object・Array へ格納する
+            match stack.last_mut() {
+                None => return Ok(Some(node)),
+                Some(Frame::Array(array)) => builder.push(array, node),
+                Some(Frame::Object(object, key)) => {
+                    let key = key.take().expect("値の前にキーを読み出している");
+                    builder.insert(self, object, key, node)?;
+                }
+            }
+        }
+    }
+}
+
+/// Object を K が表す種類の Map で構築する
+pub(crate) struct NodeBuilder<'f, K> {
+    fields: Option<&'f [&'f str]>,
+    map: PhantomData<K>,
+}
+
+impl<'f, K> NodeBuilder<'f, K> {
+    /// fields が Some の場合、最上位のObjectのうち一覧にないキーの値は読み飛ばす
+    pub(crate) fn new(fields: Option<&'f [&'f str]>) -> Self {
+        Self {
+            fields,
+            map: PhantomData,
+        }
+    }
+}
+
+impl<T, K> TreeBuilder<T> for NodeBuilder<'_, K>
+where
+    T: std::io::BufRead + std::fmt::Debug,
+    K: MapKind,
+{
+    type Node = Node<K>;
+    type Array = Vec<Node<K>>;
+    type Object = K::Map<Node<K>>;
+    type Key = Key;
+
+    fn start_array(&mut self, _: &mut Parser<T>) -> Self::Array {
+        vec![]
+    }
+
+    fn start_object(&mut self, _: &mut Parser<T>) -> Self::Object {
+        K::Map::default()
+    }
+
+    fn key(
+        &mut self,
+        parser: &mut Parser<T>,
+        _: &mut Budget,
+        name: String,
+        depth: usize,
+    ) -> Result<Option<Key>, Error> {
+        if depth == 1
+            && self
+                .fields
+                .is_some_and(|fields| !fields.contains(&name.as_str()))
+        {
+            return Ok(None);
+        }
+
+        let (line, pos) = parser.events.span();
+        Ok(Some(Key { name, line, pos }))
+    }
+
+    // スカラー値は Object を持たないため作り直しても複製は発生しない
+    fn leaf(&mut self, _: &mut Parser<T>, _: &mut Budget, node: Node) -> Result<Node<K>, Error> {
+        Ok(node.into_map_kind())
+    }
+
+    fn end_array(&mut self, _: &mut Parser<T>, array: Self::Array) -> Node<K> {
+        Node::Array(array)
+    }
+
+    fn end_object(
+        &mut self,
+        parser: &mut Parser<T>,
+        mut object: Self::Object,
+    ) -> Result<Node<K>, Error> {
+        parser.sort_keys(&mut object);
+        Ok(Node::Object(object))
+    }
+
+    fn push(&mut self, array: &mut Self::Array, node: Node<K>) {
+        array.push(node);
+    }
+
+    fn insert(
+        &mut self,
+        parser: &mut Parser<T>,
+        object: &mut Self::Object,
+        key: Key,
+        node: Node<K>,
+    ) -> Result<(), Error> {
+        if !object.contains_key(&key.name) || parser.accept_duplicate(&key)? {
+            object.insert(key.name, node);
+        }
+
+        Ok(())
+    }
+}
+
+/// 全ての値に入力中の範囲を持たせた SpannedNode を構築する
+/// Object・Array は開き括弧の範囲を保持し、閉じ括弧の範囲と合わせて値の範囲とする
+pub(crate) struct SpannedBuilder;
+
+impl<T> TreeBuilder<T> for SpannedBuilder
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    type Node = SpannedNode;
+    type Array = (Span, Vec<SpannedNode>);
+    type Object = (Span, node::Map<SpannedEntry>);
+    type Key = Key;
+
+    fn start_array(&mut self, parser: &mut Parser<T>) -> Self::Array {
+        (span(parser), vec![])
+    }
+
+    fn start_object(&mut self, parser: &mut Parser<T>) -> Self::Object {
+        (span(parser), node::Map::new())
+    }
+
+    fn key(
+        &mut self,
+        parser: &mut Parser<T>,
+        _: &mut Budget,
+        name: String,
+        _: usize,
+    ) -> Result<Option<Key>, Error> {
+        let (line, pos) = parser.events.span();
+        Ok(Some(Key { name, line, pos }))
+    }
+
+    fn leaf(
+        &mut self,
+        parser: &mut Parser<T>,
+        _: &mut Budget,
+        node: Node,
+    ) -> Result<SpannedNode, Error> {
+        Ok(SpannedNode {
+            span: span(parser),
+            value: match node {
+                Node::String(value) => SpannedValue::String(value),
+                Node::Number(value) => SpannedValue::Number(value),
+                Node::Bool(value) => SpannedValue::Bool(value),
+                _ => SpannedValue::Null,
+            },
+        })
+    }
+
+    fn end_array(&mut self, parser: &mut Parser<T>, (start, array): Self::Array) -> SpannedNode {
+        SpannedNode {
+            span: enclosing(start, span(parser)),
+            value: SpannedValue::Array(array),
+        }
+    }
+
+    fn end_object(
+        &mut self,
+        parser: &mut Parser<T>,
+        (start, mut object): Self::Object,
+    ) -> Result<SpannedNode, Error> {
+        parser.sort_keys(&mut object);
+        Ok(SpannedNode {
+            span: enclosing(start, span(parser)),
+            value: SpannedValue::Object(object),
+        })
+    }
+
+    fn push(&mut self, (_, array): &mut Self::Array, node: SpannedNode) {
+        array.push(node);
+    }
+
+    fn insert(
+        &mut self,
+        parser: &mut Parser<T>,
+        (_, object): &mut Self::Object,
+        key: Key,
+        node: SpannedNode,
+    ) -> Result<(), Error> {
+        if !object.contains_key(&key.name) || parser.accept_duplicate(&key)? {
+            let entry = SpannedEntry {
+                key: Span {
+                    line: key.line,
+                    pos: key.pos,
+                },
+                value: node,
+            };
+            object.insert(key.name, entry);
+        }
+
+        Ok(())
+    }
+}
+
+/// 直前に読み出したトークンの範囲
+fn span<T>(parser: &Parser<T>) -> Span
+where
+    T: std::io::BufRead + std::fmt::Debug,
+{
+    let (line, pos) = parser.events.span();
+    Span { line, pos }
+}
+
+/// 開き括弧から閉じ括弧までの範囲
+fn enclosing(start: Span, end: Span) -> Span {
+    Span {
+        line: start.line.start..end.line.end,
+        pos: start.pos.start..end.pos.end,
+    }
+}
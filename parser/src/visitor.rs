@@ -0,0 +1,62 @@
+use node::Node;
+
+/// Parser::parse_with_visitor から JSONの構造を読み出した順に呼び出されるコールバック
+/// line・pos は対応するトークンの行と位置を表し、必要なものだけを実装すれば良い
+///
+/// # Examples
+///
+/// ```
+/// use parser::visitor::JsonVisitor;
+///
+/// #[derive(Default)]
+/// struct Counter {
+///     keys: usize,
+///     values: usize,
+/// }
+///
+/// impl JsonVisitor for Counter {
+///     fn on_key(&mut self, _: &str, _: std::ops::Range<usize>, _: std::ops::Range<usize>) {
+///         self.keys += 1;
+///     }
+///
+///     fn on_value(&mut self, _: &node::Node, _: std::ops::Range<usize>, _: std::ops::Range<usize>) {
+///         self.values += 1;
+///     }
+/// }
+///
+/// let input = r#"{"a": [1, 2, {"b": null}], "c": "d"}"#;
+/// let cursor = std::io::Cursor::new(input);
+/// let buf_reader = std::io::BufReader::new(cursor);
+/// let mut parser = parser::Parser::new(buf_reader);
+/// let mut counter = Counter::default();
+///
+/// assert!(parser.parse_with_visitor(&mut counter).unwrap());
+/// assert_eq!(counter.keys, 3);
+/// assert_eq!(counter.values, 4);
+/// ```
+#[allow(unused_variables)]
+pub trait JsonVisitor {
+    /// `{` を読み出した
+    fn on_object_start(&mut self, line: std::ops::Range<usize>, pos: std::ops::Range<usize>) {}
+
+    /// `}` を読み出した
+    fn on_object_end(&mut self, line: std::ops::Range<usize>, pos: std::ops::Range<usize>) {}
+
+    /// `[` を読み出した
+    fn on_array_start(&mut self, line: std::ops::Range<usize>, pos: std::ops::Range<usize>) {}
+
+    /// `]` を読み出した
+    fn on_array_end(&mut self, line: std::ops::Range<usize>, pos: std::ops::Range<usize>) {}
+
+    /// Objectのキーを読み出した（次に呼び出されるコールバックがその値となる）
+    fn on_key(&mut self, key: &str, line: std::ops::Range<usize>, pos: std::ops::Range<usize>) {}
+
+    /// String型・Number型・bool型・null型のいずれかの値を読み出した
+    fn on_value(
+        &mut self,
+        value: &Node,
+        line: std::ops::Range<usize>,
+        pos: std::ops::Range<usize>,
+    ) {
+    }
+}